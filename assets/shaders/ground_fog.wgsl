@@ -11,11 +11,18 @@ struct GroundFogSettings {
     max_height: f32,
     density: f32,
     layer_index: f32,
+    safe_radius: f32,
+    edge_rise: f32,
     _pad1: f32,
+    safe_center: vec2<f32>,
     _pad2: f32,
     _pad3: f32,
 }
 
+// Туман Нави за границей безопасной зоны: плотность и оттенок
+const NAVI_DENSITY: f32 = 0.85;
+const NAVI_TINT: vec3<f32> = vec3(0.45, 0.2, 0.6);
+
 @group(3) @binding(0)
 var<uniform> settings: GroundFogSettings;
 
@@ -61,6 +68,13 @@ struct FogVaryings {
     @builtin(position) clip_position: vec4<f32>,
     @location(0) world_xz: vec2<f32>,
     @location(1) displacement: f32,
+    @location(2) outside: f32,
+}
+
+// 0 — внутри безопасной зоны, 1 — за границей (мягкий переход ±1.5м)
+fn outside_factor(world_xz: vec2<f32>) -> f32 {
+    let dist = length(world_xz - settings.safe_center);
+    return smoothstep(settings.safe_radius - 1.5, settings.safe_radius + 1.5, dist);
 }
 
 @vertex
@@ -71,7 +85,9 @@ fn vertex(vertex: Vertex) -> FogVaryings {
     let t = settings.time * settings.speed;
     let layer_off = settings.layer_index * 43.7;
     let n = fbm(world_pos.xz * 0.09 + t * vec2(0.18, 0.12) + layer_off);
-    let disp = max(n - 0.25, 0.0) * settings.max_height;
+    let outside = outside_factor(world_pos.xz);
+    // За границей зоны туман встаёт стеной, гребни по-прежнему живые от шума
+    let disp = max(n - 0.25, 0.0) * settings.max_height + outside * settings.edge_rise * (0.6 + 0.4 * n);
 
     world_pos.y += disp;
 
@@ -79,6 +95,7 @@ fn vertex(vertex: Vertex) -> FogVaryings {
     out.clip_position = position_world_to_clip(world_pos.xyz);
     out.world_xz = world_pos.xz;
     out.displacement = disp;
+    out.outside = outside;
     return out;
 }
 
@@ -93,5 +110,10 @@ fn fragment(in: FogVaryings) -> @location(0) vec4<f32> {
 
     let alpha = height_alpha * settings.density * edge_fade;
 
-    return vec4(settings.fog_color.rgb, alpha);
+    // Стена тумана Нави: плотная, тает только у самых верхушек
+    let wall_top = settings.max_height + settings.edge_rise;
+    let wall_alpha = in.outside * NAVI_DENSITY * (1.0 - 0.6 * smoothstep(0.0, wall_top, in.displacement));
+    let color = mix(settings.fog_color.rgb, NAVI_TINT, in.outside * 0.5);
+
+    return vec4(color, max(alpha, wall_alpha));
 }
@@ -0,0 +1,52 @@
+// Vertex Animation Texture: общие биндинги и выборка позы для импостеров врагов.
+// Раскладка текстуры: кадр = rows_per_frame строк, вершина v → (v % width, frame * rows + v / width).
+// Клипы подряд: idle, walk, run — по frames_per_clip кадров, каждый зациклен.
+
+struct VatSettings {
+    clip_durations: vec4<f32>,  // x = idle, y = walk, z = run (секунды)
+    texture_width: u32,
+    rows_per_frame: u32,
+    frames_per_clip: u32,
+    _pad: u32,
+}
+
+@group(3) @binding(101) var vat_positions: texture_2d<f32>;
+@group(3) @binding(102) var vat_normals: texture_2d<f32>;
+@group(3) @binding(103) var<uniform> vat: VatSettings;
+
+struct VatSample {
+    position: vec3<f32>,
+    normal: vec3<f32>,
+}
+
+fn vat_texel(vertex: u32, frame: u32) -> vec2<i32> {
+    let x = vertex % vat.texture_width;
+    let y = frame * vat.rows_per_frame + vertex / vat.texture_width;
+    return vec2<i32>(i32(x), i32(y));
+}
+
+// MeshTag инстанса: биты 0-1 = клип, 2-15 = сдвиг фазы (1/16384 цикла), 16-31 = скорость × 1000
+fn sample_vat(vertex: u32, tag: u32, time: f32) -> VatSample {
+    let clip = tag & 3u;
+    let offset = f32((tag >> 2u) & 0x3FFFu) / 16384.0;
+    let speed = f32(tag >> 16u) / 1000.0;
+    let duration = max(vat.clip_durations[clip], 0.001);
+
+    let phase = fract(offset + time * speed / duration);
+    let frame = phase * f32(vat.frames_per_clip);
+    let i0 = u32(floor(frame)) % vat.frames_per_clip;
+    let i1 = (i0 + 1u) % vat.frames_per_clip;
+    let t = fract(frame);
+    let base = clip * vat.frames_per_clip;
+
+    // Линейная интерполяция соседних кадров — без ступенек при малой частоте запекания
+    let p0 = textureLoad(vat_positions, vat_texel(vertex, base + i0), 0).xyz;
+    let p1 = textureLoad(vat_positions, vat_texel(vertex, base + i1), 0).xyz;
+    let n0 = textureLoad(vat_normals, vat_texel(vertex, base + i0), 0).xyz;
+    let n1 = textureLoad(vat_normals, vat_texel(vertex, base + i1), 0).xyz;
+
+    var out: VatSample;
+    out.position = mix(p0, p1, t);
+    out.normal = normalize(mix(n0, n1, t));
+    return out;
+}
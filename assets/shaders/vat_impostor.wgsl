@@ -0,0 +1,44 @@
+#import bevy_pbr::{
+    mesh_bindings::mesh,
+    mesh_functions,
+    forward_io::{Vertex, VertexOutput},
+    mesh_view_bindings::globals,
+    view_transformations::position_world_to_clip,
+}
+#import "shaders/vat_common.wgsl"::sample_vat
+
+// Вершинный шейдер импостера: поза берётся из VAT вместо скиннинга.
+// Фрагментный — stylized_pbr.wgsl (тот же PBR + rim, что у скиннутой модели).
+@vertex
+fn vertex(vertex: Vertex, @builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    var out: VertexOutput;
+
+    // vertex_index включает base_vertex слэба — приводим к индексу внутри меша
+    let local_index = vertex_index - mesh[vertex.instance_index].first_vertex_index;
+    let tag = mesh_functions::get_tag(vertex.instance_index);
+    let pose = sample_vat(local_index, tag, globals.time);
+
+    let world_from_local = mesh_functions::get_world_from_local(vertex.instance_index);
+    out.world_position = mesh_functions::mesh_position_local_to_world(world_from_local, vec4<f32>(pose.position, 1.0));
+    out.position = position_world_to_clip(out.world_position.xyz);
+    out.world_normal = mesh_functions::mesh_normal_local_to_world(pose.normal, vertex.instance_index);
+
+#ifdef VERTEX_UVS_A
+    out.uv = vertex.uv;
+#endif
+#ifdef VERTEX_UVS_B
+    out.uv_b = vertex.uv_b;
+#endif
+#ifdef VERTEX_COLORS
+    out.color = vertex.color;
+#endif
+#ifdef VERTEX_OUTPUT_INSTANCE_INDEX
+    out.instance_index = vertex.instance_index;
+#endif
+#ifdef VISIBILITY_RANGE_DITHER
+    out.visibility_range_dither = mesh_functions::get_visibility_range_dither_level(
+        vertex.instance_index, world_from_local[3]);
+#endif
+
+    return out;
+}
@@ -0,0 +1,61 @@
+#import bevy_pbr::{
+    mesh_bindings::mesh,
+    mesh_functions,
+    prepass_io::{Vertex, VertexOutput},
+    view_transformations::position_world_to_clip,
+}
+#import bevy_render::globals::Globals
+#import "shaders/vat_common.wgsl"::sample_vat
+
+// В prepass/shadow layout globals на binding 1 (mesh_view_bindings здесь недоступны)
+@group(0) @binding(1) var<uniform> globals: Globals;
+
+// Prepass/тени импостера: та же поза из VAT, иначе тень осталась бы в bind pose
+@vertex
+fn vertex(vertex: Vertex, @builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    var out: VertexOutput;
+
+    let local_index = vertex_index - mesh[vertex.instance_index].first_vertex_index;
+    let tag = mesh_functions::get_tag(vertex.instance_index);
+    let pose = sample_vat(local_index, tag, globals.time);
+
+    let world_from_local = mesh_functions::get_world_from_local(vertex.instance_index);
+    out.world_position = mesh_functions::mesh_position_local_to_world(world_from_local, vec4<f32>(pose.position, 1.0));
+    out.position = position_world_to_clip(out.world_position.xyz);
+#ifdef UNCLIPPED_DEPTH_ORTHO_EMULATION
+    out.unclipped_depth = out.position.z;
+    out.position.z = min(out.position.z, 1.0);
+#endif
+
+#ifdef VERTEX_UVS_A
+    out.uv = vertex.uv;
+#endif
+#ifdef VERTEX_UVS_B
+    out.uv_b = vertex.uv_b;
+#endif
+
+#ifdef NORMAL_PREPASS_OR_DEFERRED_PREPASS
+    out.world_normal = mesh_functions::mesh_normal_local_to_world(pose.normal, vertex.instance_index);
+#endif
+
+#ifdef VERTEX_COLORS
+    out.color = vertex.color;
+#endif
+
+#ifdef MOTION_VECTOR_PREPASS
+    // Импостеры далеко — motion vectors без учёта прошлой позы
+    out.previous_world_position = mesh_functions::mesh_position_local_to_world(
+        mesh_functions::get_previous_world_from_local(vertex.instance_index),
+        vec4<f32>(pose.position, 1.0));
+#endif
+
+#ifdef VERTEX_OUTPUT_INSTANCE_INDEX
+    out.instance_index = vertex.instance_index;
+#endif
+#ifdef VISIBILITY_RANGE_DITHER
+    out.visibility_range_dither = mesh_functions::get_visibility_range_dither_level(
+        vertex.instance_index, world_from_local[3]);
+#endif
+
+    return out;
+}
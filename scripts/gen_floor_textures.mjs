@@ -0,0 +1,254 @@
+#!/usr/bin/env node
+/**
+ * Процедурные тайлящиеся текстуры пола биомов: грязь болота, снег, пепел, камень Нави.
+ * На каждый биом — diffuse + normal (OpenGL, +Y вверх) в assets/textures/floor/.
+ * Шум периодический (период = размер текстуры), поэтому тайлы стыкуются без швов.
+ * Зависимостей нет — PNG пишется через встроенный zlib.
+ *
+ * Использование:
+ *   node scripts/gen_floor_textures.mjs
+ */
+
+import { writeFileSync, mkdirSync } from 'node:fs';
+import { deflateSync } from 'node:zlib';
+
+const SIZE = 512;
+const OUT_DIR = 'assets/textures/floor';
+
+// === PNG ===
+
+const CRC_TABLE = new Uint32Array(256).map((_, n) => {
+    let c = n;
+    for (let k = 0; k < 8; k++) c = c & 1 ? 0xedb88320 ^ (c >>> 1) : c >>> 1;
+    return c >>> 0;
+});
+
+function crc32(buf) {
+    let c = 0xffffffff;
+    for (const b of buf) c = CRC_TABLE[(c ^ b) & 0xff] ^ (c >>> 8);
+    return (c ^ 0xffffffff) >>> 0;
+}
+
+function chunk(type, data) {
+    const len = Buffer.alloc(4);
+    len.writeUInt32BE(data.length);
+    const body = Buffer.concat([Buffer.from(type, 'ascii'), data]);
+    const crc = Buffer.alloc(4);
+    crc.writeUInt32BE(crc32(body));
+    return Buffer.concat([len, body, crc]);
+}
+
+/** rgb: Float32Array(SIZE*SIZE*3) в [0, 1] */
+function writePng(path, rgb) {
+    const raw = Buffer.alloc((SIZE * 3 + 1) * SIZE);
+    for (let y = 0; y < SIZE; y++) {
+        raw[y * (SIZE * 3 + 1)] = 0; // фильтр None
+        for (let x = 0; x < SIZE * 3; x++) {
+            const v = rgb[y * SIZE * 3 + x];
+            raw[y * (SIZE * 3 + 1) + 1 + x] = Math.round(Math.min(1, Math.max(0, v)) * 255);
+        }
+    }
+    const header = Buffer.alloc(13);
+    header.writeUInt32BE(SIZE, 0);
+    header.writeUInt32BE(SIZE, 4);
+    header[8] = 8; // бит на канал
+    header[9] = 2; // RGB
+    const png = Buffer.concat([
+        Buffer.from([0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a]),
+        chunk('IHDR', header),
+        chunk('IDAT', deflateSync(raw, { level: 9 })),
+        chunk('IEND', Buffer.alloc(0)),
+    ]);
+    writeFileSync(path, png);
+    console.log(`  ${path} (${(png.length / 1024).toFixed(0)} KB)`);
+}
+
+// === Шум ===
+
+/** Детерминированный хэш целых → [0, 1) */
+function hash(x, y, seed) {
+    let h = (x * 374761393 + y * 668265263 + seed * 2147483647) | 0;
+    h = Math.imul(h ^ (h >>> 13), 1274126177);
+    h ^= h >>> 16;
+    return (h >>> 0) / 4294967296;
+}
+
+const wrap = (v, p) => ((v % p) + p) % p;
+const smooth = (t) => t * t * (3 - 2 * t);
+const lerp = (a, b, t) => a + (b - a) * t;
+const clamp01 = (v) => Math.min(1, Math.max(0, v));
+const mix3 = (a, b, t) => [lerp(a[0], b[0], t), lerp(a[1], b[1], t), lerp(a[2], b[2], t)];
+
+/** Value noise с периодом `cells` ячеек на текстуру */
+function valueNoise(u, v, cells, seed) {
+    const x = u * cells;
+    const y = v * cells;
+    const x0 = Math.floor(x);
+    const y0 = Math.floor(y);
+    const tx = smooth(x - x0);
+    const ty = smooth(y - y0);
+    const h = (i, j) => hash(wrap(x0 + i, cells), wrap(y0 + j, cells), seed);
+    return lerp(lerp(h(0, 0), h(1, 0), tx), lerp(h(0, 1), h(1, 1), tx), ty);
+}
+
+/** fBm: октавы с удвоением частоты, результат ≈ [0, 1] */
+function fbm(u, v, cells, octaves, seed) {
+    let sum = 0;
+    let amp = 0.5;
+    let norm = 0;
+    for (let o = 0; o < octaves; o++) {
+        sum += valueNoise(u, v, cells << o, seed + o * 101) * amp;
+        norm += amp;
+        amp *= 0.5;
+    }
+    return sum / norm;
+}
+
+/** Периодический Вороной: f1, f2, расстояние до шва (в долях ячейки) и id ближайшей точки */
+function voronoi(u, v, cells, seed) {
+    const x = u * cells;
+    const y = v * cells;
+    const cx = Math.floor(x);
+    const cy = Math.floor(y);
+    let f1 = 9;
+    let f2 = 9;
+    let p1 = [0, 0];
+    let p2 = [0, 0];
+    let id = 0;
+    // 5×5 ячеек: с джиттером второй сосед может лежать через одну — иначе ложные швы
+    for (let j = -2; j <= 2; j++) {
+        for (let i = -2; i <= 2; i++) {
+            const gx = wrap(cx + i, cells);
+            const gy = wrap(cy + j, cells);
+            const px = cx + i + hash(gx, gy, seed);
+            const py = cy + j + hash(gx, gy, seed + 7);
+            const d = Math.hypot(px - x, py - y);
+            if (d < f1) {
+                f2 = f1;
+                p2 = p1;
+                f1 = d;
+                p1 = [px, py];
+                id = hash(gx, gy, seed + 13);
+            } else if (d < f2) {
+                f2 = d;
+                p2 = [px, py];
+            }
+        }
+    }
+    // До биссектрисы двух ближайших точек (f2 - f1 раздувает шов, где точки почти совпали)
+    const edge = (f2 * f2 - f1 * f1) / (2 * Math.max(1e-6, Math.hypot(p2[0] - p1[0], p2[1] - p1[1])));
+    return { f1, f2, edge, id };
+}
+
+// === Сборка карты ===
+
+/** sample(u, v) → { color: [r, g, b], height } — height в условных единицах */
+function bake(name, sample, bump) {
+    const color = new Float32Array(SIZE * SIZE * 3);
+    const height = new Float32Array(SIZE * SIZE);
+    for (let y = 0; y < SIZE; y++) {
+        for (let x = 0; x < SIZE; x++) {
+            const s = sample(x / SIZE, y / SIZE, x, y);
+            const i = y * SIZE + x;
+            color.set(s.color, i * 3);
+            height[i] = s.height;
+        }
+    }
+
+    // Нормали из высоты (центральные разности с заворотом — без шва на краю тайла)
+    const normal = new Float32Array(SIZE * SIZE * 3);
+    const h = (x, y) => height[wrap(y, SIZE) * SIZE + wrap(x, SIZE)];
+    for (let y = 0; y < SIZE; y++) {
+        for (let x = 0; x < SIZE; x++) {
+            const dx = (h(x + 1, y) - h(x - 1, y)) * bump;
+            const dy = (h(x, y - 1) - h(x, y + 1)) * bump; // +Y — вверх по картинке
+            const len = Math.hypot(dx, dy, 1);
+            const i = (y * SIZE + x) * 3;
+            normal[i] = (-dx / len) * 0.5 + 0.5;
+            normal[i + 1] = (-dy / len) * 0.5 + 0.5;
+            normal[i + 2] = (1 / len) * 0.5 + 0.5;
+        }
+    }
+
+    console.log(name);
+    writePng(`${OUT_DIR}/${name}_diff.png`, color);
+    writePng(`${OUT_DIR}/${name}_nor.png`, normal);
+}
+
+// === Биомы ===
+
+/** Болото: бурая грязь, мокрые тёмные лужи в низинах, редкая галька */
+function mud(u, v) {
+    const base = fbm(u, v, 4, 6, 11);
+    const detail = fbm(u, v, 32, 3, 23);
+    const wet = smooth(clamp01((0.42 - fbm(u, v, 3, 4, 37)) * 6));
+    const grain = fbm(u, v, 96, 2, 29);
+    const pebble = voronoi(u, v, 28, 41);
+    const radius = 0.12 + hash(Math.floor(pebble.id * 1e6), 0, 43) * 0.25;
+    const stone = pebble.id > 0.9 ? smooth(clamp01((radius - pebble.f1) * 10)) : 0;
+
+    let color = mix3([0.22, 0.16, 0.10], [0.38, 0.29, 0.18], base * 0.6 + detail * 0.25 + grain * 0.15);
+    color = mix3(color, [0.12, 0.12, 0.08], wet * 0.75);
+    color = mix3(color, [0.30, 0.27, 0.22], stone * 0.8);
+
+    const height = base * 0.6 + detail * 0.25 + grain * 0.1 - wet * 0.3 + stone * 0.4;
+    return { color, height };
+}
+
+/** Зима: голубоватый снег, следы ветра (тайлящиеся синусы), искры наста */
+function snow(u, v, x, y) {
+    const drift = fbm(u, v, 3, 5, 51);
+    const warp = fbm(u, v, 6, 3, 57) * 6;
+    const ripple = Math.sin(2 * Math.PI * (u * 14 + v * 5) + warp) * 0.5 + 0.5;
+    const grain = fbm(u, v, 64, 2, 61);
+    const sparkle = hash(x, y, 67) > 0.9975 ? 1 : 0;
+
+    let color = mix3([0.70, 0.76, 0.86], [0.93, 0.95, 0.98], drift * 0.75 + ripple * 0.15 + grain * 0.1);
+    color = mix3(color, [1, 1, 1], sparkle * 0.8);
+
+    const height = drift * 0.7 + ripple * 0.12 + grain * 0.08;
+    return { color, height };
+}
+
+/** Пепелище: серый пепел, угли, трещины по спёкшейся корке, редкие тлеющие искры */
+function ash(u, v, x, y) {
+    const base = fbm(u, v, 5, 6, 71);
+    const grain = fbm(u, v, 96, 2, 75);
+    const crust = voronoi(u, v, 8, 73);
+    // Трескается не каждый шов корки — маска из шума
+    const cracked = smooth(clamp01((fbm(u, v, 6, 3, 77) - 0.45) * 5));
+    const crack = smooth(clamp01((0.02 - crust.edge) * 50)) * cracked;
+    const coal = voronoi(u, v, 36, 79);
+    const radius = 0.15 + hash(Math.floor(coal.id * 1e6), 0, 81) * 0.3;
+    const char = coal.id > 0.88 ? smooth(clamp01((radius - coal.f1) * 6)) : 0;
+    const ember = char > 0.6 && hash(x, y, 83) > 0.97 ? 1 : 0;
+
+    let color = mix3([0.17, 0.16, 0.15], [0.44, 0.42, 0.40], base * 0.75 + crust.id * 0.1 + grain * 0.15);
+    color = mix3(color, [0.05, 0.05, 0.05], Math.max(crack * 0.85, char * 0.9));
+    color = mix3(color, [0.85, 0.32, 0.08], ember);
+
+    const height = base * 0.5 + crust.id * 0.1 + grain * 0.1 - crack * 0.4 + char * 0.25;
+    return { color, height };
+}
+
+/** Навь: лиловые плиты со швами, скол по краям, бледный лишайник */
+function naviStone(u, v) {
+    const slab = voronoi(u, v, 6, 91);
+    const grout = smooth(clamp01((0.035 - slab.edge) * 40));
+    const bevel = smooth(clamp01(slab.edge * 8));
+    const grain = fbm(u, v, 24, 4, 97);
+    const lichen = smooth(clamp01((fbm(u, v, 8, 4, 101) - 0.58) * 8));
+
+    let color = mix3([0.20, 0.18, 0.25], [0.34, 0.31, 0.40], slab.id * 0.6 + grain * 0.4);
+    color = mix3(color, [0.36, 0.26, 0.48], lichen * 0.6 * (1 - grout));
+    color = mix3(color, [0.06, 0.05, 0.08], grout);
+
+    const height = bevel * 0.6 + grain * 0.2 + slab.id * 0.1 - grout * 0.3;
+    return { color, height };
+}
+
+mkdirSync(OUT_DIR, { recursive: true });
+bake('mud', mud, 6);
+bake('snow', snow, 3);
+bake('ash', ash, 5);
+bake('navi_stone', naviStone, 8);
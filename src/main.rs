@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use chertogon::config::game_config;
-use chertogon::modules::{WorldPlugin, InputPlugin, PlayerPlugin, CameraPlugin, EnemiesPlugin, CombatPlugin, MenuPlugin, SelectionPlugin, ProgressionPlugin};
+use chertogon::modules::{WorldPlugin, InputPlugin, PlayerPlugin, CameraPlugin, EnemiesPlugin, CombatPlugin, MenuPlugin, SelectionPlugin, ProgressionPlugin, SpatialPlugin, AnimClipsPlugin, HazardsPlugin, ShrinesPlugin, LootPlugin, SoundPlugin};
 
 fn main() {
     let mut app = App::new();
@@ -12,13 +12,19 @@ fn main() {
     app.add_plugins((
         WorldPlugin,
         InputPlugin,
+        AnimClipsPlugin,
         PlayerPlugin,
         CameraPlugin,
+        SpatialPlugin,
         EnemiesPlugin,
         CombatPlugin,
         MenuPlugin,
         SelectionPlugin,
         ProgressionPlugin,
+        HazardsPlugin,
+        ShrinesPlugin,
+        LootPlugin,
+        SoundPlugin,
     ));
 
     app.run();
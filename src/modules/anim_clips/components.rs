@@ -0,0 +1,107 @@
+use crate::toolkit::asset_paths;
+
+/// Логическая анимация — код оперирует ею, а не номером клипа в GLB
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AnimSlot {
+    Idle,
+    Walk,
+    Run,
+    Attack,
+    /// Второй и третий удары комбо богатыря
+    AttackRight,
+    AttackSlam,
+    Hit,
+    Death,
+    Scream,
+}
+
+/// Что играет вместо отсутствующего клипа (вместо паники / T-позы)
+pub const FALLBACK_SLOT: AnimSlot = AnimSlot::Idle;
+
+impl AnimSlot {
+    /// Замена отсутствующего клипа: удары комбо — базовый замах, остальное — FALLBACK_SLOT
+    pub fn fallback(self) -> AnimSlot {
+        match self {
+            AnimSlot::AttackRight | AnimSlot::AttackSlam => AnimSlot::Attack,
+            _ => FALLBACK_SLOT,
+        }
+    }
+}
+
+/// Таблица модели: логическая анимация → имя клипа в GLB.
+/// Имя — как в Gltf::named_animations; Meshy-обёртка "Armature|<имя>|baselayer" допускается.
+pub struct ClipTable {
+    /// Имя модели для логов валидации
+    pub label: &'static str,
+    /// Путь к GLB (без #метки)
+    pub glb: &'static str,
+    pub clips: &'static [(AnimSlot, &'static str)],
+}
+
+impl ClipTable {
+    pub fn clip_name(&self, slot: AnimSlot) -> Option<&'static str> {
+        self.clips.iter().find(|(s, _)| *s == slot).map(|(_, name)| *name)
+    }
+}
+
+pub const BOGATYR_CLIPS: ClipTable = ClipTable {
+    label: "Богатырь",
+    glb: asset_paths::BOGATYR_GLB,
+    clips: &[
+        (AnimSlot::Idle, "Idle"),
+        (AnimSlot::Walk, "walking_man"),
+        (AnimSlot::Run, "running"),
+        (AnimSlot::Attack, "Attack"),
+        // Клипы комбо вливаются scripts/merge_animation.mjs. Пока их нет в GLB — играет
+        // Attack (AnimSlot::fallback), правый косой задом наперёд (ComboSwing::fallback_reversed)
+        (AnimSlot::AttackRight, "Attack_Right"),
+        (AnimSlot::AttackSlam, "Attack_Slam"),
+        (AnimSlot::Hit, "Hit Reaction"),
+    ],
+};
+
+pub const UPYR_CLIPS: ClipTable = ClipTable {
+    label: "Упырь",
+    glb: asset_paths::UPYR_GLB,
+    clips: &[
+        (AnimSlot::Idle, "Idle"),
+        (AnimSlot::Walk, "Monster_Walk"),
+        (AnimSlot::Run, "running"),
+        (AnimSlot::Attack, "Attack"),
+        (AnimSlot::Death, "Dead"),
+        (AnimSlot::Hit, "Hit_Reaction"),
+        (AnimSlot::Scream, "Zombie_Scream"),
+    ],
+};
+
+/// Имена задаёт scripts/merge_leshiy_anims.mjs
+pub const LESHIY_CLIPS: ClipTable = ClipTable {
+    label: "Леший",
+    glb: asset_paths::LESHIY_GLB,
+    clips: &[
+        (AnimSlot::Idle, "idle"),
+        (AnimSlot::Walk, "walk"),
+        (AnimSlot::Run, "run"),
+        (AnimSlot::Attack, "attack"),
+        (AnimSlot::Death, "death"),
+        (AnimSlot::Hit, "hit"),
+    ],
+};
+
+/// Своего крика нет — вой играет idle (вес 0.5 задаёт граф)
+pub const VOLKOLAK_CLIPS: ClipTable = ClipTable {
+    label: "Волколак",
+    glb: asset_paths::VOLKOLAK_GLB,
+    clips: &[
+        (AnimSlot::Idle, "idle"),
+        (AnimSlot::Walk, "walk"),
+        (AnimSlot::Run, "run"),
+        (AnimSlot::Attack, "attack"),
+        (AnimSlot::Hit, "hit"),
+        (AnimSlot::Death, "death"),
+        (AnimSlot::Scream, "idle"),
+    ],
+};
+
+/// Все таблицы — для предзагрузки GLB и стартовой валидации
+pub const ALL_CLIP_TABLES: &[&ClipTable] = &[&BOGATYR_CLIPS, &UPYR_CLIPS, &LESHIY_CLIPS, &VOLKOLAK_CLIPS];
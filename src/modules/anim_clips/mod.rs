@@ -0,0 +1,8 @@
+pub mod components;
+pub mod plugin;
+mod parts;
+
+// Публичный API
+pub use plugin::AnimClipsPlugin;
+pub use components::{AnimSlot, ClipTable, ALL_CLIP_TABLES, BOGATYR_CLIPS, UPYR_CLIPS, LESHIY_CLIPS, VOLKOLAK_CLIPS};
+pub use parts::library::{AnimClipLibrary, ClipGraph};
@@ -0,0 +1,197 @@
+use bevy::prelude::*;
+use bevy::animation::graph::{AnimationMask, AnimationNodeType};
+use bevy::gltf::Gltf;
+use std::collections::{HashMap, HashSet};
+use crate::modules::anim_clips::components::{AnimSlot, ClipTable, FALLBACK_SLOT};
+
+/// Результат поиска клипа по имени
+pub enum ClipMatch {
+    /// Имя совпало (полное или внутри Meshy-обёртки)
+    Exact(Handle<AnimationClip>),
+    /// Совпало без учёта регистра/пробелов/подчёркиваний — берём, но это опечатка в таблице
+    Fuzzy(Handle<AnimationClip>, String),
+    Missing,
+}
+
+/// "Armature|Idle|baselayer" → "Idle" (Meshy-экспорт), остальные имена как есть
+fn short_name(name: &str) -> &str {
+    let mut parts = name.split('|');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(_), Some(middle), Some(_)) => middle,
+        _ => name,
+    }
+}
+
+/// Ключ для нестрогого сравнения: "Hit Reaction" == "hit_reaction"
+fn loose_key(name: &str) -> String {
+    short_name(name)
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '_' | '-'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Ищет клип в Gltf::named_animations: полное имя → короткое имя → нестрогое совпадение
+pub fn find_clip(gltf: &Gltf, wanted: &str) -> ClipMatch {
+    if let Some(handle) = gltf.named_animations.get(wanted) {
+        return ClipMatch::Exact(handle.clone());
+    }
+    if let Some((_, handle)) = gltf.named_animations.iter().find(|(name, _)| short_name(name) == wanted) {
+        return ClipMatch::Exact(handle.clone());
+    }
+    let key = loose_key(wanted);
+    match gltf.named_animations.iter().find(|(name, _)| loose_key(name) == key) {
+        Some((name, handle)) => ClipMatch::Fuzzy(handle.clone(), name.to_string()),
+        None => ClipMatch::Missing,
+    }
+}
+
+fn lookup_clip(gltf: &Gltf, table: &ClipTable, slot: AnimSlot) -> Option<Handle<AnimationClip>> {
+    match table.clip_name(slot).map(|name| find_clip(gltf, name)) {
+        Some(ClipMatch::Exact(handle) | ClipMatch::Fuzzy(handle, _)) => Some(handle),
+        _ => None,
+    }
+}
+
+/// Клип для слота: по имени из таблицы → замена слота (AnimSlot::fallback) → idle → первый клип GLB.
+/// None только если в GLB вообще нет анимаций.
+pub fn resolve_clip(gltf: &Gltf, table: &ClipTable, slot: AnimSlot) -> Option<Handle<AnimationClip>> {
+    lookup_clip(gltf, table, slot)
+        .or_else(|| lookup_clip(gltf, table, slot.fallback()))
+        .or_else(|| lookup_clip(gltf, table, FALLBACK_SLOT))
+        .or_else(|| gltf.animations.first().cloned())
+}
+
+/// AnimationGraph с узлами-слотами: клипы подставляются по имени, когда GLB загружен.
+/// Индексы узлов стабильны — их можно раздавать сразу.
+pub struct ClipGraph {
+    table: &'static ClipTable,
+    graph: AnimationGraph,
+    nodes: Vec<(AnimSlot, AnimationNodeIndex)>,
+}
+
+impl ClipGraph {
+    pub fn new(table: &'static ClipTable) -> Self {
+        Self { table, graph: AnimationGraph::new(), nodes: Vec::new() }
+    }
+
+    /// Узел клипа под корнем графа (клип пока пустой — заполнит bind)
+    pub fn add(&mut self, slot: AnimSlot, weight: f32) -> AnimationNodeIndex {
+        self.add_masked(slot, 0, weight)
+    }
+
+    /// То же с маской: узел не трогает кости групп из mask (слои верх/низ тела)
+    pub fn add_masked(&mut self, slot: AnimSlot, mask: AnimationMask, weight: f32) -> AnimationNodeIndex {
+        let index = self.graph.add_clip_with_mask(Handle::default(), mask, weight, self.graph.root);
+        self.nodes.push((slot, index));
+        index
+    }
+}
+
+/// Граф, ждущий загрузки своего GLB
+struct PendingGraph {
+    table: &'static ClipTable,
+    graph: Handle<AnimationGraph>,
+    nodes: Vec<(AnimSlot, AnimationNodeIndex)>,
+}
+
+/// Источники анимаций (Gltf по таблицам) + графы, ждущие подстановки клипов
+#[derive(Resource, Default)]
+pub struct AnimClipLibrary {
+    sources: HashMap<&'static str, Handle<Gltf>>,
+    pending: Vec<PendingGraph>,
+    validated: HashSet<&'static str>,
+    /// Слоты, чей клип не нашёлся в GLB и играет замена: (путь GLB, слот)
+    fallbacks: HashSet<(&'static str, AnimSlot)>,
+}
+
+fn bind_nodes(
+    graph: &mut AnimationGraph,
+    nodes: &[(AnimSlot, AnimationNodeIndex)],
+    table: &'static ClipTable,
+    gltf: &Gltf,
+    fallbacks: &mut HashSet<(&'static str, AnimSlot)>,
+) {
+    for &(slot, index) in nodes {
+        if lookup_clip(gltf, table, slot).is_none() {
+            fallbacks.insert((table.glb, slot));
+        }
+        let Some(handle) = resolve_clip(gltf, table, slot) else { continue };
+        if let Some(node) = graph.get_mut(index) {
+            node.node_type = AnimationNodeType::Clip(handle);
+        }
+    }
+}
+
+impl AnimClipLibrary {
+    /// Strong handle на Gltf модели (держится всю игру — named_animations не выгружаются)
+    pub fn request(&mut self, asset_server: &AssetServer, table: &'static ClipTable) -> Handle<Gltf> {
+        self.sources.entry(table.glb).or_insert_with(|| asset_server.load(table.glb)).clone()
+    }
+
+    /// Регистрирует граф: клипы подставляются сразу (GLB уже загружен экраном загрузки)
+    /// или в resolve_pending_clip_graphs, как только он догрузится.
+    pub fn build(
+        &mut self,
+        clip_graph: ClipGraph,
+        asset_server: &AssetServer,
+        gltfs: &Assets<Gltf>,
+        graphs: &mut Assets<AnimationGraph>,
+    ) -> Handle<AnimationGraph> {
+        let ClipGraph { table, mut graph, nodes } = clip_graph;
+        let source = self.request(asset_server, table);
+        if let Some(gltf) = gltfs.get(&source) {
+            bind_nodes(&mut graph, &nodes, table, gltf, &mut self.fallbacks);
+            return graphs.add(graph);
+        }
+        let handle = graphs.add(graph);
+        self.pending.push(PendingGraph { table, graph: handle.clone(), nodes });
+        handle
+    }
+
+    /// Слот модели играет замену вместо своего клипа (известно после загрузки GLB)
+    pub fn uses_fallback(&self, table: &ClipTable, slot: AnimSlot) -> bool {
+        self.fallbacks.contains(&(table.glb, slot))
+    }
+
+    pub(crate) fn source(&self, table: &ClipTable) -> Option<&Handle<Gltf>> {
+        self.sources.get(table.glb)
+    }
+
+    pub(crate) fn is_validated(&self, table: &ClipTable) -> bool {
+        self.validated.contains(table.glb)
+    }
+
+    pub(crate) fn mark_validated(&mut self, table: &'static ClipTable) {
+        self.validated.insert(table.glb);
+    }
+
+    pub(crate) fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Подставляет клипы в графы, чьи GLB загрузились; упавшие загрузки отбрасываются с ошибкой
+    pub(crate) fn resolve_pending(
+        &mut self,
+        asset_server: &AssetServer,
+        gltfs: &Assets<Gltf>,
+        graphs: &mut Assets<AnimationGraph>,
+    ) {
+        let sources = &self.sources;
+        let fallbacks = &mut self.fallbacks;
+        self.pending.retain(|pending| {
+            let Some(source) = sources.get(pending.table.glb) else { return false };
+            if let Some(gltf) = gltfs.get(source) {
+                if let Some(graph) = graphs.get_mut(&pending.graph) {
+                    bind_nodes(graph, &pending.nodes, pending.table, gltf, fallbacks);
+                }
+                return false;
+            }
+            if asset_server.load_state(source.id()).is_failed() {
+                error!("❌ {}: {} не загрузился — анимации недоступны", pending.table.label, pending.table.glb);
+                return false;
+            }
+            true
+        });
+    }
+}
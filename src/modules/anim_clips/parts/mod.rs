@@ -0,0 +1,6 @@
+//! Анимации по имени клипа:
+//! - library: AnimClipLibrary (Gltf модели) + ClipGraph (граф со слотами, клипы подставляются по имени)
+//! - validate: предзагрузка GLB, подстановка отложенных графов, стартовая проверка таблиц
+
+pub mod library;
+pub mod validate;
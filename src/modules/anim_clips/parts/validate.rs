@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+use bevy::gltf::Gltf;
+use crate::modules::anim_clips::components::ALL_CLIP_TABLES;
+use super::library::{find_clip, AnimClipLibrary, ClipMatch};
+
+/// Запрашивает GLB всех таблиц при входе в Loading (экран загрузки их дожидается)
+pub fn request_clip_sources(
+    mut library: ResMut<AnimClipLibrary>,
+    asset_server: Res<AssetServer>,
+) {
+    for table in ALL_CLIP_TABLES {
+        library.request(&asset_server, table);
+    }
+}
+
+/// Подстановка клипов в графы, созданные до загрузки GLB
+pub fn resolve_pending_clip_graphs(
+    mut library: ResMut<AnimClipLibrary>,
+    asset_server: Res<AssetServer>,
+    gltfs: Res<Assets<Gltf>>,
+    mut graphs: ResMut<Assets<AnimationGraph>>,
+) {
+    if !library.has_pending() {
+        return;
+    }
+    library.resolve_pending(&asset_server, &gltfs, &mut graphs);
+}
+
+/// Стартовая валидация: один раз на модель, как только её GLB загружен.
+/// Отсутствующие клипы → error (играет fallback), опечатки в регистре/пробелах → warn.
+pub fn validate_clip_tables(
+    mut library: ResMut<AnimClipLibrary>,
+    gltfs: Res<Assets<Gltf>>,
+) {
+    for table in ALL_CLIP_TABLES {
+        if library.is_validated(table) {
+            continue;
+        }
+        let Some(gltf) = library.source(table).and_then(|source| gltfs.get(source)) else { continue };
+
+        let mut problems = 0;
+        for &(slot, name) in table.clips {
+            match find_clip(gltf, name) {
+                ClipMatch::Exact(_) => {}
+                ClipMatch::Fuzzy(_, actual) => {
+                    problems += 1;
+                    warn!("⚠️ {}: клип '{}' ({:?}) найден как '{}' — поправьте имя в таблице", table.label, name, slot, actual);
+                }
+                ClipMatch::Missing => {
+                    problems += 1;
+                    error!("❌ {}: клип '{}' ({:?}) не найден — играет {:?}", table.label, name, slot, slot.fallback());
+                }
+            }
+        }
+
+        if problems == 0 {
+            info!("🎞️ {}: {} клипов найдены по имени", table.label, table.clips.len());
+        } else {
+            let mut available: Vec<&str> = gltf.named_animations.keys().map(|name| name.as_ref()).collect();
+            available.sort_unstable();
+            warn!("🎞️ {}: клипы в {}: {:?}", table.label, table.glb, available);
+        }
+        library.mark_validated(table);
+    }
+}
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use crate::shared::GameState;
+use super::parts::{library::AnimClipLibrary, validate};
+
+pub struct AnimClipsPlugin;
+
+impl Plugin for AnimClipsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<AnimClipLibrary>()
+            .add_systems(OnEnter(GameState::Loading), validate::request_clip_sources)
+            // Работают во всех состояниях: GLB может догрузиться и после экрана загрузки
+            .add_systems(Update, (
+                validate::validate_clip_tables,
+                validate::resolve_pending_clip_graphs,
+            ).chain());
+
+        info!("🎞️ AnimClipsPlugin loaded (named clips, fallback → idle)");
+    }
+}
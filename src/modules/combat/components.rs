@@ -66,6 +66,45 @@ impl PlayerHealth {
     }
 }
 
+/// Ярость: копится от убийств и нанесённого урона, полная шкала открывает ульту героя
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Rage {
+    pub current: f32,
+    pub max: f32,
+}
+
+impl Rage {
+    pub fn new(max: f32) -> Self {
+        Self { current: 0.0, max }
+    }
+
+    pub fn add(&mut self, amount: f32) {
+        self.current = (self.current + amount).min(self.max);
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.current >= self.max
+    }
+
+    pub fn fraction(&self) -> f32 {
+        (self.current / self.max).clamp(0.0, 1.0)
+    }
+}
+
+/// Окно неуязвимости (i-frames): контактный урон и снаряды пропускают носителя
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Invulnerable {
+    pub timer: Timer,
+}
+
+impl Invulnerable {
+    pub fn new(duration: f32) -> Self {
+        Self { timer: Timer::from_seconds(duration, TimerMode::Once) }
+    }
+}
+
 /// Отложенный удар — урон наносится при ударе анимации, а не в начале замаха
 #[derive(Component, Reflect)]
 #[reflect(Component)]
@@ -76,6 +115,40 @@ pub struct PendingAttack {
     pub timer: Timer,
     /// Максимальная дистанция для попадания (weapon.range * MISS_RANGE_MULTIPLIER)
     pub max_range: f32,
+    /// Номер удара в COMBO_SWINGS (дуга, отбрасывание, форма slash)
+    pub swing: usize,
+}
+
+/// Разрушаемый объект (портал в окне уязвимости, урна, бочка): автоатака бьёт его,
+/// только когда врагов в радиусе нет. Что происходит при нуле — решает модуль-владелец.
+#[derive(Component)]
+pub struct Destructible {
+    pub current: f32,
+    pub max: f32,
+    /// Радиус «тела» — дистанция удара считается до его края
+    pub hit_radius: f32,
+    /// Сейчас можно бить (портал — только в окне после волны)
+    pub vulnerable: bool,
+}
+
+impl Destructible {
+    pub fn new(max: f32, hit_radius: f32) -> Self {
+        Self { current: max, max, hit_radius, vulnerable: true }
+    }
+
+    pub fn take_damage(&mut self, amount: f32) {
+        self.current = (self.current - amount).max(0.0);
+    }
+
+    pub fn is_broken(&self) -> bool {
+        self.current <= 0.0
+    }
+
+    /// Доступен для удара из `from` с дальностью `range` (по плоскости XZ, до края тела)
+    pub fn in_reach(&self, position: Vec3, from: Vec3, range: f32) -> bool {
+        let offset = position - from;
+        Vec2::new(offset.x, offset.z).length() - self.hit_radius <= range
+    }
 }
 
 /// Таймер контактного урона врага (чтобы не бил каждый кадр)
@@ -3,6 +3,7 @@ pub mod plugin;
 pub(crate) mod parts;
 
 pub use plugin::CombatPlugin;
-pub use components::{Weapon, AttackCooldown, PlayerHealth, EnemyAttackCooldown};
+pub use components::{Weapon, AttackCooldown, PlayerHealth, EnemyAttackCooldown, Rage, Destructible};
+pub use parts::ultimate::{HeroUltimate, UltimateDef, PERUN_STORM};
 pub use parts::camera_shake::CameraShake;
-pub use parts::game_over::KillCount;
+pub use parts::game_over::{KillCount, RunOutcome};
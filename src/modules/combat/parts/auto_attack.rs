@@ -1,9 +1,13 @@
 use bevy::prelude::*;
 use avian3d::prelude::*;
 use crate::modules::player::components::{Player, PlayerAnimState, AnimationState, PlayerModel};
-use crate::modules::enemies::components::{Enemy, Health, EnemyDying, EnemyModel, EnemyAnimState, EnemyAnim};
-use crate::modules::combat::components::{Weapon, AttackCooldown, AttackAnimTimer, PendingAttack, MISS_RANGE_MULTIPLIER};
+use crate::modules::enemies::components::{Enemy, Health, EnemyDying, EnemyModel, EnemyAnimState, EnemyAnim, TargetPriority};
+use crate::modules::combat::components::{Weapon, AttackCooldown, AttackAnimTimer, PendingAttack, Rage, Destructible, MISS_RANGE_MULTIPLIER};
+use crate::modules::spatial::{SpatialIndex, SpatialLayer};
 use super::camera_shake::CameraShake;
+use super::combo::{ComboState, COMBO_SWINGS};
+use super::hitstop::Hitstop;
+use super::ultimate::RAGE_PER_DAMAGE;
 use super::knockback::Staggered;
 use super::hit_flash::HitFlash;
 use super::vfx_assets::HitVfxAssets;
@@ -13,18 +17,23 @@ use super::blood_decals;
 use super::hit_particles;
 use super::impact_flash;
 
-/// Автоатака игрока: находит ближайшего врага → запускает замах → урон по таймеру
+/// Автоатака игрока: находит приоритетного врага → запускает очередной удар комбо → урон по таймеру.
+/// Приоритет = дистанция, делённая на TargetPriority.weight (Колдун выбирается раньше соседей).
+/// Кандидаты — из SpatialIndex в радиусе оружия, без обхода всех врагов.
+/// Врагов рядом нет — целью становится ближайший уязвимый Destructible (портал, урна).
 pub fn player_auto_attack_system(
     time: Res<Time>,
+    index: Res<SpatialIndex>,
     mut player_query: Query<
-        (Entity, &Weapon, &mut AttackCooldown, &Children, &Transform, &mut PlayerAnimState),
+        (Entity, &Weapon, &mut AttackCooldown, &mut ComboState, &Children, &Transform, &mut PlayerAnimState),
         (With<Player>, Without<PendingAttack>)
     >,
-    enemies: Query<(Entity, &Transform, &Health), (With<Enemy>, Without<EnemyDying>)>,
+    enemies: Query<(&Health, Option<&TargetPriority>), (With<Enemy>, Without<EnemyDying>)>,
+    destructibles: Query<(Entity, &GlobalTransform, &Destructible)>,
     mut model_query: Query<&mut Transform, (With<PlayerModel>, Without<Player>, Without<Enemy>)>,
     mut commands: Commands,
 ) {
-    let Ok((player_entity, weapon, mut cooldown, children, player_transform, mut state)) =
+    let Ok((player_entity, weapon, mut cooldown, mut combo, children, player_transform, mut state)) =
         player_query.single_mut() else { return };
 
     // Во время стаггера нельзя атаковать (ARPG стандарт: action lock)
@@ -41,23 +50,46 @@ pub fn player_auto_attack_system(
 
     let player_pos = player_transform.translation;
 
-    // Ищем ближайшего ЖИВОГО врага в радиусе (length_squared — без sqrt)
+    // Ищем приоритетного ЖИВОГО врага в радиусе (length_squared — без sqrt)
     let range_sq = weapon.range * weapon.range;
     let mut closest: Option<(Entity, f32, Vec3)> = None;
-    for (entity, enemy_transform, health) in &enemies {
-        if health.is_dead() { continue; }
-        let enemy_pos = enemy_transform.translation;
+    index.for_each_in_radius(SpatialLayer::Enemy, player_pos, weapon.range, |entity, enemy_pos| {
+        let Ok((health, priority)) = enemies.get(entity) else { return };
+        if health.is_dead() { return; }
         let dist_sq = (enemy_pos - player_pos).length_squared();
         if dist_sq <= range_sq {
-            if closest.is_none() || dist_sq < closest.unwrap().1 {
-                closest = Some((entity, dist_sq, enemy_pos));
+            // Оценка: дистанция² / weight² — приоритетные цели "ближе" остальных
+            let weight = priority.map_or(1.0, |p| p.weight);
+            let score = dist_sq / (weight * weight);
+            if closest.is_none_or(|c| score < c.1) {
+                closest = Some((entity, score, enemy_pos));
+            }
+        }
+    });
+
+    // Врагов нет — ближайший уязвимый разрушаемый объект (до края тела)
+    if closest.is_none() {
+        for (entity, transform, destructible) in &destructibles {
+            let pos = transform.translation();
+            if !destructible.vulnerable || destructible.is_broken() || !destructible.in_reach(pos, player_pos, weapon.range) {
+                continue;
+            }
+            let dist_sq = (pos - player_pos).xz().length_squared();
+            if closest.is_none_or(|c| dist_sq < c.1) {
+                closest = Some((entity, dist_sq, Vec3::new(pos.x, player_pos.y, pos.z)));
             }
         }
     }
 
-    let Some((target_entity, _distance, target_pos)) = closest else { return };
+    // Целей нет — цепочка комбо обрывается
+    let Some((target_entity, _distance, target_pos)) = closest else {
+        combo.reset();
+        return;
+    };
 
-    // Нашли цель — запускаем замах!
+    // Нашли цель — запускаем очередной удар комбо!
+    let step = combo.advance(cooldown.timer.duration().as_secs_f32());
+    let swing = &COMBO_SWINGS[step];
 
     // Поворачиваем модель к врагу
     let direction = (target_pos - player_pos).normalize_or_zero();
@@ -71,43 +103,53 @@ pub fn player_auto_attack_system(
         }
     }
 
-    // Только ставим состояние — центральная система применит анимацию со скоростью 2.5×
+    // Только ставим состояние — центральная система применит клип удара с его скоростью
     state.current = AnimationState::Attacking;
+    state.swing = step;
 
-    // Откладываем урон до момента удара (0.42с в анимацию при 2.5× скорости)
+    // Откладываем урон до момента удара (у каждого удара свой тайминг)
     commands.entity(player_entity).insert(PendingAttack {
         target: target_entity,
-        damage: weapon.damage,
+        damage: weapon.damage * swing.damage_mult,
         direction: direction_2d,
-        timer: Timer::from_seconds(0.42, TimerMode::Once),
+        timer: Timer::from_seconds(swing.hit_delay, TimerMode::Once),
         max_range: weapon.range * MISS_RANGE_MULTIPLIER,
+        swing: step,
     });
 
     // Сбрасываем cooldown
     cooldown.timer.reset();
 
-    // Таймер для сброса анимации атаки (0.5с)
+    // Таймер для сброса анимации атаки
     commands.entity(player_entity).insert(
         AttackAnimTimer {
-            timer: Timer::from_seconds(0.5, TimerMode::Once),
+            timer: Timer::from_seconds(swing.anim_duration, TimerMode::Once),
         }
     );
+
+    debug!("⚔️ Combo {}/{}: {}", step + 1, COMBO_SWINGS.len(), swing.name);
 }
 
-/// Наносит урон при ударе анимации (после задержки замаха)
+/// Наносит урон при ударе анимации (после задержки замаха).
+/// Бьёт цель и всех врагов в дуге удара (конус из SpatialIndex); финишер — сильнее
+/// отбрасывает и даёт hitstop. Цель-Destructible получает урон, если ещё в досягаемости.
 pub fn apply_pending_attack_system(
     time: Res<Time>,
-    mut player_query: Query<(Entity, &Transform, &mut PendingAttack), With<Player>>,
+    index: Res<SpatialIndex>,
+    mut player_query: Query<(Entity, &Transform, &mut PendingAttack, &mut Rage), With<Player>>,
     mut enemies: Query<(&Transform, &mut Health, &mut LinearVelocity, &Children, &mut EnemyAnimState), (With<Enemy>, Without<EnemyDying>)>,
+    mut destructibles: Query<(&GlobalTransform, &mut Destructible)>,
     enemy_model_query: Query<Entity, With<EnemyModel>>,
     mut commands: Commands,
     mut camera_shake: ResMut<CameraShake>,
+    mut hitstop: ResMut<Hitstop>,
     vfx_assets: Res<HitVfxAssets>,
     blood_assets: Res<blood_decals::BloodDecalAssets>,
     mut slash_assets: ResMut<slash_vfx::SlashVfxAssets>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    mut victims: Local<Vec<Entity>>,
 ) {
-    for (player_entity, player_transform, mut pending) in &mut player_query {
+    for (player_entity, player_transform, mut pending, mut rage) in &mut player_query {
         pending.timer.tick(time.delta());
 
         if !pending.timer.is_finished() {
@@ -115,86 +157,124 @@ pub fn apply_pending_attack_system(
         }
 
         let player_pos = player_transform.translation;
+        let swing = &COMBO_SWINGS[pending.swing.min(COMBO_SWINGS.len() - 1)];
 
-        // Наносим урон — проверяем что враг ещё жив и существует
-        if let Ok((enemy_transform, mut health, mut velocity, children, mut anim_state)) = enemies.get_mut(pending.target) {
-            let enemy_pos = enemy_transform.translation;
-            let dist_sq = (enemy_pos - player_pos).length_squared();
-            let max_range_sq = pending.max_range * pending.max_range;
-
-            if dist_sq > max_range_sq {
-                // MISS: враг увернулся — только slash VFX, без попадания
-                slash_vfx::spawn_slash(
-                    &mut commands, &mut slash_assets, &mut materials,
-                    player_pos, pending.direction,
-                );
-                damage_numbers::spawn_miss_text(
-                    &mut commands, &vfx_assets.font,
-                    enemy_pos,
-                );
-                debug!(
-                    "⚔️ Player MISSES enemy! (dist² {:.1} > max_range² {:.1})",
-                    dist_sq, max_range_sq
-                );
-            } else if !health.is_dead() {
-                health.take_damage(pending.damage);
-
-                // VFX: Slash огненная дуга перед игроком
-                slash_vfx::spawn_slash(
-                    &mut commands, &mut slash_assets, &mut materials,
-                    player_pos, pending.direction,
-                );
-
-                // Hit particles — искры при попадании (кэшированные ассеты)
-                hit_particles::spawn_hit_particles(
-                    &mut commands, &vfx_assets,
-                    enemy_pos,
-                );
-
-                // Impact flash — вспышка в точке удара (emissive mesh)
-                impact_flash::spawn_impact_flash(
-                    &mut commands, &vfx_assets,
-                    enemy_pos,
-                );
-
-                // Camera shake — направленный толчок камеры
-                camera_shake.trigger(0.15, 0.15, pending.direction);
-
-                // Knockback — толкаем врага от игрока
-                let knockback_dir = pending.direction;
-                velocity.0 = knockback_dir * 8.0;
-                commands.entity(pending.target).insert(Staggered::new(0.35));
-
-                // Hit reaction анимация (guard: не перезаписывать если уже в HitReaction)
-                if anim_state.current != EnemyAnim::HitReaction {
-                    anim_state.current = EnemyAnim::HitReaction;
+        // Жертвы: цель (если ещё в досягаемости) + все в дуге удара
+        victims.clear();
+        let max_range_sq = pending.max_range * pending.max_range;
+        let target_pos = enemies.get(pending.target).ok().map(|(t, ..)| t.translation);
+        if target_pos.is_some_and(|pos| (pos - player_pos).length_squared() <= max_range_sq) {
+            victims.push(pending.target);
+        }
+        index.for_each_in_cone(
+            SpatialLayer::Enemy, player_pos, pending.direction, swing.arc_half_angle, pending.max_range,
+            |entity, _| {
+                if entity != pending.target {
+                    victims.push(entity);
                 }
+            },
+        );
 
-                // Hit flash — scale-pop на модели врага (не на parent, чтобы круг не двигался)
-                for child in children.iter() {
-                    if enemy_model_query.get(child).is_ok() {
-                        commands.entity(child).insert(HitFlash::new());
-                        break;
-                    }
+        // VFX: огненная дуга перед игроком — форма по удару комбо
+        slash_vfx::spawn_slash(
+            &mut commands, &mut slash_assets, &mut materials,
+            player_pos, pending.direction, swing.slash,
+        );
+
+        // Разрушаемая цель (врагов рядом не было): урон без ярости, отбрасывания и крови
+        let mut object_hit = false;
+        if let Ok((transform, mut destructible)) = destructibles.get_mut(pending.target) {
+            let pos = transform.translation();
+            if destructible.vulnerable && !destructible.is_broken() && destructible.in_reach(pos, player_pos, pending.max_range) {
+                destructible.take_damage(pending.damage);
+                let hit_pos = Vec3::new(pos.x, player_pos.y, pos.z) - pending.direction * destructible.hit_radius;
+                hit_particles::spawn_hit_particles(&mut commands, &vfx_assets, hit_pos);
+                impact_flash::spawn_impact_flash(&mut commands, &vfx_assets, hit_pos);
+                damage_numbers::spawn_damage_number(&mut commands, &vfx_assets.font, hit_pos, pending.damage);
+                object_hit = true;
+                debug!("⚔️ {} hits object for {} damage ({}/{})", swing.name, pending.damage, destructible.current, destructible.max);
+            }
+        }
+
+        let mut hits = 0;
+        for &victim in victims.iter() {
+            let Ok((enemy_transform, mut health, mut velocity, children, mut anim_state)) = enemies.get_mut(victim) else { continue };
+            if health.is_dead() {
+                continue;
+            }
+            let enemy_pos = enemy_transform.translation;
+            health.take_damage(pending.damage);
+            hits += 1;
+
+            // Hit particles — искры при попадании (кэшированные ассеты)
+            hit_particles::spawn_hit_particles(
+                &mut commands, &vfx_assets,
+                enemy_pos,
+            );
+
+            // Impact flash — вспышка в точке удара (emissive mesh)
+            impact_flash::spawn_impact_flash(
+                &mut commands, &vfx_assets,
+                enemy_pos,
+            );
+
+            // Knockback — толкаем врага от игрока (цель — по направлению удара, остальных — радиально)
+            let knockback_dir = if victim == pending.target {
+                pending.direction
+            } else {
+                Vec3::new(enemy_pos.x - player_pos.x, 0.0, enemy_pos.z - player_pos.z)
+                    .try_normalize()
+                    .unwrap_or(pending.direction)
+            };
+            velocity.0 = knockback_dir * swing.knockback;
+            commands.entity(victim).insert(Staggered::new(swing.stagger));
+
+            // Hit reaction анимация (guard: не перезаписывать если уже в HitReaction)
+            if anim_state.current != EnemyAnim::HitReaction {
+                anim_state.current = EnemyAnim::HitReaction;
+            }
+
+            // Hit flash — scale-pop на модели врага (не на parent, чтобы круг не двигался)
+            for child in children.iter() {
+                if enemy_model_query.get(child).is_ok() {
+                    commands.entity(child).insert(HitFlash::new());
+                    break;
                 }
+            }
+
+            // Damage number — всплывающее число урона
+            damage_numbers::spawn_damage_number(
+                &mut commands, &vfx_assets.font,
+                enemy_pos, pending.damage,
+            );
+
+            // Blood decal — пятно крови на полу (остаётся навсегда)
+            blood_decals::spawn_blood_decal(
+                &mut commands, &blood_assets,
+                enemy_pos, blood_decals::BloodColor::Red,
+            );
+
+            debug!(
+                "⚔️ {} hits enemy for {} damage! (HP: {}/{})",
+                swing.name, pending.damage, health.current, health.max
+            );
+        }
+
+        if hits > 0 {
+            // Ярость — за весь нанесённый урон (по дуге копится быстрее)
+            rage.add(pending.damage * hits as f32 * RAGE_PER_DAMAGE);
 
-                // Damage number — всплывающее число урона
-                damage_numbers::spawn_damage_number(
-                    &mut commands, &vfx_assets.font,
-                    enemy_pos, pending.damage,
-                );
-
-                // Blood decal — пятно крови на полу (остаётся навсегда)
-                blood_decals::spawn_blood_decal(
-                    &mut commands, &blood_assets,
-                    enemy_pos, blood_decals::BloodColor::Red,
-                );
-
-                debug!(
-                    "⚔️ Player hits enemy for {} damage! (HP: {}/{})",
-                    pending.damage, health.current, health.max
-                );
+            // Camera shake — направленный толчок камеры (финишер сильнее)
+            camera_shake.trigger(swing.shake, 0.15, pending.direction);
+            if swing.hitstop > 0.0 {
+                hitstop.trigger(swing.hitstop);
             }
+        } else if object_hit {
+            camera_shake.trigger(swing.shake * 0.5, 0.1, pending.direction);
+        } else if let Some(pos) = target_pos {
+            // MISS: цель увернулась, в дуге никого
+            damage_numbers::spawn_miss_text(&mut commands, &vfx_assets.font, pos);
+            debug!("⚔️ Player MISSES ({})", swing.name);
         }
 
         // Убираем PendingAttack (удар выполнен или цель исчезла)
@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+use std::f32::consts::{FRAC_PI_2, PI};
+use crate::modules::player::components::{Player, PlayerAnimState, AnimationState};
+use crate::modules::anim_clips::AnimSlot;
+use super::slash_vfx::SlashShape;
+
+/// Окно продолжения комбо после готовности кулдауна (с)
+pub const COMBO_WINDOW: f32 = 0.6;
+
+/// Удар комбо: свой клип, тайминг, урон, дуга поражения и форма slash
+pub struct ComboSwing {
+    pub name: &'static str,
+    /// Клип удара в таблице богатыря
+    pub slot: AnimSlot,
+    pub damage_mult: f32,
+    /// Полуугол дуги поражения (рад); PI — круг вокруг игрока
+    pub arc_half_angle: f32,
+    /// Задержка урона от начала замаха (с)
+    pub hit_delay: f32,
+    /// Через сколько Attacking возвращается в локомоцию (с)
+    pub anim_duration: f32,
+    /// Скорость клипа на верхнем слое
+    pub anim_speed: f32,
+    /// Своего клипа в GLB нет — замена (базовый замах) идёт задом наперёд, встречным ударом
+    pub fallback_reversed: bool,
+    pub slash: SlashShape,
+    /// Скорость отбрасывания (м/с) и длительность стаггера врага (с)
+    pub knockback: f32,
+    pub stagger: f32,
+    /// Сила camera shake
+    pub shake: f32,
+    /// Hitstop при попадании (с), 0 — без заморозки
+    pub hitstop: f32,
+}
+
+/// Цепочка: левый косой → правый косой → удар сверху (финишер)
+pub const COMBO_SWINGS: [ComboSwing; 3] = [
+    ComboSwing {
+        name: "left slash",
+        slot: AnimSlot::Attack,
+        damage_mult: 1.0,
+        arc_half_angle: FRAC_PI_2 * 0.6,
+        hit_delay: 0.42,
+        anim_duration: 0.5,
+        anim_speed: 2.5,
+        fallback_reversed: false,
+        slash: SlashShape { roll: 0.0, mirror: false, scale: 1.0 },
+        knockback: 8.0,
+        stagger: 0.35,
+        shake: 0.15,
+        hitstop: 0.0,
+    },
+    ComboSwing {
+        name: "right slash",
+        slot: AnimSlot::AttackRight,
+        damage_mult: 1.15,
+        arc_half_angle: FRAC_PI_2 * 0.6,
+        hit_delay: 0.36,
+        anim_duration: 0.45,
+        anim_speed: 2.8,
+        fallback_reversed: true,
+        slash: SlashShape { roll: 0.0, mirror: true, scale: 1.0 },
+        knockback: 8.0,
+        stagger: 0.35,
+        shake: 0.15,
+        hitstop: 0.0,
+    },
+    ComboSwing {
+        name: "overhead slam",
+        slot: AnimSlot::AttackSlam,
+        damage_mult: 1.8,
+        arc_half_angle: PI,
+        hit_delay: 0.55,
+        anim_duration: 0.7,
+        anim_speed: 1.9,
+        fallback_reversed: false,
+        slash: SlashShape { roll: FRAC_PI_2, mirror: false, scale: 1.5 },
+        knockback: 14.0,
+        stagger: 0.6,
+        shake: 0.3,
+        hitstop: 0.08,
+    },
+];
+
+/// Прогресс комбо игрока: какой удар следующий и сколько ещё ждать продолжения
+#[derive(Component, Default)]
+pub struct ComboState {
+    pub next: usize,
+    /// Окно продолжения; None — цепочка не начата
+    pub window: Option<Timer>,
+}
+
+impl ComboState {
+    /// Выбирает удар для нового замаха и открывает окно до следующего
+    pub fn advance(&mut self, cooldown: f32) -> usize {
+        let step = if self.window.is_some() { self.next } else { 0 };
+        self.next = (step + 1) % COMBO_SWINGS.len();
+        self.window = Some(Timer::from_seconds(cooldown + COMBO_WINDOW, TimerMode::Once));
+        step
+    }
+
+    pub fn reset(&mut self) {
+        self.next = 0;
+        self.window = None;
+    }
+}
+
+/// Окно комбо: истекает → цепочка заново; стаггер игрока сбрасывает сразу
+pub fn combo_window_system(
+    time: Res<Time>,
+    mut query: Query<(&mut ComboState, &PlayerAnimState), With<Player>>,
+) {
+    for (mut combo, state) in &mut query {
+        if state.current == AnimationState::HitReaction {
+            combo.reset();
+            continue;
+        }
+        let expired = combo.window.as_mut().is_some_and(|window| window.tick(time.delta()).is_finished());
+        if expired {
+            combo.reset();
+        }
+    }
+}
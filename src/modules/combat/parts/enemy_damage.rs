@@ -1,8 +1,8 @@
 use bevy::prelude::*;
 use avian3d::prelude::*;
 use crate::modules::player::components::{Player, PlayerAnimState, AnimationState, PlayerHitStagger, PlayerModel, StaggerCooldown};
-use crate::modules::enemies::components::{Enemy, EnemyAnimState, EnemyAnim, EnemyDying};
-use crate::modules::combat::components::{PlayerHealth, EnemyAttackCooldown, PendingAttack, AttackAnimTimer};
+use crate::modules::enemies::components::{Enemy, EnemyAnimState, EnemyAnim, EnemyDying, AuraBuff};
+use crate::modules::combat::components::{PlayerHealth, EnemyAttackCooldown, PendingAttack, AttackAnimTimer, Invulnerable};
 use super::damage_vignette::DamageVignette;
 use super::camera_shake::CameraShake;
 use super::hit_flash::HitFlash;
@@ -11,29 +11,30 @@ use super::damage_numbers;
 use super::vfx_assets::HitVfxAssets;
 
 /// Враг наносит контактный урон игроку когда в состоянии Attacking
-/// Diablo 2 Hit Recovery: урон ВСЕГДА проходит, стаггер только если не уже в стаггере
+/// Diablo 2 Hit Recovery: урон проходит всегда, кроме окна i-frames (рывок);
+/// стаггер только если не уже в стаггере
 pub fn enemy_contact_damage_system(
     time: Res<Time>,
     mut commands: Commands,
-    mut enemies: Query<(&Transform, &EnemyAnimState, &mut EnemyAttackCooldown), (With<Enemy>, Without<EnemyDying>)>,
-    mut player: Query<(Entity, &Transform, &mut PlayerHealth, &mut PlayerAnimState, &mut LinearVelocity, &Children, Has<StaggerCooldown>), With<Player>>,
+    mut enemies: Query<(&Transform, &EnemyAnimState, &mut EnemyAttackCooldown, Option<&AuraBuff>), (With<Enemy>, Without<EnemyDying>)>,
+    mut player: Query<(Entity, &Transform, &mut PlayerHealth, &mut PlayerAnimState, &mut LinearVelocity, &Children, Has<StaggerCooldown>, Has<Invulnerable>), With<Player>>,
     player_model_query: Query<Entity, With<PlayerModel>>,
     mut vignette: ResMut<DamageVignette>,
     mut camera_shake: ResMut<CameraShake>,
     vfx_assets: Res<HitVfxAssets>,
 ) {
-    let Ok((player_entity, player_tf, mut player_health, mut state, mut velocity, children, has_stagger_cooldown)) = player.single_mut() else { return };
+    let Ok((player_entity, player_tf, mut player_health, mut state, mut velocity, children, has_stagger_cooldown, invulnerable)) = player.single_mut() else { return };
     let player_pos = player_tf.translation;
     let already_staggered = state.current == AnimationState::HitReaction;
 
-    for (enemy_tf, anim_state, mut attack_cd) in &mut enemies {
+    for (enemy_tf, anim_state, mut attack_cd, buff) in &mut enemies {
         if anim_state.current == EnemyAnim::Attacking {
             attack_cd.timer.tick(time.delta());
 
             if attack_cd.timer.is_finished() {
-                // Проверка дистанции: промах если игрок убежал
+                // Промах если игрок убежал или проскочил удар рывком (i-frames)
                 let distance = (player_pos - enemy_tf.translation).length();
-                if distance > attack_cd.max_range {
+                if distance > attack_cd.max_range || invulnerable {
                     damage_numbers::spawn_miss_text(
                         &mut commands, &vfx_assets.font,
                         player_pos,
@@ -42,8 +43,9 @@ pub fn enemy_contact_damage_system(
                     continue;
                 }
 
-                // Diablo 2: урон ВСЕГДА проходит (если в радиусе)
-                player_health.take_damage(attack_cd.damage);
+                // Diablo 2: урон проходит (если в радиусе и нет i-frames); бафф Колдуна усиливает удар
+                let damage = attack_cd.damage * buff.map_or(1.0, |b| b.damage_multiplier);
+                player_health.take_damage(damage);
 
                 let hit_dir = (player_pos - enemy_tf.translation).normalize_or_zero();
 
@@ -88,3 +90,17 @@ pub fn enemy_contact_damage_system(
         }
     }
 }
+
+/// Тикает окно неуязвимости, снимает по завершении
+pub fn invulnerability_decay_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Invulnerable)>,
+) {
+    for (entity, mut invulnerable) in &mut query {
+        invulnerable.timer.tick(time.delta());
+        if invulnerable.timer.is_finished() {
+            commands.entity(entity).remove::<Invulnerable>();
+        }
+    }
+}
@@ -13,6 +13,13 @@ pub struct KillCount {
     pub upyr: u32,
     pub leshiy: u32,
     pub volkolak: u32,
+    pub koldun: u32,
+}
+
+/// Итог забега для экрана Game Over: победа — все разломы запечатаны (режим «Поход»)
+#[derive(Resource, Default)]
+pub struct RunOutcome {
+    pub victory: bool,
 }
 
 /// Проверяет смерть игрока → fade-переход в GameOver
@@ -34,13 +41,15 @@ pub fn check_game_over_system(
     }
 }
 
-/// Сброс при входе в Playing: unpause время + reset CameraShake + DamageVignette
+/// Сброс при входе в Playing: unpause время + reset CameraShake + DamageVignette + итог забега
 pub fn reset_on_enter(
     mut time: ResMut<Time<Virtual>>,
     mut shake: ResMut<CameraShake>,
     mut vignette: ResMut<DamageVignette>,
+    mut outcome: ResMut<RunOutcome>,
 ) {
     time.unpause();
+    *outcome = RunOutcome::default();
     *shake = CameraShake::default();
     *vignette = DamageVignette::default();
 }
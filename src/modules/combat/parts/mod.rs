@@ -1,6 +1,9 @@
 //! Реализация боевой системы:
-//! - auto_attack: автоатака ближайшего врага, PendingAttack с задержкой на windup
-//! - enemy_damage: контактный урон врагов по игроку
+//! - auto_attack: автоатака ближайшего врага, PendingAttack с задержкой на windup, урон по дуге удара
+//! - combo: цепочка из трёх ударов (левый, правый, удар сверху) с окном продолжения
+//! - hitstop: микро-заморозка виртуального времени (финишер комбо)
+//! - ultimate: ярость (убийства + урон) и ульта героя — гроза Перуна по площади
+//! - enemy_damage: контактный урон врагов по игроку (i-frames Invulnerable его пропускают)
 //! - camera_shake: тряска камеры при ударе (ресурс CameraShake)
 //! - slash_vfx: огненная дуга (6-кадровая анимация billboard)
 //! - hit_particles: искры при попадании (эмиссивные сферы с физикой)
@@ -9,7 +12,7 @@
 //! - knockback: отбрасывание врага (компонент Staggered)
 //! - hit_flash: импульс масштаба + эмиссивная вспышка модели при попадании
 //! - damage_vignette: красный сдвиг экрана при уроне игроку (ColorGrading)
-//! - game_over: проверка смерти игрока, fade → GameOver
+//! - game_over: проверка смерти игрока, fade → GameOver, итог забега (RunOutcome)
 //! - game_timer: таймер раунда (MM:SS), ресурс GameTimer
 
 pub mod auto_attack;
@@ -28,3 +31,6 @@ pub mod damage_vignette;
 pub mod vfx_assets;
 pub mod bloody_footprints;
 pub mod haptic;
+pub mod combo;
+pub mod hitstop;
+pub mod ultimate;
@@ -65,6 +65,15 @@ pub fn init_slash_vfx_assets(
     commands.insert_resource(SlashVfxAssets { mesh, frames, material_pool, next_index: 0 });
 }
 
+/// Форма дуги: поворот в плоскости экрана, зеркало (удар справа), масштаб
+#[derive(Clone, Copy, Reflect)]
+pub struct SlashShape {
+    /// Поворот вокруг оси взгляда (рад): 0 — косой, PI/2 — вертикальный (удар сверху)
+    pub roll: f32,
+    pub mirror: bool,
+    pub scale: f32,
+}
+
 /// Маркер slash-эффекта с покадровой анимацией
 #[derive(Component, Reflect)]
 #[reflect(Component)]
@@ -72,34 +81,43 @@ pub struct SlashVfx {
     pub timer: Timer,
     /// Последний отрисованный кадр (чтобы не мутировать материал каждый кадр)
     pub last_frame: usize,
+    pub shape: SlashShape,
 }
 
 /// Маркер: квад всегда повёрнут лицом к камере
 #[derive(Component)]
 pub struct VfxBillboard;
 
+/// Доворот billboard-квада вокруг оси взгляда (рад)
+#[derive(Component)]
+pub struct BillboardRoll(pub f32);
+
 /// Поворачивает VFX квады лицом к камере (billboard)
 pub fn vfx_billboard_system(
     camera_q: Query<&Transform, With<Camera3d>>,
-    mut billboards: Query<&mut Transform, (With<VfxBillboard>, Without<Camera3d>)>,
+    mut billboards: Query<(&mut Transform, Option<&BillboardRoll>), (With<VfxBillboard>, Without<Camera3d>)>,
 ) {
     let Ok(cam) = camera_q.single() else { return };
-    for mut t in &mut billboards {
+    for (mut t, roll) in &mut billboards {
         let dir = (cam.translation - t.translation).normalize();
         let up = Vec3::Y;
         let right = dir.cross(up).normalize();
         let corrected_up = right.cross(dir);
         t.rotation = Quat::from_mat3(&Mat3::from_cols(right, corrected_up, dir));
+        if let Some(roll) = roll {
+            t.rotation *= Quat::from_rotation_z(roll.0);
+        }
     }
 }
 
-/// Спавнит slash VFX перед игроком в направлении врага
+/// Спавнит slash VFX перед игроком в направлении врага (форма — от удара комбо)
 pub fn spawn_slash(
     commands: &mut Commands,
     slash_assets: &mut SlashVfxAssets,
     materials: &mut ResMut<Assets<StandardMaterial>>,
     player_pos: Vec3,
     direction: Vec3,
+    shape: SlashShape,
 ) {
     let slash_pos = player_pos + direction * 0.8 + Vec3::Y * 0.8;
 
@@ -121,8 +139,10 @@ pub fn spawn_slash(
         SlashVfx {
             timer: Timer::from_seconds(0.25, TimerMode::Once),
             last_frame: 0,
+            shape,
         },
         VfxBillboard,
+        BillboardRoll(shape.roll),
     ));
 }
 
@@ -143,9 +163,10 @@ pub fn slash_vfx_system(
         let frame_index = ((progress * SLASH_FRAMES.len() as f32) as usize)
             .min(SLASH_FRAMES.len() - 1);
 
-        // Небольшое увеличение масштаба (1.0 → 1.3)
-        let scale = 1.0 + progress * 0.3;
-        transform.scale = Vec3::splat(scale);
+        // Небольшое увеличение масштаба (1.0 → 1.3); зеркало — отрицательный X
+        let scale = (1.0 + progress * 0.3) * vfx.shape.scale;
+        let mirror = if vfx.shape.mirror { -1.0 } else { 1.0 };
+        transform.scale = Vec3::new(scale * mirror, scale, scale);
 
         // Обновляем материал только при смене кадра или в фазе fade out
         let needs_update = frame_index != vfx.last_frame || progress > 0.7;
@@ -0,0 +1,369 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use bevy::render::view::ColorGrading;
+use avian3d::prelude::*;
+use crate::modules::input::InputState;
+use crate::modules::player::components::{Player, PlayerAnimState, AnimationState};
+use crate::modules::enemies::components::{Enemy, Health, EnemyDying, EnemyModel, EnemyAnimState, EnemyAnim};
+use crate::modules::combat::components::{Rage, AttackAnimTimer};
+use crate::modules::spatial::{SpatialIndex, SpatialLayer};
+use super::camera_shake::CameraShake;
+use super::hitstop::Hitstop;
+use super::knockback::Staggered;
+use super::hit_flash::HitFlash;
+use super::vfx_assets::HitVfxAssets;
+use super::combo::COMBO_SWINGS;
+use super::damage_numbers;
+use super::impact_flash;
+
+/// Ярость за убийство и за единицу нанесённого урона (шкала 100: ~20 убийств с уроном)
+pub const RAGE_PER_KILL: f32 = 4.0;
+pub const RAGE_PER_DAMAGE: f32 = 0.1;
+pub const RAGE_MAX: f32 = 100.0;
+
+const BOLT_HEIGHT: f32 = 14.0;
+const BOLT_LIFETIME: f32 = 0.22;
+const BOLT_POOL_SIZE: usize = 16; // Одновременно живёт ~1 удар × цели в нём
+
+/// Ульта героя: радиус, серия ударов и их сила.
+/// Другой герой/оружие — другая константа в HeroUltimate.
+pub struct UltimateDef {
+    pub name: &'static str,
+    pub radius: f32,
+    /// Сколько ударов в серии; цели (от ближних к дальним) делятся между ними поровну
+    pub strikes: u32,
+    pub strike_interval: f32,
+    pub damage: f32,
+    pub knockback: f32,
+    pub stagger: f32,
+    pub shake: f32,
+    /// Hitstop на первом ударе серии (с)
+    pub hitstop: f32,
+    /// Вспышка ColorGrading: сила и длительность (с)
+    pub pulse: f32,
+    pub pulse_duration: f32,
+}
+
+/// Гроза Перуна: молнии бьют всех врагов вокруг волной от центра к краю
+pub const PERUN_STORM: UltimateDef = UltimateDef {
+    name: "Гроза Перуна",
+    radius: 12.0,
+    strikes: 8,
+    strike_interval: 0.14,
+    damage: 60.0,
+    knockback: 6.0,
+    stagger: 0.8,
+    shake: 0.35,
+    hitstop: 0.1,
+    pulse: 1.0,
+    pulse_duration: 1.4,
+};
+
+/// Какую ульту открывает полная ярость
+#[derive(Component)]
+pub struct HeroUltimate(pub &'static UltimateDef);
+
+impl Default for HeroUltimate {
+    fn default() -> Self {
+        Self(&PERUN_STORM)
+    }
+}
+
+/// Идущая серия ударов ульты (снимается после последнего удара)
+#[derive(Component)]
+pub struct UltimateCast {
+    pub def: &'static UltimateDef,
+    /// Цели на момент каста, от ближних к дальним
+    pub targets: Vec<Entity>,
+    pub next_target: usize,
+    pub strikes_done: u32,
+    pub timer: Timer,
+}
+
+/// Молния: столб от неба до цели, гаснет и сужается
+#[derive(Component)]
+pub struct LightningBolt {
+    pub timer: Timer,
+    pub material: Handle<StandardMaterial>,
+}
+
+/// Кэшированные ассеты молний: общий mesh + пул материалов (round-robin, как у slash)
+#[derive(Resource)]
+pub struct UltimateAssets {
+    pub bolt_mesh: Handle<Mesh>,
+    pub material_pool: [Handle<StandardMaterial>; BOLT_POOL_SIZE],
+    pub next_index: usize,
+}
+
+/// Холодная вспышка экрана на время грозы (паттерн DamageVignette: trigger → decay).
+/// spike — короткий всплеск на каждый удар поверх общего затухания.
+#[derive(Resource, Default)]
+pub struct LightningPulse {
+    pub intensity: f32,
+    pub duration: f32,
+    pub elapsed: f32,
+    pub spike: f32,
+}
+
+impl LightningPulse {
+    pub fn trigger(&mut self, intensity: f32, duration: f32) {
+        self.intensity = intensity;
+        self.duration = duration;
+        self.elapsed = 0.0;
+    }
+
+    pub fn flash(&mut self) {
+        self.spike = 1.0;
+    }
+}
+
+/// Инициализация ассетов ульты при старте раунда
+pub fn init_ultimate_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let bolt_mesh = meshes.add(Cylinder::new(0.12, BOLT_HEIGHT));
+    let material_pool = std::array::from_fn(|_| materials.add(StandardMaterial {
+        base_color: Color::srgba(0.75, 0.85, 1.0, 1.0),
+        emissive: LinearRgba::new(6.0, 8.0, 20.0, 1.0),
+        alpha_mode: AlphaMode::Add,
+        unlit: true,
+        ..default()
+    }));
+    commands.insert_resource(UltimateAssets { bolt_mesh, material_pool, next_index: 0 });
+    commands.insert_resource(LightningPulse::default());
+}
+
+/// Ярость за убийства: каждый новый EnemyDying.
+/// Пока идёт ульта — не копится, иначе гроза сама себя перезаряжает.
+pub fn rage_from_kills_system(
+    new_dead: Query<(), Added<EnemyDying>>,
+    mut player: Query<&mut Rage, (With<Player>, Without<UltimateCast>)>,
+) {
+    let kills = new_dead.iter().count();
+    if kills == 0 {
+        return;
+    }
+    let Ok(mut rage) = player.single_mut() else { return };
+    rage.add(kills as f32 * RAGE_PER_KILL);
+}
+
+/// Запуск ульты по запросу (Q / шкала ярости / автоплей): нужна полная ярость и хотя бы одна цель.
+/// Без целей ярость не тратится.
+pub fn start_ultimate_system(
+    mut input_state: ResMut<InputState>,
+    index: Res<SpatialIndex>,
+    mut player: Query<
+        (Entity, &Transform, &mut Rage, &HeroUltimate, &mut PlayerAnimState),
+        (With<Player>, Without<UltimateCast>)
+    >,
+    mut pulse: ResMut<LightningPulse>,
+    mut commands: Commands,
+) {
+    let requested = std::mem::take(&mut input_state.ultimate_requested);
+    if !requested {
+        return;
+    }
+    let Ok((entity, transform, mut rage, ultimate, mut state)) = player.single_mut() else { return };
+    if !rage.is_full() || state.current == AnimationState::HitReaction {
+        return;
+    }
+
+    let def = ultimate.0;
+    let player_pos = transform.translation;
+    let mut targets: Vec<(Entity, f32)> = Vec::new();
+    index.for_each_in_radius(SpatialLayer::Enemy, player_pos, def.radius, |enemy, pos| {
+        targets.push((enemy, (pos - player_pos).length_squared()));
+    });
+    if targets.is_empty() {
+        debug!("⚡ {}: нет целей — ярость сохранена", def.name);
+        return;
+    }
+    targets.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    rage.current = 0.0;
+    pulse.trigger(def.pulse, def.pulse_duration);
+
+    // Вскинутый меч — клип финишера комбо на верхнем слое
+    state.current = AnimationState::Attacking;
+    state.swing = COMBO_SWINGS.len() - 1;
+    commands.entity(entity).insert((
+        AttackAnimTimer {
+            timer: Timer::from_seconds(COMBO_SWINGS[state.swing].anim_duration, TimerMode::Once),
+        },
+        UltimateCast {
+            def,
+            targets: targets.into_iter().map(|(enemy, _)| enemy).collect(),
+            next_target: 0,
+            strikes_done: 0,
+            timer: Timer::from_seconds(def.strike_interval, TimerMode::Repeating),
+        },
+    ));
+    info!("⚡ {}!", def.name);
+}
+
+/// Отклик удара грозы: тряска камеры, hitstop и вспышка экрана
+#[derive(SystemParam)]
+pub struct StrikeFeedback<'w> {
+    camera_shake: ResMut<'w, CameraShake>,
+    hitstop: ResMut<'w, Hitstop>,
+    pulse: ResMut<'w, LightningPulse>,
+}
+
+/// Ассеты удара: столб молнии из пула + общие VFX попадания
+#[derive(SystemParam)]
+pub struct BoltAssets<'w> {
+    assets: ResMut<'w, UltimateAssets>,
+    materials: ResMut<'w, Assets<StandardMaterial>>,
+    vfx_assets: Res<'w, HitVfxAssets>,
+}
+
+/// Серия ударов: каждый тик бьёт следующую порцию целей (волна от центра к краю)
+pub fn ultimate_strike_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut player: Query<(Entity, &Transform, &mut UltimateCast), With<Player>>,
+    mut enemies: Query<(&Transform, &mut Health, &mut LinearVelocity, &Children, &mut EnemyAnimState), (With<Enemy>, Without<EnemyDying>)>,
+    enemy_model_query: Query<Entity, With<EnemyModel>>,
+    feedback: StrikeFeedback,
+    bolts: BoltAssets,
+) {
+    let StrikeFeedback { mut camera_shake, mut hitstop, mut pulse } = feedback;
+    let BoltAssets { mut assets, mut materials, vfx_assets } = bolts;
+    let Ok((player_entity, player_transform, mut cast)) = player.single_mut() else { return };
+    let player_pos = player_transform.translation;
+    let def = cast.def;
+    let per_strike = cast.targets.len().div_ceil(def.strikes as usize);
+
+    let ticks = cast.timer.tick(time.delta()).times_finished_this_tick();
+    for _ in 0..ticks {
+        if cast.strikes_done >= def.strikes {
+            break;
+        }
+        let start = cast.next_target;
+        let end = (start + per_strike).min(cast.targets.len());
+        cast.next_target = end;
+        cast.strikes_done += 1;
+
+        let mut hits = 0;
+        for &victim in &cast.targets[start..end] {
+            let Ok((enemy_transform, mut health, mut velocity, children, mut anim_state)) = enemies.get_mut(victim) else { continue };
+            if health.is_dead() {
+                continue;
+            }
+            let enemy_pos = enemy_transform.translation;
+            health.take_damage(def.damage);
+            hits += 1;
+
+            // Молния + вспышка у земли
+            let material = assets.material_pool[assets.next_index % BOLT_POOL_SIZE].clone();
+            assets.next_index = assets.next_index.wrapping_add(1);
+            if let Some(mat) = materials.get_mut(&material) {
+                mat.base_color = mat.base_color.with_alpha(1.0);
+            }
+            commands.spawn((
+                Mesh3d(assets.bolt_mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                Transform::from_translation(enemy_pos + Vec3::Y * BOLT_HEIGHT * 0.5),
+                LightningBolt {
+                    timer: Timer::from_seconds(BOLT_LIFETIME, TimerMode::Once),
+                    material,
+                },
+            ));
+            impact_flash::spawn_impact_flash(&mut commands, &vfx_assets, enemy_pos);
+
+            // Радиальное отбрасывание от игрока + стаггер
+            let knockback_dir = Vec3::new(enemy_pos.x - player_pos.x, 0.0, enemy_pos.z - player_pos.z)
+                .normalize_or_zero();
+            velocity.0 = knockback_dir * def.knockback;
+            commands.entity(victim).insert(Staggered::new(def.stagger));
+            if anim_state.current != EnemyAnim::HitReaction {
+                anim_state.current = EnemyAnim::HitReaction;
+            }
+            for child in children.iter() {
+                if enemy_model_query.get(child).is_ok() {
+                    commands.entity(child).insert(HitFlash::new());
+                    break;
+                }
+            }
+            damage_numbers::spawn_damage_number(&mut commands, &vfx_assets.font, enemy_pos, def.damage);
+        }
+
+        if hits > 0 {
+            camera_shake.trigger(def.shake, 0.12, Vec3::ZERO);
+            pulse.flash();
+            if cast.strikes_done == 1 && def.hitstop > 0.0 {
+                hitstop.trigger(def.hitstop);
+            }
+        }
+        debug!("⚡ {} strike {}/{}: {} hits", def.name, cast.strikes_done, def.strikes, hits);
+    }
+
+    if cast.strikes_done >= def.strikes {
+        commands.entity(player_entity).remove::<UltimateCast>();
+    }
+}
+
+/// Затухание молний: сужение столба + alpha, затем despawn
+pub fn lightning_bolt_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut LightningBolt, &mut Transform)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (entity, mut bolt, mut transform) in &mut query {
+        bolt.timer.tick(time.delta());
+        if bolt.timer.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let remaining = bolt.timer.fraction_remaining();
+        transform.scale = Vec3::new(remaining, 1.0, remaining);
+        if let Some(mat) = materials.get_mut(&bolt.material) {
+            mat.base_color = mat.base_color.with_alpha(remaining);
+        }
+    }
+}
+
+/// Холодная вспышка ColorGrading: ярче, синее, менее насыщенно.
+/// Работает после damage_vignette_apply_system — на время грозы перекрывает её.
+pub fn lightning_pulse_system(
+    time: Res<Time>,
+    mut pulse: ResMut<LightningPulse>,
+    mut camera_query: Query<&mut ColorGrading, With<Camera3d>>,
+) {
+    if pulse.intensity <= 0.0 {
+        return;
+    }
+    let Ok(mut grading) = camera_query.single_mut() else { return };
+
+    pulse.elapsed += time.delta_secs();
+    pulse.spike = (pulse.spike - time.delta_secs() * 8.0).max(0.0);
+
+    if pulse.elapsed >= pulse.duration {
+        pulse.intensity = 0.0;
+        // Базовые значения (из Phase 1 setup_camera)
+        grading.global.exposure = 0.2;
+        grading.global.temperature = -0.05;
+        grading.shadows.saturation = 0.9;
+        return;
+    }
+
+    let progress = pulse.elapsed / pulse.duration;
+    let strength = pulse.intensity * (1.0 - progress) * 0.5 + pulse.spike;
+
+    grading.global.exposure = 0.2 + 0.8 * strength;
+    grading.global.temperature = -0.05 - 0.3 * strength;
+    grading.shadows.saturation = 0.9 - 0.4 * strength;
+}
+
+/// Despawn молний при выходе из Playing
+pub fn cleanup_lightning_bolts(
+    mut commands: Commands,
+    query: Query<Entity, With<LightningBolt>>,
+) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
@@ -1,10 +1,11 @@
 use bevy::prelude::*;
 use crate::shared::GameState;
+use crate::modules::spatial::SpatialIndexSet;
 use crate::modules::combat::parts::{
     auto_attack, enemy_damage, camera_shake, slash_vfx, hit_particles,
     game_over, game_timer, knockback, hit_flash, damage_numbers,
     impact_flash, damage_vignette, vfx_assets, blood_decals, bloody_footprints,
-    haptic,
+    haptic, combo, hitstop, ultimate,
 };
 
 pub struct CombatPlugin;
@@ -14,13 +15,19 @@ impl Plugin for CombatPlugin {
         app
             .init_resource::<camera_shake::CameraShake>()
             .init_resource::<game_over::KillCount>()
+            .init_resource::<game_over::RunOutcome>()
             .init_resource::<game_timer::GameTimer>()
             .init_resource::<damage_vignette::DamageVignette>()
             .init_resource::<haptic::HapticState>()
+            .init_resource::<hitstop::Hitstop>()
             .add_systems(Update, (
-                auto_attack::player_auto_attack_system,
-                auto_attack::apply_pending_attack_system,
+                auto_attack::player_auto_attack_system
+                    .after(SpatialIndexSet),
+                auto_attack::apply_pending_attack_system
+                    .after(SpatialIndexSet),
                 auto_attack::attack_animation_reset_system,
+                combo::combo_window_system,
+                hitstop::hitstop_system,
                 enemy_damage::enemy_contact_damage_system,
                 camera_shake::camera_shake_decay_system,
                 slash_vfx::slash_vfx_system,
@@ -38,6 +45,15 @@ impl Plugin for CombatPlugin {
                 haptic::haptic_feedback_system,
             ).run_if(in_state(GameState::Playing)))
             .add_systems(Update, (
+                enemy_damage::invulnerability_decay_system,
+                ultimate::rage_from_kills_system,
+                ultimate::start_ultimate_system
+                    .after(SpatialIndexSet),
+                ultimate::ultimate_strike_system
+                    .after(ultimate::start_ultimate_system),
+                ultimate::lightning_bolt_system,
+                ultimate::lightning_pulse_system
+                    .after(damage_vignette::damage_vignette_apply_system),
                 blood_decals::footprint_decay_system,
                 blood_decals::blood_decal_limit_system,
                 bloody_footprints::detect_blood_contact_system,
@@ -49,11 +65,13 @@ impl Plugin for CombatPlugin {
                 vfx_assets::init_hit_vfx_assets,
                 blood_decals::init_blood_decal_assets,
                 slash_vfx::init_slash_vfx_assets,
+                ultimate::init_ultimate_assets,
             ))
+            .add_systems(OnExit(GameState::Playing), ultimate::cleanup_lightning_bolts)
             .add_systems(OnEnter(GameState::GameOver),
                 damage_vignette::reset_color_grading,
             );
 
-        info!("⚔️ CombatPlugin loaded (auto-attack + VFX + hit effects)");
+        info!("⚔️ CombatPlugin loaded (auto-attack combo + rage ultimate + VFX + hit effects)");
     }
 }
@@ -41,6 +41,7 @@ pub enum EnemyType {
     Upyr,      // Упырь — славянский зомби (медленный, HP 20)
     Leshiy,    // Леший — лесной дух (быстрый фланкер, HP 15)
     Volkolak,  // Волколак — четвероногий хищник (быстрый, HP 12)
+    Koldun,    // Колдун Нави — поддержка: лечит и ускоряет соседей, убегает от игрока (HP 18)
 }
 
 /// Маркер для визуальной модели врага (child entity)
@@ -82,6 +83,30 @@ pub enum EnemyLod {
     Minimal,   // >25м: анимация заморожена + circle скрыт
 }
 
+impl EnemyLod {
+    /// Период тика AI в кадрах: дальние враги думают реже (time-slicing)
+    pub fn ai_period(self) -> u32 {
+        match self {
+            EnemyLod::Full => 1,
+            EnemyLod::Reduced => 2,
+            EnemyLod::Minimal => 4,
+        }
+    }
+}
+
+/// Счётчик кадров AI для time-slicing: враг с периодом N тикает раз в N кадров,
+/// фаза по индексу entity — нагрузка размазана равномерно по кадрам
+#[derive(Resource, Default)]
+pub struct AiTick {
+    pub frame: u32,
+}
+
+impl AiTick {
+    pub fn is_due(&self, entity: Entity, period: u32) -> bool {
+        period <= 1 || entity.index_u32().wrapping_add(self.frame).is_multiple_of(period)
+    }
+}
+
 /// Кэш последнего применённого speed_factor анимации.
 /// Обновляем set_speed() только при изменении > 5% (экономим change detection).
 #[derive(Component, Default)]
@@ -182,6 +207,16 @@ impl Default for ChasePlayer {
     }
 }
 
+/// Враг поднят по тревоге: охотится за игроком вне aggro_range,
+/// пока не потеряет его дольше lose_timer (путь к игроку не сокращается)
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Alerted {
+    pub lose_timer: Timer,
+    /// Кратчайшая длина пути до игрока с последнего сброса таймера (м)
+    pub closest: f32,
+}
+
 /// Направление орбитирования вокруг цели (CW/CCW)
 #[derive(Component, Reflect)]
 #[reflect(Component)]
@@ -190,21 +225,298 @@ pub struct OrbitDirection {
     pub change_timer: Timer,
 }
 
-/// Маркер: враг получил слот на ближнюю атаку
+/// Узел поведения врага (лист utility-выбора)
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Reflect)]
+pub enum BehaviorNode {
+    #[default]
+    Idle,          // вне агро — стоит
+    Chase,         // преследование по прямой
+    Attack,        // есть слот — подходит и бьёт
+    CircleStrafe,  // нет слота — кружит вокруг игрока
+    Flank,         // заход со спины
+    Charge,        // рывок со средней дистанции
+    Ambush,        // замирает, пока игрок смотрит на него
+    Retreat,       // бегство от игрока
+    KeepDistance,  // держит предпочтительную дистанцию
+    PackCircle,    // стая: кружит на кольце вокруг игрока
+    Howl,          // стая: вой перед броском
+    Lunge,         // стая: бросок со своего угла
+}
+
+impl BehaviorNode {
+    pub const ALL: &'static [BehaviorNode] = &[
+        BehaviorNode::Idle,
+        BehaviorNode::Chase,
+        BehaviorNode::Attack,
+        BehaviorNode::CircleStrafe,
+        BehaviorNode::Flank,
+        BehaviorNode::Charge,
+        BehaviorNode::Ambush,
+        BehaviorNode::Retreat,
+        BehaviorNode::KeepDistance,
+        BehaviorNode::PackCircle,
+        BehaviorNode::Howl,
+        BehaviorNode::Lunge,
+    ];
+}
+
+/// Фаза охоты стаи (управляется вожаком)
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Reflect)]
+pub enum PackPhase {
+    #[default]
+    Approach,  // игрок вне агро вожака — стая подтягивается
+    Stalk,     // кружат на дистанции, выбирая момент
+    Howl,      // вой перед броском (scream-анимация)
+    Lunge,     // согласованный бросок с разных углов
+}
+
+/// Вожак стаи волколаков: фаза охоты + зарезервированные слоты атаки
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct PackLeader {
+    pub phase: PackPhase,
+    pub phase_timer: Timer,
+    /// Поворот кольца окружения (рад) — стая кружит вокруг игрока
+    pub circle_phase: f32,
+    /// Сколько членов стаи получили слоты на текущий бросок
+    pub lunge_slots: u32,
+    /// Живые члены стаи (пересчитывается каждый кадр)
+    pub member_count: u32,
+}
+
+impl Default for PackLeader {
+    fn default() -> Self {
+        Self {
+            phase: PackPhase::Approach,
+            phase_timer: Timer::from_seconds(0.0, TimerMode::Once),
+            circle_phase: crate::shared::rand_01() * std::f32::consts::TAU,
+            lunge_slots: 0,
+            member_count: 0,
+        }
+    }
+}
+
+impl PackLeader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Приказ стаи члену на текущий кадр
+#[derive(Clone, Copy, PartialEq, Debug, Default, Reflect)]
+pub enum PackOrder {
+    #[default]
+    Follow,                 // без приказа — обычное поведение профиля
+    Circle { angle: f32 },  // держать точку на кольце вокруг игрока
+    Howl,                   // выть на месте
+    Lunge { angle: f32 },   // бросок к игроку со своего угла
+}
+
+/// Член стаи (вожак — тоже член с index 0)
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct PackMember {
+    pub leader: Entity,
+    /// Порядковый номер в стае: задаёт угол на кольце и очередь на слот
+    pub index: u32,
+    pub pack_size: u32,
+    pub order: PackOrder,
+}
+
+/// Стая без вожака: разбегается, затем охотится поодиночке
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct PackScatter {
+    pub timer: Timer,
+}
+
+/// Профиль поведения архетипа — определяет набор узлов и их оценки
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+pub enum BehaviorProfile {
+    Brute,     // Упырь: chase → attack / circle-strafe
+    Flanker,   // Леший: flank + ambush
+    Predator,  // Волколак: charge + attack
+    Support,   // Колдун: keep-distance + retreat
+}
+
+/// Поведение врага: профиль архетипа + текущий узел (выбирается каждый кадр по utility)
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct EnemyBehavior {
+    pub profile: BehaviorProfile,
+    pub current: BehaviorNode,
+    /// Время в текущем узле (сек)
+    pub node_time: f32,
+    /// Кулдаун рывка (Charge), сек
+    pub charge_cooldown: f32,
+    /// Скорость последнего тика AI — держится между тиками при time-slicing
+    pub steering: Vec3,
+    /// Куда развернуть модель (ZERO — не поворачивать)
+    pub facing: Vec3,
+}
+
+impl EnemyBehavior {
+    pub fn new(profile: BehaviorProfile) -> Self {
+        Self {
+            profile,
+            current: BehaviorNode::Idle,
+            node_time: 0.0,
+            charge_cooldown: 0.0,
+            steering: Vec3::ZERO,
+            facing: Vec3::ZERO,
+        }
+    }
+}
+
+/// Аура поддержки (Колдун): периодический импульс лечит и баффает союзников в радиусе
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct SupportAura {
+    pub radius: f32,
+    pub heal_amount: f32,
+    pub buff_duration: f32,
+    pub pulse_timer: Timer,
+}
+
+/// Временный бафф от ауры Колдуна: ускорение + усиленный урон
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct AuraBuff {
+    pub timer: Timer,
+    pub speed_multiplier: f32,
+    pub damage_multiplier: f32,
+}
+
+/// Бегство от игрока: держит дистанцию вместо ближнего боя (параметр узлов Retreat/KeepDistance)
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct FleeFromPlayer {
+    /// Дистанция, ближе которой враг убегает
+    pub flee_range: f32,
+}
+
+/// Приоритет цели для автоатаки игрока (>1 — выбирается раньше более близких врагов)
+#[derive(Component, Reflect, Clone, Copy)]
+#[reflect(Component)]
+pub struct TargetPriority {
+    pub weight: f32,
+}
+
+/// Маркер: враг получил слот (токены) на атаку
 #[derive(Component, Reflect)]
 #[reflect(Component)]
 pub struct HasAttackSlot;
 
-/// Менеджер слотов — лимитирует одновременных атакующих (Diablo-style)
+/// Пул токенов атаки: ближний бой и дальний — раздельные бюджеты
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+pub enum TokenPool {
+    Melee,
+    Ranged,
+}
+
+/// Стоимость атаки врага в токенах (тяжёлые враги занимают больше бюджета)
+#[derive(Component, Reflect, Clone, Copy)]
+#[reflect(Component)]
+pub struct AttackTokenCost {
+    pub cost: u32,
+    pub pool: TokenPool,
+}
+
+impl AttackTokenCost {
+    pub const fn melee(cost: u32) -> Self {
+        Self { cost, pool: TokenPool::Melee }
+    }
+}
+
+impl Default for AttackTokenCost {
+    fn default() -> Self {
+        Self::melee(1)
+    }
+}
+
+/// Маркер: враг вне бюджета токенов (боссы) — атакует всегда, бюджет не занимает
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct BypassAttackTokens;
+
+/// Бюджет токенов атаки (Diablo-style): сколько "веса" атакующих одновременно у игрока.
+/// Текущие бюджеты пересчитываются от базы по сложности и номеру волны.
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
+pub struct AttackTokenBudget {
+    pub base_melee: u32,
+    pub base_ranged: u32,
+    /// Множитель сложности (1.0 = норма) — задаётся режимом забега на входе в Playing
+    pub difficulty: f32,
+    pub melee_tokens: u32,
+    pub ranged_tokens: u32,
+    /// Кандидатов в радиусе атаки осталось без токенов (для ротации атакующих)
+    pub waiting: u32,
+}
+
+impl AttackTokenBudget {
+    /// Бюджет пула с учётом волны и сложности: +1 melee каждые 2 волны (до +4), +1 ranged каждые 4 (до +2)
+    pub fn rescale(&mut self, wave: u32) {
+        let melee = self.base_melee + (wave / 2).min(4);
+        let ranged = self.base_ranged + (wave / 4).min(2);
+        self.melee_tokens = ((melee as f32 * self.difficulty).round() as u32).max(1);
+        self.ranged_tokens = (ranged as f32 * self.difficulty).round() as u32;
+    }
+
+    pub fn tokens(&self, pool: TokenPool) -> u32 {
+        match pool {
+            TokenPool::Melee => self.melee_tokens,
+            TokenPool::Ranged => self.ranged_tokens,
+        }
+    }
+}
+
+impl Default for AttackTokenBudget {
+    fn default() -> Self {
+        let mut budget = Self {
+            base_melee: 6,
+            base_ranged: 2,
+            difficulty: 1.0,
+            melee_tokens: 0,
+            ranged_tokens: 0,
+            waiting: 0,
+        };
+        budget.rescale(0);
+        budget
+    }
+}
+
+/// Опция режима (GameModeDef::body_blocking): враги — твёрдые тела для игрока (мягкое выталкивание).
+/// Выключено — классика VS/action RPG, игрок проходит сквозь толпу.
+/// Включено — толпа может окружить и зажать героя (спасает рывок и отбрасывание).
 #[derive(Resource, Reflect)]
 #[reflect(Resource)]
-pub struct AttackSlotManager {
-    pub max_slots: u32,
+pub struct BodyBlocking {
+    pub enabled: bool,
+    /// Скорость выталкивания из перекрытия (м/с при полном перекрытии)
+    pub push_strength: f32,
+    /// Доля выталкивания, с которой толпа теснит игрока (0 — игрок непоколебим)
+    pub player_yield: f32,
+    /// Separation внутри кольца контакта ослабляется — иначе соседи не дают кольцу сомкнуться
+    pub ring_separation_scale: f32,
+}
+
+impl BodyBlocking {
+    /// Сколько тел радиуса body_radius помещается в кольцо контакта вокруг игрока
+    pub fn ring_capacity(player_radius: f32, body_radius: f32) -> u32 {
+        let ring = player_radius + body_radius;
+        (std::f32::consts::TAU * ring / (2.0 * body_radius)).floor() as u32
+    }
 }
 
-impl Default for AttackSlotManager {
+impl Default for BodyBlocking {
     fn default() -> Self {
-        Self { max_slots: 4 }
+        Self {
+            enabled: false,
+            push_strength: 6.0,
+            player_yield: 0.3,
+            ring_separation_scale: 0.3,
+        }
     }
 }
 
@@ -213,6 +525,71 @@ impl Default for AttackSlotManager {
 #[reflect(Component)]
 pub struct SpawnPortal {
     pub index: u8, // 0 = "Разлом Огня", 1 = "Разлом Тьмы"
+    /// Место в ArenaLayout::portal_sites (стартовые порталы, затем rift_sites)
+    pub site: usize,
+    /// Точка выхода врагов (внутри арены)
+    pub spawn: Vec3,
+}
+
+/// Запечатанный портал: враги из него не выходят, вихрь и свет притушены
+#[derive(Component)]
+pub struct SealedPortal;
+
+/// Разлом, открывшийся по ходу забега (не из стартовой раскладки) — закрывается с новым забегом
+#[derive(Component)]
+pub struct OpenedRift;
+
+/// Портал запечатан — для музыки и всего, что меняет настроение боя
+#[derive(Message)]
+pub struct PortalSealed {
+    /// Место в ArenaLayout::portal_sites
+    pub site: usize,
+    /// Сколько порталов ещё открыто
+    pub remaining: usize,
+}
+
+/// Печати разломов за забег: окно уязвимости и очередь новых разломов
+#[derive(Resource, Default)]
+pub struct RiftState {
+    /// Порталы сейчас можно бить (пауза после каждой 3-й волны)
+    pub window_open: bool,
+    /// Волна, после которой окно открывалось последний раз
+    pub window_wave: u32,
+    /// Волна, к началу которой уже открыты положенные разломы
+    pub opened_for_wave: u32,
+    /// Номера волн, к началу которых откроются новые разломы
+    pub pending_opens: Vec<u32>,
+    pub sealed: u32,
+}
+
+/// Запрос волнового спавнера на временный разлом (фланговая волна — из-за спины игрока)
+#[derive(Message)]
+pub struct FlankRiftRequest {
+    /// Сколько врагов волны выпустит разлом
+    pub budget: u32,
+}
+
+/// Фаза временного разлома
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RiftPhase {
+    Opening,    // телеграф: вихрь раскрывается, свет мерцает
+    Open,       // выпускает врагов из бюджета
+    Closing,    // схлопывается
+}
+
+/// Временный разлом в произвольной точке арены: живёт, пока не потратит бюджет
+/// или не истечёт срок, потом схлопывается
+#[derive(Component)]
+pub struct TemporaryRift {
+    pub phase: RiftPhase,
+    /// Таймер текущей фазы (раскрытие / срок жизни / схлопывание)
+    pub timer: Timer,
+    pub spawn_timer: Timer,
+    pub budget: u32,
+    /// 0 = "Разлом Огня", 1 = "Разлом Тьмы" (материалы частиц как у SpawnPortal)
+    pub index: u8,
+    pub fill: Entity,
+    pub light: Entity,
 }
 
 /// Маркер визуальной воронки портала (child entity)
@@ -256,10 +633,11 @@ pub struct WaveState {
     pub spawn_timer: Timer,
     pub wave_cooldown: Timer,
     pub phase: WavePhase,
-    /// Счётчик врагов, отправленных в портал 0 (для балансировки ~50/50)
-    pub portal_0_count: u32,
-    /// Счётчик врагов, отправленных в портал 1
-    pub portal_1_count: u32,
+    /// Сколько врагов за волну вышло из каждого места портала (индекс — SpawnPortal::site),
+    /// для балансировки между открытыми разломами
+    pub portal_counts: Vec<u32>,
+    /// Враги волны, отданные временным разломам и ещё не вышедшие (волна ждёт их)
+    pub rift_budget: u32,
 }
 
 impl Default for WaveState {
@@ -272,8 +650,8 @@ impl Default for WaveState {
             spawn_timer: Timer::from_seconds(0.8, TimerMode::Repeating),
             wave_cooldown: cooldown,
             phase: WavePhase::Cooldown,
-            portal_0_count: 0,
-            portal_1_count: 0,
+            portal_counts: Vec::new(),
+            rift_budget: 0,
         }
     }
 }
@@ -4,4 +4,5 @@ mod parts;
 
 // Публичный API
 pub use plugin::EnemiesPlugin;
-pub use components::{Enemy, Health, EnemyType, ChasePlayer, EnemyModel, EnemyAnimations, EnemyAnimationSetupComplete, EnemyAnimState, EnemyAnim, EnemyDying, EnemyCorpse, SpawnPortal, PortalVortex, PortalLight, PortalSpawnAnim, EnemyCoreSet, WaveState, WavePhase};
+pub use parts::flow_field::FlowField;
+pub use components::{Enemy, Health, EnemyType, ChasePlayer, EnemyModel, EnemyAnimations, EnemyAnimationSetupComplete, EnemyAnimState, EnemyAnim, EnemyDying, EnemyCorpse, SpawnPortal, SealedPortal, OpenedRift, PortalSealed, RiftState, FlankRiftRequest, RiftPhase, TemporaryRift, PortalVortex, PortalLight, PortalSpawnAnim, EnemyCoreSet, WaveState, WavePhase, SupportAura, AuraBuff, FleeFromPlayer, TargetPriority, EnemyBehavior, BehaviorProfile, BehaviorNode, PackLeader, PackMember, PackPhase, PackOrder, PackScatter, AttackTokenCost, AttackTokenBudget, BodyBlocking, TokenPool, BypassAttackTokens, Alerted};
@@ -1,39 +1,74 @@
 use bevy::prelude::*;
 use bevy::light::NotShadowCaster;
 use avian3d::prelude::*;
-use crate::modules::player::components::Player;
+use crate::modules::player::components::{Player, PlayerModel};
 use crate::modules::enemies::components::*;
+use crate::modules::enemies::parts::behavior::{self, BehaviorContext};
+use crate::modules::enemies::parts::flow_field::{self, FlowField};
 use crate::modules::combat::components::EnemyAttackCooldown;
 use crate::modules::combat::parts::knockback::{Staggered, StaggerRecovery};
+use crate::modules::hazards::HazardSlow;
 use crate::modules::world::GroundCircle;
 use crate::modules::combat::parts::game_over::KillCount;
 use crate::modules::menu::KillFeedMessage;
 use crate::modules::selection::components::Selected;
+use crate::modules::spatial::SpatialIndexed;
 use bevy::ecs::system::Commands;
 
-/// Система AI: враги реагируют на игрока по дистанции
-/// - Далеко (> aggro_range): стоит на месте (Idle)
-/// - Средне (attack_range*1.3..aggro_range): преследует (Walking/Running)
-/// - Близко (<= attack_range*1.3) + есть слот: атакует (Attacking)
-/// - Близко (<= attack_range*1.3) + нет слота: кружит (Orbiting → Walking anim)
+/// Тик счётчика кадров AI (начало AI chain)
+pub fn advance_ai_tick(mut tick: ResMut<AiTick>) {
+    tick.frame = tick.frame.wrapping_add(1);
+}
+
+/// Система AI: контекст (дистанция, слот, HP, взгляд игрока) → узел поведения → steering.
+/// Выбор узла и steering — в behavior.rs (utility по профилю архетипа),
+/// здесь только сбор контекста и применение результата к ECS.
+/// Вне ближнего боя скорость огибает препятствия по FlowField.
+/// Reduced/Minimal LOD думают раз в 2/4 кадра (между тиками держат steering),
+/// обход параллельный, команды — через ParallelCommands.
 pub fn enemy_ai_system(
-    mut commands: Commands,
+    par_commands: ParallelCommands,
     time: Res<Time>,
+    tick: Res<AiTick>,
+    flow: Res<FlowField>,
     mut enemies: Query<
-        (Entity, &ChasePlayer, &Health, &Children, &Transform, &mut LinearVelocity, &mut EnemyAnimState, &mut OrbitDirection, Option<&HasAttackSlot>),
+        (Entity, &ChasePlayer, &Health, &Transform, &EnemyLod, &mut LinearVelocity, &mut EnemyAnimState, &mut OrbitDirection, &mut EnemyBehavior, Option<&HasAttackSlot>, Option<&FleeFromPlayer>, (Option<&AuraBuff>, Option<&HazardSlow>), Option<&PackMember>, Has<PackScatter>, Has<Alerted>),
         (With<Enemy>, Without<Player>, Without<EnemyDying>, Without<Staggered>, Without<StaggerRecovery>, Without<SpawnScream>)
     >,
     player: Query<&Transform, With<Player>>,
-    mut model_query: Query<&mut Transform, (With<EnemyModel>, Without<Enemy>, Without<Player>)>,
+    player_model: Query<&Transform, (With<PlayerModel>, Without<EnemyModel>)>,
 ) {
     let Ok(player_transform) = player.single() else {
         return;
     };
 
     let player_pos = player_transform.translation;
-    let dt = time.delta_secs();
+    let frame_dt = time.delta_secs();
+    let frame_delta = time.delta();
+
+    // Взгляд игрока: модель — child (parent без вращения), лицом к +Z при identity
+    let player_forward = player_model.single()
+        .map(|t| {
+            let f = t.rotation * Vec3::Z;
+            Vec3::new(f.x, 0.0, f.z).normalize_or_zero()
+        })
+        .unwrap_or(Vec3::Z);
+
+    enemies.par_iter_mut().for_each(|(entity, chase, health, enemy_transform, lod, mut velocity, mut anim_state, mut orbit, mut behavior, has_slot, flee, (buff, slow), pack, scattering, alerted)| {
+        // Тикаем таймер смены направления орбиты
+        orbit.change_timer.tick(frame_delta);
+        if orbit.change_timer.just_finished() {
+            orbit.clockwise = !orbit.clockwise;
+        }
+
+        // Time-slicing: между тиками — последняя скорость (иначе LinearDamping гасит движение)
+        let period = lod.ai_period();
+        if !tick.is_due(entity, period) {
+            velocity.0 = behavior.steering;
+            return;
+        }
+        let dt = frame_dt * period as f32;
 
-    for (entity, chase, health, children, enemy_transform, mut velocity, mut anim_state, mut orbit, has_slot) in &mut enemies {
         let enemy_pos = enemy_transform.translation;
         let distance = (player_pos - enemy_pos).length();
 
@@ -42,84 +77,91 @@ pub fn enemy_ai_system(
         let direction_2d = Vec3::new(direction.x, 0.0, direction.z).normalize_or_zero();
 
         // Enrage: при HP < 30% — бежит вдвое быстрее
-        let is_enraged = health.current / health.max < 0.3;
+        let hp_fraction = health.current / health.max;
+        let is_enraged = hp_fraction < 0.3;
         let move_speed = if is_enraged { chase.speed * 2.0 } else { chase.speed };
+        // Бафф ауры Колдуна — ускорение, трясина — замедление
+        let move_speed = move_speed
+            * buff.map_or(1.0, |b| b.speed_multiplier)
+            * slow.map_or(1.0, |s| s.multiplier);
 
-        // Тикаем таймер смены направления орбиты
-        orbit.change_timer.tick(time.delta());
-        if orbit.change_timer.just_finished() {
-            orbit.clockwise = !orbit.clockwise;
-        }
+        // По тревоге враг охотится на всей арене, пока не потеряет игрока
+        let aggro_range = if alerted { f32::MAX } else { chase.aggro_range };
 
-        let orbit_radius = chase.attack_range * 1.2;
-
-        let new_state = if distance <= chase.attack_range * 1.3 {
-            if has_slot.is_some() {
-                // Есть слот → идти к игроку и атаковать
-                if distance > chase.attack_range {
-                    velocity.0 = direction_2d * move_speed * 0.6;
-                    if is_enraged { EnemyAnim::Running } else { EnemyAnim::Walking }
-                } else {
-                    velocity.0 = Vec3::ZERO;
-                    EnemyAnim::Attacking
-                }
-            } else {
-                // Нет слота → ORBIT: кружить вокруг игрока
-                let tangent = if orbit.clockwise {
-                    Vec3::new(-direction_2d.z, 0.0, direction_2d.x)
-                } else {
-                    Vec3::new(direction_2d.z, 0.0, -direction_2d.x)
-                };
+        let ctx = BehaviorContext {
+            to_player: direction_2d,
+            distance,
+            player_forward,
+            aggro_range,
+            attack_range: chase.attack_range,
+            move_speed,
+            is_enraged,
+            has_slot: has_slot.is_some(),
+            hp_fraction,
+            flee_range: flee.map(|f| f.flee_range),
+            pack_order: pack.map_or(PackOrder::Follow, |p| p.order),
+            scattering,
+        };
 
-                // Радиальная коррекция: держать дистанцию orbit_radius
-                let radial = if distance < orbit_radius * 0.9 {
-                    -direction_2d * 2.0 // отойти
-                } else if distance > orbit_radius * 1.3 {
-                    direction_2d * 2.0  // подойти
-                } else {
-                    Vec3::ZERO
-                };
+        let node = behavior::select_node(&behavior, &ctx);
+        if node != behavior.current {
+            debug!("🧠 Enemy {:?}: {:?} → {:?}", entity, behavior.current, node);
+        }
+        behavior::advance(&mut behavior, node, dt);
 
-                let orbit_speed = move_speed * 0.4;
-                velocity.0 = (tangent * orbit_speed + radial).clamp_length_max(move_speed * 0.5);
-                EnemyAnim::Walking
-            }
-        } else if distance <= chase.aggro_range {
-            // В зоне агро — преследуем
-            velocity.0 = direction_2d * move_speed;
-            if is_enraged { EnemyAnim::Running } else { EnemyAnim::Walking }
-        } else {
-            // Далеко — стоим и ждём
-            velocity.0 = Vec3::ZERO;
-            EnemyAnim::Idle
+        let (new_velocity, new_state) = behavior::steer(node, &ctx, &orbit);
+        // Обход препятствий: вблизи игрока путь прямой, поток не нужен
+        velocity.0 = match flow.sample(enemy_pos) {
+            Some(flow_dir) if distance > chase.attack_range * 1.3 =>
+                flow_field::blend_with_flow(new_velocity, direction_2d, flow_dir),
+            _ => new_velocity,
         };
+        behavior.steering = velocity.0;
 
         // Обновляем состояние только если изменилось (Changed<> фильтр в анимации)
         if anim_state.current != new_state {
             anim_state.current = new_state;
 
             // Управляем таймером повтора анимации атаки
-            if new_state == EnemyAnim::Attacking {
-                commands.entity(entity).insert(EnemyAttackAnimTimer {
-                    timer: Timer::from_seconds(1.0, TimerMode::Repeating),
-                });
-            } else {
-                commands.entity(entity).remove::<EnemyAttackAnimTimer>();
-            }
+            par_commands.command_scope(|mut commands| {
+                if new_state == EnemyAnim::Attacking {
+                    commands.entity(entity).insert(EnemyAttackAnimTimer {
+                        timer: Timer::from_seconds(1.0, TimerMode::Repeating),
+                    });
+                } else {
+                    commands.entity(entity).remove::<EnemyAttackAnimTimer>();
+                }
+            });
         }
 
-        // Поворачиваем child модель лицом к игроку (только в агро)
-        if distance <= chase.aggro_range && direction_2d.length() > 0.01 {
-            let target_rotation = Quat::from_rotation_y(direction_2d.x.atan2(direction_2d.z));
-            let t = (8.0 * dt).min(1.0);
+        // Лицом к игроку (только в агро), при бегстве/фланге — по ходу движения.
+        // Поворот модели — в enemy_facing_system (child transform, свой параллельный обход)
+        let facing = if behavior::faces_movement(node) {
+            Vec3::new(velocity.0.x, 0.0, velocity.0.z).normalize_or_zero()
+        } else {
+            direction_2d
+        };
+        behavior.facing = if distance <= aggro_range { facing } else { Vec3::ZERO };
+    });
+}
 
-            for &child in children {
-                if let Ok(mut model_transform) = model_query.get_mut(child) {
-                    model_transform.rotation = model_transform.rotation.lerp(target_rotation, t).normalize();
-                }
-            }
+/// Плавно поворачивает child модель врага к EnemyBehavior::facing (параллельно)
+pub fn enemy_facing_system(
+    time: Res<Time>,
+    enemies: Query<&EnemyBehavior, (With<Enemy>, Without<EnemyDying>, Without<Staggered>, Without<StaggerRecovery>, Without<SpawnScream>)>,
+    mut models: Query<(&ChildOf, &mut Transform), (With<EnemyModel>, Without<Enemy>)>,
+) {
+    let t = (8.0 * time.delta_secs()).min(1.0);
+
+    models.par_iter_mut().for_each(|(child_of, mut model_transform)| {
+        let Ok(behavior) = enemies.get(child_of.parent()) else { return };
+        let facing = behavior.facing;
+        if facing.length_squared() < 0.0001 {
+            return;
         }
-    }
+        let target_rotation = Quat::from_rotation_y(facing.x.atan2(facing.z));
+        model_transform.rotation = model_transform.rotation.lerp(target_rotation, t).normalize();
+    });
 }
 
 /// Когда HP <= 0 — запускаем анимацию смерти (не despawn сразу)
@@ -140,6 +182,7 @@ pub fn start_enemy_death(
                 EnemyType::Upyr => kill_count.upyr += 1,
                 EnemyType::Leshiy => kill_count.leshiy += 1,
                 EnemyType::Volkolak => kill_count.volkolak += 1,
+                EnemyType::Koldun => kill_count.koldun += 1,
             }
             debug!("💀 Enemy dying — playing death animation (kills: {})", kill_count.total);
 
@@ -148,6 +191,7 @@ pub fn start_enemy_death(
                 EnemyType::Upyr => "Упырь",
                 EnemyType::Leshiy => "Леший",
                 EnemyType::Volkolak => "Волколак",
+                EnemyType::Koldun => "Колдун",
             };
             feed.write(KillFeedMessage {
                 text: format!("{} убит!", name),
@@ -168,10 +212,19 @@ pub fn start_enemy_death(
                 .insert(EnemyDying {
                     timer: Timer::from_seconds(3.0, TimerMode::Once),
                 })
-                // AI + gameplay
+                // AI + gameplay (труп выпадает из SpatialIndex)
+                .remove::<SpatialIndexed>()
                 .remove::<Selected>()
                 .remove::<ChasePlayer>()
                 .remove::<OrbitDirection>()
+                .remove::<SupportAura>()
+                .remove::<AuraBuff>()
+                .remove::<FleeFromPlayer>()
+                .remove::<EnemyBehavior>()
+                .remove::<PackLeader>()
+                .remove::<PackMember>()
+                .remove::<PackScatter>()
+                .remove::<Alerted>()
                 .remove::<SpawnScream>()
                 .remove::<HasAttackSlot>()
                 .remove::<EnemyAttackAnimTimer>()
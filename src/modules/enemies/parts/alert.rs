@@ -0,0 +1,126 @@
+use bevy::prelude::*;
+use bevy::platform::collections::HashSet;
+use crate::modules::enemies::components::*;
+use crate::modules::spatial::{SpatialIndex, SpatialLayer};
+use crate::modules::combat::parts::knockback::Staggered;
+use crate::modules::player::components::Player;
+use super::flow_field::FlowField;
+
+/// Радиус, в котором крик/тревога поднимает соседей
+const ALERT_RADIUS: f32 = 8.0;
+/// Сколько секунд без продвижения к игроку, прежде чем враг теряет его
+const LOSE_PLAYER_TIME: f32 = 6.0;
+/// Путь короче прежнего минимума на столько (м) — враг продвигается к игроку
+const PROGRESS_STEP: f32 = 1.0;
+/// Крик поднятого по тревоге (переиспользует SpawnScream)
+const RELAY_SCREAM_DURATION: f32 = 1.0;
+
+/// Тревога: враг у origin заметил игрока — соседи в радиусе подхватывают
+#[derive(Message)]
+pub struct EnemyAlertMessage {
+    pub source: Entity,
+    pub origin: Vec3,
+}
+
+fn new_alerted() -> Alerted {
+    Alerted {
+        lose_timer: Timer::from_seconds(LOSE_PLAYER_TIME, TimerMode::Once),
+        closest: f32::MAX,
+    }
+}
+
+/// Источники тревоги: вход в агро-зону, попадание игрока, крик поднятого врага.
+/// Крик ретранслирует тревогу дальше — волна проходит по всей орде.
+pub fn alert_trigger_system(
+    mut commands: Commands,
+    player: Query<&Transform, With<Player>>,
+    enemies: Query<(Entity, &Transform, &ChasePlayer, Has<Alerted>), (With<Enemy>, Without<EnemyDying>)>,
+    hit: Query<Entity, (With<Enemy>, Without<EnemyDying>, Without<Alerted>, Added<Staggered>)>,
+    screams: Query<(Entity, &Transform), (With<Enemy>, With<Alerted>, Without<EnemyDying>, Added<SpawnScream>)>,
+    mut alerts: MessageWriter<EnemyAlertMessage>,
+) {
+    let Ok(player_tf) = player.single() else { return };
+    let player_pos = player_tf.translation;
+
+    for (entity, transform, chase, alerted) in &enemies {
+        let entered_aggro = (player_pos - transform.translation).length() <= chase.aggro_range;
+        if alerted || !(entered_aggro || hit.contains(entity)) {
+            continue;
+        }
+        commands.entity(entity).insert(new_alerted());
+        alerts.write(EnemyAlertMessage { source: entity, origin: transform.translation });
+    }
+
+    for (entity, transform) in &screams {
+        alerts.write(EnemyAlertMessage { source: entity, origin: transform.translation });
+    }
+}
+
+/// Распространение тревоги через SpatialIndex: спокойные соседи кричат и начинают охоту
+pub fn alert_propagation_system(
+    mut commands: Commands,
+    index: Res<SpatialIndex>,
+    mut alerts: MessageReader<EnemyAlertMessage>,
+    mut calm: Query<&mut EnemyAnimState, (With<Enemy>, Without<Alerted>, Without<EnemyDying>, Without<SpawnScream>)>,
+    mut buf: Local<Vec<Entity>>,
+    mut raised: Local<HashSet<Entity>>,
+) {
+    raised.clear();
+    for alert in alerts.read() {
+        buf.clear();
+        index.for_each_in_radius(SpatialLayer::Enemy, alert.origin, ALERT_RADIUS, |entity, _| {
+            if entity != alert.source {
+                buf.push(entity);
+            }
+        });
+
+        for &entity in buf.iter() {
+            let Ok(mut anim_state) = calm.get_mut(entity) else { continue };
+            if !raised.insert(entity) {
+                continue;
+            }
+            anim_state.current = EnemyAnim::Screaming;
+            commands.entity(entity).insert((
+                new_alerted(),
+                SpawnScream { timer: Timer::from_seconds(RELAY_SCREAM_DURATION, TimerMode::Once) },
+            ));
+        }
+    }
+
+    if !raised.is_empty() {
+        debug!("📢 Alert raised {} enemies", raised.len());
+    }
+}
+
+/// Потеря игрока: таймер тикает, только пока путь к игроку (flow field) не сокращается.
+/// В агро-зоне, во время крика и пока враг подбирается издалека — сброшен.
+pub fn alert_decay_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    flow: Res<FlowField>,
+    player: Query<&Transform, With<Player>>,
+    mut alerted: Query<(Entity, &Transform, &ChasePlayer, &mut Alerted, Has<SpawnScream>), (With<Enemy>, Without<EnemyDying>)>,
+) {
+    let Ok(player_tf) = player.single() else { return };
+    let player_pos = player_tf.translation;
+
+    for (entity, transform, chase, mut alert, screaming) in &mut alerted {
+        let straight = (player_pos - transform.translation).length();
+        // Без готового поля — прямая дистанция; нет пути — прогресса нет
+        let path = if flow.is_ready() {
+            flow.path_distance(transform.translation).unwrap_or(f32::MAX)
+        } else {
+            straight
+        };
+
+        if screaming || straight <= chase.aggro_range || path < alert.closest - PROGRESS_STEP {
+            alert.closest = path;
+            alert.lose_timer.reset();
+            continue;
+        }
+        alert.lose_timer.tick(time.delta());
+        if alert.lose_timer.is_finished() {
+            commands.entity(entity).remove::<Alerted>();
+        }
+    }
+}
@@ -1,83 +1,162 @@
 use bevy::prelude::*;
 use crate::modules::enemies::components::*;
-use crate::shared::rand_01;
 use crate::modules::player::components::Player;
 use crate::modules::combat::components::EnemyAttackCooldown;
+use crate::modules::world::{SelectedMode, GAME_MODES};
+use super::body_block::{PLAYER_BODY_RADIUS, ENEMY_BODY_RADIUS};
 
-/// Выделяет attack slots ближайшим врагам (max 4 одновременных атакующих)
+/// Штраф за скученность: кандидат с той же стороны, что и атакующий, "дальше" в 1 + 2 раза
+const CROWDING_PENALTY: f32 = 2.0;
+
+/// Занятые токены по пулам (melee, ranged) — боссы не учитываются
+pub fn tokens_in_use<'a>(slotted: impl Iterator<Item = Option<&'a AttackTokenCost>>) -> (u32, u32) {
+    slotted.fold((0, 0), |(melee, ranged), cost| {
+        let cost = cost.copied().unwrap_or_default();
+        match cost.pool {
+            TokenPool::Melee => (melee + cost.cost, ranged),
+            TokenPool::Ranged => (melee, ranged + cost.cost),
+        }
+    })
+}
+
+/// OnEnter(Playing): множитель бюджета из выбранного режима (Натиск Нави — больше атакующих разом)
+pub fn apply_mode_token_budget(
+    selected: Res<SelectedMode>,
+    mut budget: ResMut<AttackTokenBudget>,
+) {
+    budget.difficulty = GAME_MODES[selected.0].token_budget;
+    budget.rescale(0);
+}
+
+/// Пересчёт бюджета токенов при смене волны
+pub fn update_attack_token_budget(
+    wave: Res<WaveState>,
+    mut budget: ResMut<AttackTokenBudget>,
+) {
+    if wave.is_changed() {
+        budget.rescale(wave.current_wave);
+    }
+}
+
+/// Раздаёт токены атаки кандидатам в радиусе атаки.
+/// Приоритет: дистанция × штраф за угол (атакующие распределяются вокруг героя, а не в одну кучу).
+/// Дорогой враг ждёт, пока освободится достаточно токенов в его пуле; боссы вне бюджета.
+/// Стаи волколаков резервируют токены сами (pack_hunting_system).
+/// С блокировкой телами melee-атакующих не больше, чем тел помещается в кольцо контакта.
 pub fn attack_slot_system(
     mut commands: Commands,
-    slot_manager: Res<AttackSlotManager>,
+    mut budget: ResMut<AttackTokenBudget>,
+    blocking: Res<BodyBlocking>,
     player: Query<&Transform, With<Player>>,
-    slotted: Query<Entity, (With<HasAttackSlot>, With<Enemy>, Without<EnemyDying>)>,
+    slotted: Query<(&Transform, Option<&AttackTokenCost>), (With<HasAttackSlot>, With<Enemy>, Without<EnemyDying>, Without<BypassAttackTokens>)>,
     unslotted: Query<
-        (Entity, &Transform, &ChasePlayer),
-        (With<Enemy>, Without<HasAttackSlot>, Without<EnemyDying>)
+        (Entity, &Transform, &ChasePlayer, &EnemyLod, Option<&AttackTokenCost>, Has<BypassAttackTokens>),
+        (With<Enemy>, Without<HasAttackSlot>, Without<EnemyDying>, Without<FleeFromPlayer>, Without<PackMember>)
     >,
-    mut candidates: Local<Vec<(Entity, f32)>>,
+    mut candidates: Local<Vec<(Entity, f32, Vec3, AttackTokenCost)>>,
+    mut attackers: Local<Vec<Vec3>>,
 ) {
     let Ok(player_tf) = player.single() else { return };
     let player_pos = player_tf.translation;
 
-    let active = slotted.iter().count() as u32;
-    let available = slot_manager.max_slots.saturating_sub(active);
-
-    if available == 0 {
-        return;
+    let (used_melee, used_ranged) = tokens_in_use(slotted.iter().map(|(_, cost)| cost));
+    let mut free_melee = budget.melee_tokens.saturating_sub(used_melee);
+    let mut free_ranged = budget.ranged_tokens.saturating_sub(used_ranged);
+    if blocking.enabled {
+        // Токен ≈ тело: остальные ждут снаружи кольца, а не толкаются в нём
+        let ring = BodyBlocking::ring_capacity(PLAYER_BODY_RADIUS, ENEMY_BODY_RADIUS);
+        free_melee = free_melee.min(ring.saturating_sub(used_melee));
     }
 
-    // Кандидаты: враги без слота в радиусе attack_range * 1.5 (squared для скорости)
+    // Направления уже атакующих (XZ) — для штрафа за скученность
+    attackers.clear();
+    attackers.extend(slotted.iter().map(|(tf, _)| {
+        let d = tf.translation - player_pos;
+        Vec3::new(d.x, 0.0, d.z).normalize_or_zero()
+    }));
+
+    // Кандидаты: враги без токенов в радиусе attack_range * 1.5 (squared для скорости)
     // Local<Vec> — capacity переиспользуется между кадрами (0 аллокаций в steady state)
     candidates.clear();
-    candidates.extend(unslotted.iter().filter_map(|(entity, tf, chase)| {
-        let dist_sq = (player_pos - tf.translation).length_squared();
+    for (entity, tf, chase, lod, cost, bypass) in &unslotted {
+        // Дальние LOD заведомо вне радиуса атаки — без вычислений
+        if *lod != EnemyLod::Full {
+            continue;
+        }
+        let diff = tf.translation - player_pos;
+        let dist_sq = diff.length_squared();
         let max = chase.attack_range * 1.5;
-        if dist_sq <= max * max {
-            Some((entity, dist_sq))
-        } else {
-            None
+        if dist_sq > max * max {
+            continue;
         }
-    }));
-
-    // Partial sort: нужны только top-N ближайших, полная сортировка не нужна
-    let n = (available as usize).min(candidates.len());
-    if n > 0 {
-        candidates.select_nth_unstable_by(n - 1, |a, b| a.1.partial_cmp(&b.1).unwrap());
+        // Босс: атакует без очереди
+        if bypass {
+            commands.entity(entity).insert(HasAttackSlot);
+            continue;
+        }
+        let dir = Vec3::new(diff.x, 0.0, diff.z).normalize_or_zero();
+        candidates.push((entity, dist_sq.sqrt(), dir, cost.copied().unwrap_or_default()));
     }
 
-    // Выдаём слоты
-    for &(entity, _) in candidates.iter().take(n) {
+    // Жадная раздача: каждый раз берём лучшего с учётом уже выбранных направлений
+    while !candidates.is_empty() && (free_melee > 0 || free_ranged > 0) {
+        let best = candidates.iter().enumerate()
+            .filter(|(_, c)| {
+                let free = match c.3.pool {
+                    TokenPool::Melee => free_melee,
+                    TokenPool::Ranged => free_ranged,
+                };
+                c.3.cost <= free
+            })
+            .map(|(i, c)| {
+                let crowding = attackers.iter()
+                    .map(|a| a.dot(c.2).max(0.0))
+                    .fold(0.0_f32, f32::max);
+                (i, c.1 * (1.0 + crowding * CROWDING_PENALTY))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+
+        let Some((index, _)) = best else { break };
+        let (entity, _, dir, cost) = candidates.swap_remove(index);
+        match cost.pool {
+            TokenPool::Melee => free_melee -= cost.cost,
+            TokenPool::Ranged => free_ranged -= cost.cost,
+        }
+        attackers.push(dir);
         commands.entity(entity).insert(HasAttackSlot);
     }
+
+    budget.waiting = candidates.len() as u32;
 }
 
-/// Освобождает attack slots: ротация атакующих + cleanup далёких
+/// Освобождает токены: ротация атакующих + cleanup далёких (токены стаи — по окончании броска).
+/// После удара токен отдаётся, только если его кто-то ждёт.
 pub fn release_attack_slot_system(
     mut commands: Commands,
+    budget: Res<AttackTokenBudget>,
     slotted: Query<
-        (Entity, &Transform, &EnemyAttackCooldown),
-        (With<HasAttackSlot>, With<Enemy>, Without<EnemyDying>)
+        (Entity, &Transform, &ChasePlayer, &EnemyAttackCooldown),
+        (With<HasAttackSlot>, With<Enemy>, Without<EnemyDying>, Without<PackMember>, Without<BypassAttackTokens>)
     >,
     player: Query<&Transform, With<Player>>,
 ) {
     let Ok(player_tf) = player.single() else { return };
     let player_pos = player_tf.translation;
 
-    for (entity, tf, cd) in &slotted {
+    for (entity, tf, chase, cd) in &slotted {
         let dist_sq = (player_pos - tf.translation).length_squared();
 
-        // Отпустить слот если враг далеко (отброшен knockback'ом)
-        if dist_sq > 36.0 { // 6.0²
+        // Отпустить слот если враг далеко (отброшен knockback'ом; дальнобойный — ушёл из радиуса атаки)
+        let release = (chase.attack_range * 1.5).max(6.0);
+        if dist_sq > release * release {
             commands.entity(entity).remove::<HasAttackSlot>();
             continue;
         }
 
-        // После атаки → 30% шанс отпустить слот (ротация атакующих)
-        // Проверяем что cooldown только что сброшен (fraction < 0.1 = начало нового цикла)
-        if cd.timer.fraction() > 0.05 && cd.timer.fraction() < 0.15 {
-            if rand_01() < 0.3 {
-                commands.entity(entity).remove::<HasAttackSlot>();
-            }
+        // После атаки → отдать токен ожидающим (ротация атакующих)
+        // Проверяем что cooldown только что сброшен (fraction < 0.15 = начало нового цикла)
+        if budget.waiting > 0 && cd.timer.fraction() > 0.05 && cd.timer.fraction() < 0.15 {
+            commands.entity(entity).remove::<HasAttackSlot>();
         }
     }
 }
@@ -0,0 +1,261 @@
+use bevy::prelude::*;
+use crate::modules::enemies::components::*;
+
+/// Длительность рывка (Charge) и пауза до следующего
+const CHARGE_DURATION: f32 = 0.8;
+const CHARGE_COOLDOWN: f32 = 4.0;
+const CHARGE_SPEED_MULTIPLIER: f32 = 1.8;
+/// Бонус текущему узлу — защита от дрожания между узлами с близкими оценками
+const HYSTERESIS_BONUS: f32 = 0.05;
+/// Радиус кольца, на котором стая кружит перед броском
+const PACK_CIRCLE_RADIUS: f32 = 7.0;
+const LUNGE_SPEED_MULTIPLIER: f32 = 1.6;
+/// Приказ стаи перебивает любые оценки профиля
+const PACK_ORDER_SCORE: f32 = 1.1;
+
+/// Снимок мира для выбора узла: всё, что нужно профилю, без доступа к ECS
+pub struct BehaviorContext {
+    /// Направление к игроку (XZ, нормализовано)
+    pub to_player: Vec3,
+    pub distance: f32,
+    /// Куда смотрит модель игрока (XZ, нормализовано)
+    pub player_forward: Vec3,
+    pub aggro_range: f32,
+    pub attack_range: f32,
+    pub move_speed: f32,
+    pub is_enraged: bool,
+    pub has_slot: bool,
+    pub hp_fraction: f32,
+    /// Дистанция бегства (FleeFromPlayer), если есть
+    pub flee_range: Option<f32>,
+    /// Приказ стаи (PackMember), Follow — без стаи
+    pub pack_order: PackOrder,
+    /// Стая потеряла вожака — разбегается
+    pub scattering: bool,
+}
+
+impl BehaviorContext {
+    /// Враг в зоне ближнего боя (чуть шире attack_range — гистерезис с AI)
+    fn in_melee(&self) -> bool {
+        self.distance <= self.attack_range * 1.3
+    }
+
+    /// Враг за спиной игрока (вне его поля зрения)
+    fn is_behind_player(&self) -> bool {
+        self.player_forward.dot(-self.to_player) < -0.3
+    }
+
+    /// Игрок смотрит на врага
+    fn player_facing_enemy(&self) -> bool {
+        self.player_forward.dot(-self.to_player) > 0.6
+    }
+}
+
+/// Utility-оценка узла для профиля (0 = узел недоступен)
+fn score(profile: BehaviorProfile, node: BehaviorNode, ctx: &BehaviorContext, charge_ready: bool) -> f32 {
+    let in_aggro = ctx.distance <= ctx.aggro_range;
+    let melee = ctx.in_melee();
+
+    match (profile, node) {
+        (_, BehaviorNode::Idle) if !in_aggro => 1.0,
+
+        // Колдун: держит дистанцию, при сближении убегает (в ближний бой не идёт)
+        (BehaviorProfile::Support, _) => match node {
+            BehaviorNode::Retreat if ctx.distance < ctx.flee_range.unwrap_or(ctx.attack_range * 3.0) * 0.6 => 1.0,
+            BehaviorNode::KeepDistance if in_aggro => 0.8,
+            _ => 0.0,
+        },
+
+        // Общий ближний бой: есть слот — атака, нет — кружит
+        (_, BehaviorNode::Attack) if melee && ctx.has_slot => 1.0,
+        (_, BehaviorNode::CircleStrafe) if melee && !ctx.has_slot => 0.9,
+        (_, BehaviorNode::Chase) if in_aggro => 0.5,
+
+        // Леший: заходит со спины, замирает пока игрок смотрит на него
+        (BehaviorProfile::Flanker, BehaviorNode::Ambush)
+            if in_aggro && ctx.hp_fraction > 0.5 && ctx.distance > ctx.attack_range * 4.0 && ctx.player_facing_enemy() => 0.85,
+        (BehaviorProfile::Flanker, BehaviorNode::Flank)
+            if in_aggro && !melee && !ctx.is_behind_player() => 0.8,
+
+        // Волколак в стае: приказы вожака (кольцо → вой → бросок), без вожака — врассыпную
+        (BehaviorProfile::Predator, BehaviorNode::PackCircle)
+            if matches!(ctx.pack_order, PackOrder::Circle { .. }) => PACK_ORDER_SCORE,
+        (BehaviorProfile::Predator, BehaviorNode::Howl)
+            if ctx.pack_order == PackOrder::Howl => PACK_ORDER_SCORE,
+        (BehaviorProfile::Predator, BehaviorNode::Lunge)
+            if matches!(ctx.pack_order, PackOrder::Lunge { .. }) => PACK_ORDER_SCORE,
+        (BehaviorProfile::Predator, BehaviorNode::Retreat) if ctx.scattering => PACK_ORDER_SCORE,
+
+        // Волколак: рывок со средней дистанции, затем ближний бой
+        (BehaviorProfile::Predator, BehaviorNode::Charge)
+            if charge_ready && ctx.distance > ctx.attack_range * 2.5 && ctx.distance < ctx.aggro_range * 0.7 => 0.95,
+
+        // Упырь (Brute): только общие узлы — прямолинейный
+        _ => 0.0,
+    }
+}
+
+/// Выбирает узел с максимальной оценкой (текущий узел получает небольшой бонус)
+pub fn select_node(behavior: &EnemyBehavior, ctx: &BehaviorContext) -> BehaviorNode {
+    let charge_ready = behavior.charge_cooldown <= 0.0;
+    let mut best = BehaviorNode::Idle;
+    let mut best_score = f32::MIN;
+
+    for &node in BehaviorNode::ALL {
+        let mut s = score(behavior.profile, node, ctx, charge_ready);
+        if s <= 0.0 {
+            continue;
+        }
+        if node == behavior.current {
+            s += HYSTERESIS_BONUS;
+        }
+        if s > best_score {
+            best_score = s;
+            best = node;
+        }
+    }
+
+    // Незавершённый рывок не прерывается (кроме ближнего боя и приказа стаи)
+    if behavior.current == BehaviorNode::Charge
+        && behavior.node_time < CHARGE_DURATION
+        && !ctx.in_melee()
+        && ctx.pack_order == PackOrder::Follow
+    {
+        return BehaviorNode::Charge;
+    }
+
+    best
+}
+
+/// Обновляет таймеры поведения после выбора узла (время в узле, кулдаун рывка)
+pub fn advance(behavior: &mut EnemyBehavior, node: BehaviorNode, dt: f32) {
+    if node != behavior.current {
+        // Выход из рывка — запускаем кулдаун
+        if behavior.current == BehaviorNode::Charge {
+            behavior.charge_cooldown = CHARGE_COOLDOWN;
+        }
+        behavior.current = node;
+        behavior.node_time = 0.0;
+    } else {
+        behavior.node_time += dt;
+    }
+    behavior.charge_cooldown = (behavior.charge_cooldown - dt).max(0.0);
+}
+
+/// Касательная орбиты вокруг игрока (направление по OrbitDirection)
+fn orbit_tangent(to_player: Vec3, orbit: &OrbitDirection) -> Vec3 {
+    if orbit.clockwise {
+        Vec3::new(-to_player.z, 0.0, to_player.x)
+    } else {
+        Vec3::new(to_player.z, 0.0, -to_player.x)
+    }
+}
+
+/// Скорость и анимация для узла. Узлы не знают про ECS — только про контекст.
+pub fn steer(node: BehaviorNode, ctx: &BehaviorContext, orbit: &OrbitDirection) -> (Vec3, EnemyAnim) {
+    let dir = ctx.to_player;
+    let speed = ctx.move_speed;
+    let locomotion = if ctx.is_enraged { EnemyAnim::Running } else { EnemyAnim::Walking };
+
+    match node {
+        BehaviorNode::Idle | BehaviorNode::Ambush => (Vec3::ZERO, EnemyAnim::Idle),
+
+        BehaviorNode::Chase => (dir * speed, locomotion),
+
+        BehaviorNode::Attack => {
+            if ctx.distance > ctx.attack_range {
+                (dir * speed * 0.6, locomotion)
+            } else {
+                (Vec3::ZERO, EnemyAnim::Attacking)
+            }
+        }
+
+        BehaviorNode::CircleStrafe => {
+            let orbit_radius = ctx.attack_range * 1.2;
+            // Радиальная коррекция: держать дистанцию orbit_radius
+            let radial = if ctx.distance < orbit_radius * 0.9 {
+                -dir * 2.0 // отойти
+            } else if ctx.distance > orbit_radius * 1.3 {
+                dir * 2.0 // подойти
+            } else {
+                Vec3::ZERO
+            };
+            let tangent = orbit_tangent(dir, orbit);
+            ((tangent * speed * 0.4 + radial).clamp_length_max(speed * 0.5), EnemyAnim::Walking)
+        }
+
+        BehaviorNode::Flank => {
+            // Точка за спиной игрока, смещённая в сторону орбиты; по дуге, не через игрока
+            let behind = -ctx.player_forward;
+            let side = orbit_tangent(ctx.player_forward, orbit);
+            let offset = (behind + side * 0.5).normalize_or_zero() * ctx.attack_range * 1.5;
+            let (to_target, _) = toward_offset(ctx, offset);
+            let tangent = orbit_tangent(dir, orbit);
+            let v = (to_target + tangent * 0.6).normalize_or_zero() * speed;
+            (v, EnemyAnim::Running)
+        }
+
+        BehaviorNode::Charge => (dir * speed * CHARGE_SPEED_MULTIPLIER, EnemyAnim::Running),
+
+        BehaviorNode::Retreat => {
+            // Прочь от игрока + уклон по орбите (не упирается в стену по прямой)
+            let flee = ctx.flee_range.unwrap_or(ctx.attack_range * 3.0);
+            let urgency = (1.0 - ctx.distance / flee).clamp(0.0, 1.0);
+            let tangent = orbit_tangent(dir, orbit);
+            let v = (-dir + tangent * 0.5).normalize_or_zero() * speed * (0.8 + urgency * 0.6);
+            (v, EnemyAnim::Running)
+        }
+
+        BehaviorNode::KeepDistance => {
+            let preferred = ctx.flee_range.unwrap_or(ctx.attack_range * 3.0) + 2.0;
+            let tangent = orbit_tangent(dir, orbit);
+            let radial = if ctx.distance < preferred * 0.9 {
+                -dir
+            } else if ctx.distance > preferred * 1.2 {
+                dir
+            } else {
+                Vec3::ZERO
+            };
+            let v = (radial + tangent * 0.4).clamp_length_max(1.0) * speed * 0.7;
+            if v.length_squared() > 0.01 { (v, EnemyAnim::Walking) } else { (Vec3::ZERO, EnemyAnim::Idle) }
+        }
+
+        BehaviorNode::PackCircle => {
+            let PackOrder::Circle { angle } = ctx.pack_order else { return (Vec3::ZERO, EnemyAnim::Idle) };
+            // Точка на кольце: вдали — бег, у точки — шаг (держит позицию, пока кольцо вращается)
+            let (to_slot, slot_dist) = toward_offset(ctx, ring_offset(angle) * PACK_CIRCLE_RADIUS);
+            let factor = (slot_dist / 2.0).clamp(0.3, 1.0);
+            let anim = if factor > 0.6 { EnemyAnim::Running } else { EnemyAnim::Walking };
+            (to_slot * speed * factor, anim)
+        }
+
+        BehaviorNode::Howl => (Vec3::ZERO, EnemyAnim::Screaming),
+
+        BehaviorNode::Lunge => {
+            let PackOrder::Lunge { angle } = ctx.pack_order else { return (Vec3::ZERO, EnemyAnim::Idle) };
+            if ctx.distance <= ctx.attack_range && ctx.has_slot {
+                return (Vec3::ZERO, EnemyAnim::Attacking);
+            }
+            // Бросок к точке у игрока со своего угла — стая бьёт с нескольких сторон
+            let (to_slot, _) = toward_offset(ctx, ring_offset(angle) * ctx.attack_range * 0.8);
+            (to_slot * speed * LUNGE_SPEED_MULTIPLIER, EnemyAnim::Running)
+        }
+    }
+}
+
+/// Узлы, в которых модель смотрит по ходу движения, а не на игрока
+pub fn faces_movement(node: BehaviorNode) -> bool {
+    matches!(node, BehaviorNode::Retreat | BehaviorNode::Flank | BehaviorNode::PackCircle)
+}
+
+/// Направление к точке, смещённой от игрока на offset (относительно врага, XZ)
+fn toward_offset(ctx: &BehaviorContext, offset: Vec3) -> (Vec3, f32) {
+    let target = ctx.to_player * ctx.distance + offset;
+    let flat = Vec3::new(target.x, 0.0, target.z);
+    (flat.normalize_or_zero(), flat.length())
+}
+
+/// Единичный вектор на кольце вокруг игрока
+fn ring_offset(angle: f32) -> Vec3 {
+    Vec3::new(angle.cos(), 0.0, angle.sin())
+}
@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use bevy::platform::collections::HashSet;
+use crate::modules::enemies::components::*;
+
+/// Включён ли overlay узлов поведения (F5)
+#[derive(Resource, Default)]
+pub struct BehaviorDebugOverlay {
+    pub enabled: bool,
+}
+
+/// Подпись над врагом с текущим узлом поведения
+#[derive(Component)]
+pub struct BehaviorDebugLabel {
+    pub target: Entity,
+}
+
+/// F5: вкл/выкл overlay узлов поведения
+pub fn toggle_behavior_debug_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut overlay: ResMut<BehaviorDebugOverlay>,
+) {
+    if keys.just_pressed(KeyCode::F5) {
+        overlay.enabled = !overlay.enabled;
+        info!("[DEBUG] Behavior overlay: {}", if overlay.enabled { "ON" } else { "OFF" });
+    }
+}
+
+/// Подписи над врагами: профиль + узел + время в узле.
+/// Создаёт недостающие, двигает существующие, удаляет лишние (враг умер / overlay выключен).
+pub fn behavior_debug_label_system(
+    mut commands: Commands,
+    overlay: Res<BehaviorDebugOverlay>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    enemies: Query<(Entity, &GlobalTransform, &EnemyBehavior), (With<Enemy>, Without<EnemyDying>)>,
+    mut labels: Query<(Entity, &BehaviorDebugLabel, &mut Node, &mut Text, &mut Visibility)>,
+    ui_scale: Res<UiScale>,
+    mut labelled: Local<HashSet<Entity>>,
+) {
+    labelled.clear();
+    let camera = camera_query.single().ok();
+    let scale = ui_scale.0.max(0.01);
+
+    for (label_entity, label, mut node, mut text, mut visibility) in &mut labels {
+        let Ok((_, transform, behavior)) = enemies.get(label.target) else {
+            commands.entity(label_entity).despawn();
+            continue;
+        };
+        if !overlay.enabled {
+            commands.entity(label_entity).despawn();
+            continue;
+        }
+        labelled.insert(label.target);
+
+        let world_pos = transform.translation() + Vec3::Y * 1.4;
+        match camera.and_then(|(cam, cam_tf)| cam.world_to_viewport(cam_tf, world_pos).ok()) {
+            Some(screen_pos) => {
+                *visibility = Visibility::Inherited;
+                node.left = Val::Px(screen_pos.x / scale - 40.0);
+                node.top = Val::Px(screen_pos.y / scale);
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+        **text = format!("{:?}: {:?} {:.1}s", behavior.profile, behavior.current, behavior.node_time);
+    }
+
+    if !overlay.enabled {
+        return;
+    }
+
+    for (entity, _, _) in &enemies {
+        if labelled.contains(&entity) {
+            continue;
+        }
+        commands.spawn((
+            BehaviorDebugLabel { target: entity },
+            Text::new(""),
+            TextFont {
+                font_size: 11.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 1.0, 0.9)),
+            Node {
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            Visibility::Hidden,
+        ));
+    }
+}
+
+/// Удаляет подписи overlay (выход из Playing)
+pub fn cleanup_behavior_debug_labels(
+    mut commands: Commands,
+    query: Query<Entity, With<BehaviorDebugLabel>>,
+) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use avian3d::prelude::*;
+use crate::modules::enemies::components::*;
+use crate::modules::player::components::{Player, Dashing};
+use crate::modules::spatial::{SpatialIndex, SpatialLayer};
+use crate::modules::combat::parts::knockback::Staggered;
+use crate::modules::world::{SelectedMode, GAME_MODES};
+
+/// Радиус тела игрока (Collider::cylinder(0.5, ..) в spawner)
+pub const PLAYER_BODY_RADIUS: f32 = 0.5;
+/// Типичный радиус тела врага — для ёмкости кольца атакующих
+pub const ENEMY_BODY_RADIUS: f32 = 0.5;
+/// Самый широкий враг (волколак) — радиус поиска контактов
+const MAX_ENEMY_BODY_RADIUS: f32 = 0.8;
+
+/// OnEnter(Playing): блокировка телами по выбранному режиму
+pub fn apply_mode_body_blocking(
+    selected: Res<SelectedMode>,
+    mut blocking: ResMut<BodyBlocking>,
+) {
+    blocking.enabled = GAME_MODES[selected.0].body_blocking;
+}
+
+/// F7 (отладка): переключает блокировку телами поверх режима — до конца забега
+pub fn toggle_body_blocking_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut blocking: ResMut<BodyBlocking>,
+) {
+    if keys.just_pressed(KeyCode::F7) {
+        blocking.enabled = !blocking.enabled;
+        info!("🧱 Body blocking: {}", if blocking.enabled { "ON" } else { "OFF" });
+    }
+}
+
+/// Радиус тела врага из его коллайдера (цилиндр), иначе типичный
+fn body_radius(collider: &Collider) -> f32 {
+    collider.shape_scaled().as_cylinder().map_or(ENEMY_BODY_RADIUS, |cylinder| cylinder.radius)
+}
+
+/// Мягкая блокировка телами (после AI и движения игрока, до физики):
+/// - враг в контакте не идёт внутрь и выталкивается наружу пропорционально перекрытию;
+///   отброшенные ударом (Staggered) не трогаются — нокбэк и так уносит их наружу;
+/// - игрок теряет составляющую скорости в сторону каждого контакта — окружённый со всех
+///   сторон стоит на месте; толпа слегка теснит его (player_yield). Рывок проходит сквозь.
+pub fn body_block_system(
+    blocking: Res<BodyBlocking>,
+    index: Res<SpatialIndex>,
+    mut player: Query<(&Transform, &mut LinearVelocity, Has<Dashing>), (With<Player>, Without<Enemy>)>,
+    mut enemies: Query<(&Collider, &mut LinearVelocity, Has<Staggered>), (With<Enemy>, Without<EnemyDying>, Without<Player>)>,
+    mut contacts: Local<Vec<(Vec3, f32)>>,
+) {
+    if !blocking.enabled {
+        return;
+    }
+    let Ok((player_tf, mut player_velocity, dashing)) = player.single_mut() else { return };
+    let player_pos = player_tf.translation;
+
+    // Контакты: нормаль (игрок → враг) + доля перекрытия
+    contacts.clear();
+    index.for_each_in_radius(
+        SpatialLayer::Enemy, player_pos, PLAYER_BODY_RADIUS + MAX_ENEMY_BODY_RADIUS,
+        |entity, enemy_pos| {
+            let Ok((collider, mut velocity, staggered)) = enemies.get_mut(entity) else { return };
+            let contact = PLAYER_BODY_RADIUS + body_radius(collider);
+            let offset = Vec3::new(enemy_pos.x - player_pos.x, 0.0, enemy_pos.z - player_pos.z);
+            let dist = offset.length();
+            if dist >= contact || dist < 0.0001 {
+                return;
+            }
+            let normal = offset / dist;
+            let overlap = (contact - dist) / contact;
+            contacts.push((normal, overlap));
+
+            // Минимальная скорость наружу (идемпотентно — AI пишет velocity не каждый кадр)
+            if !staggered {
+                let min_out = overlap * blocking.push_strength;
+                let out = velocity.0.dot(normal);
+                if out < min_out {
+                    velocity.0 += normal * (min_out - out);
+                }
+            }
+        },
+    );
+
+    if dashing || contacts.is_empty() {
+        return;
+    }
+
+    // Два прохода: срезание по одной нормали может снова направить скорость в соседнюю
+    for _ in 0..2 {
+        for &(normal, _) in contacts.iter() {
+            let into = player_velocity.0.dot(normal);
+            if into > 0.0 {
+                player_velocity.0 -= normal * into;
+            }
+        }
+    }
+
+    // Толпа теснит: с одной стороны — сдвигает, со всех сторон — толчки гасят друг друга
+    let push: Vec3 = contacts.iter().map(|&(normal, overlap)| -normal * overlap).sum();
+    player_velocity.0 += push * blocking.push_strength * blocking.player_yield;
+}
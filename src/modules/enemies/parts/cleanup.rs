@@ -1,13 +1,15 @@
 use bevy::prelude::*;
 use crate::modules::enemies::components::*;
 use crate::modules::combat::parts::game_over::KillCount;
+use super::support_aura::AuraPulseVfx;
 
-/// Удаляет всех врагов, трупы и умирающих
+/// Удаляет всех врагов, трупы, умирающих и импульсы аур
 pub fn despawn_enemies(
     mut commands: Commands,
     enemies: Query<Entity, Or<(With<Enemy>, With<EnemyCorpse>, With<EnemyDying>)>>,
+    aura_pulses: Query<Entity, With<AuraPulseVfx>>,
 ) {
-    for entity in &enemies {
+    for entity in enemies.iter().chain(aura_pulses.iter()) {
         commands.entity(entity).despawn();
     }
 }
@@ -1,14 +1,41 @@
 use bevy::prelude::*;
+use bevy::platform::time::Instant;
 use crate::modules::player::components::Player;
 use crate::modules::enemies::components::*;
 use crate::shared::rand_01;
 use super::preload::EnemyAssets;
 
+/// Стресс-сцена: сколько врагов спавнить (desktop / wasm)
+#[cfg(not(target_arch = "wasm32"))]
+const STRESS_ENEMY_COUNT: usize = 1000;
+#[cfg(target_arch = "wasm32")]
+const STRESS_ENEMY_COUNT: usize = 300;
+/// Длительность замера стресс-сцены (сек)
+const STRESS_MEASURE_SECONDS: f32 = 10.0;
+/// Первые кадры после спавна не учитываем (загрузка сцен GLB)
+const STRESS_WARMUP_SECONDS: f32 = 2.0;
+/// Удержание кнопки стресс-сцены (сек) — случайный тап в бою не запускает
+const STRESS_HOLD_SECONDS: f32 = 1.0;
+
+/// Замер стресс-сцены: времена кадров за окно STRESS_MEASURE_SECONDS
+#[derive(Resource, Default)]
+pub struct StressTest {
+    pub active: bool,
+    pub elapsed: f32,
+    pub frame_times: Vec<f32>,
+    /// Итог последнего замера для счётчика (avg FPS, p95 кадр мс)
+    pub last_result: Option<(f32, f32)>,
+}
+
 /// Маркер для дебаг-счётчика врагов (абсолютный overlay)
 #[derive(Component)]
 pub struct DebugEnemyCounter;
 
-/// Создаёт дебаг-счётчик (вызывается при OnEnter(Playing))
+/// Кнопка стресс-сцены (touch/wasm: альтернатива F6) — под дебаг-счётчиком
+#[derive(Component)]
+pub struct StressSceneButton;
+
+/// Создаёт дебаг-счётчик и кнопку стресс-сцены (вызывается при OnEnter(Playing))
 pub fn setup_debug_counter(mut commands: Commands) {
     commands.spawn((
         DebugEnemyCounter,
@@ -25,6 +52,26 @@ pub fn setup_debug_counter(mut commands: Commands) {
             ..default()
         },
     ));
+    commands.spawn((
+        StressSceneButton,
+        Button,
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(12.0),
+            bottom: Val::Px(34.0),
+            padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.1, 0.2, 0.1, 0.6)),
+        GlobalZIndex(200),
+    )).with_child((
+        Text::new("Stress (hold)"),
+        TextFont {
+            font_size: 12.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.7, 0.9, 0.7)),
+    ));
 }
 
 /// Обновляет дебаг-счётчик (Alive / Dying / Corpses)
@@ -33,23 +80,103 @@ pub fn update_debug_counter(
     dying: Query<Entity, With<EnemyDying>>,
     corpses: Query<Entity, With<EnemyCorpse>>,
     mut text_query: Query<&mut Text, With<DebugEnemyCounter>>,
+    stress: Res<StressTest>,
 ) {
     let alive_count = alive.iter().count();
     let dying_count = dying.iter().count();
     let corpse_count = corpses.iter().count();
 
+    let stress_info = if stress.active {
+        " | Stress: measuring...".to_string()
+    } else if let Some((fps, p95)) = stress.last_result {
+        format!(" | Stress: {:.0} FPS, p95 {:.1}ms", fps, p95)
+    } else {
+        String::new()
+    };
+
     for mut text in &mut text_query {
         **text = format!(
-            "Alive: {} | Dying: {} | Corpses: {}",
-            alive_count, dying_count, corpse_count,
+            "Alive: {} | Dying: {} | Corpses: {}{}",
+            alive_count, dying_count, corpse_count, stress_info,
         );
     }
 }
 
-/// Удаляет дебаг-счётчик
+/// F6 или удержание кнопки: стресс-сцена — спавн STRESS_ENEMY_COUNT Упырей по всей арене + замер FPS
+pub fn stress_scene_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+    enemy_assets: Option<Res<EnemyAssets>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut stress: ResMut<StressTest>,
+    button: Query<&Interaction, With<StressSceneButton>>,
+    mut pressed_at: Local<Option<Instant>>,
+) {
+    // Удержание по реальному времени — hitstop не растягивает long-press
+    if button.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        pressed_at.get_or_insert_with(Instant::now);
+    } else {
+        *pressed_at = None;
+    }
+    let long_press = pressed_at.is_some_and(|at| at.elapsed().as_secs_f32() >= STRESS_HOLD_SECONDS);
+    if !(keys.just_pressed(KeyCode::F6) || long_press) || stress.active {
+        return;
+    }
+    *pressed_at = None;
+    let Some(assets) = &enemy_assets else { return };
+
+    for _ in 0..STRESS_ENEMY_COUNT {
+        let pos = Vec3::new((rand_01() - 0.5) * 44.0, 0.0, (rand_01() - 0.5) * 44.0);
+        super::spawner::spawn_upyr_at(
+            &mut commands, assets,
+            &mut materials, pos,
+        );
+    }
+
+    stress.active = true;
+    stress.elapsed = 0.0;
+    stress.frame_times.clear();
+    info!("[STRESS] Spawned {} Upyr, measuring {}s...", STRESS_ENEMY_COUNT, STRESS_MEASURE_SECONDS);
+}
+
+/// Собирает времена кадров стресс-сцены, по окончании — avg FPS / p95 / 1% low в лог
+pub fn stress_measure_system(
+    time: Res<Time>,
+    mut stress: ResMut<StressTest>,
+    alive: Query<(), (With<Enemy>, Without<EnemyDying>)>,
+) {
+    if !stress.active {
+        return;
+    }
+    let dt = time.delta_secs();
+    stress.elapsed += dt;
+    if stress.elapsed < STRESS_WARMUP_SECONDS {
+        return;
+    }
+    stress.frame_times.push(dt);
+
+    if stress.elapsed < STRESS_WARMUP_SECONDS + STRESS_MEASURE_SECONDS {
+        return;
+    }
+
+    let frames = stress.frame_times.len().max(1);
+    let avg_fps = frames as f32 / stress.frame_times.iter().sum::<f32>().max(0.0001);
+    stress.frame_times.sort_by(f32::total_cmp);
+    let p95_ms = stress.frame_times[(frames * 95 / 100).min(frames - 1)] * 1000.0;
+    let low_1_fps = 1.0 / stress.frame_times[(frames * 99 / 100).min(frames - 1)].max(0.0001);
+
+    info!(
+        "[STRESS] {} enemies alive: avg {:.1} FPS, p95 frame {:.2}ms, 1% low {:.1} FPS ({} frames)",
+        alive.iter().count(), avg_fps, p95_ms, low_1_fps, frames,
+    );
+    stress.last_result = Some((avg_fps, p95_ms));
+    stress.active = false;
+}
+
+/// Удаляет дебаг-счётчик и кнопку стресс-сцены
 pub fn cleanup_debug_counter(
     mut commands: Commands,
-    query: Query<Entity, With<DebugEnemyCounter>>,
+    query: Query<Entity, Or<(With<DebugEnemyCounter>, With<StressSceneButton>)>>,
 ) {
     for entity in &query {
         commands.entity(entity).despawn();
@@ -60,6 +187,7 @@ pub fn cleanup_debug_counter(
 /// F2: спавн 10 Упырей за раз
 /// F3: убить всех живых врагов (HP = 0)
 /// F4: деспавн всех трупов
+/// (F5 — overlay поведения, F6 — стресс-сцена: отдельные системы)
 pub fn debug_spawn_system(
     keys: Res<ButtonInput<KeyCode>>,
     mut commands: Commands,
@@ -0,0 +1,325 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use avian3d::prelude::*;
+use crate::modules::enemies::components::*;
+use crate::modules::player::components::Player;
+use crate::modules::menu::KillFeedMessage;
+use crate::modules::world::{
+    portal_light_color, portal_vortex, CurrentArena, PortalStyle, PortalVortexMaterial,
+    SafeZone, PORTAL_LIGHT_INTENSITY,
+};
+use crate::shared::rand_01;
+use super::flow_field::FlowField;
+use super::portal_vfx::{PortalSparkParticle, PortalVfxAssets};
+use super::preload::EnemyAssets;
+use super::spawner::spawn_wave_enemy;
+
+/// Дистанция разлома от игрока (м): за краем экрана, но не на другом конце арены
+const FLANK_MIN_DIST: f32 = 11.0;
+const FLANK_MAX_DIST: f32 = 17.0;
+/// Разброс направления вокруг «за спиной» (рад); после половины попыток — любое направление
+const FLANK_SPREAD: f32 = 1.0;
+const FLANK_ATTEMPTS: u32 = 32;
+/// Отступ от стен арены (м)
+const WALL_MARGIN: f32 = 2.5;
+/// Запас за краем экрана (px) — разлом не должен мелькать на кромке
+const OFFSCREEN_MARGIN: f32 = 60.0;
+/// Телеграф (с): вихрь раскрывается, свет мерцает — враги ещё не идут
+const OPENING_TIME: f32 = 1.6;
+/// Сколько разлом держится открытым, даже если бюджет не потрачен (с)
+const RIFT_LIFETIME: f32 = 12.0;
+const CLOSING_TIME: f32 = 0.6;
+/// Интервал выхода врагов (с)
+const RIFT_SPAWN_INTERVAL: f32 = 0.7;
+const RIFT_RADIUS: f32 = 1.6;
+/// Высота центра вихря над полом (м)
+const RIFT_HEIGHT: f32 = 1.5;
+
+/// Кэшированные меш и материалы вихря разлома (создаются один раз на OnEnter Playing)
+#[derive(Resource)]
+pub struct FlankRiftAssets {
+    pub disc_mesh: Handle<Mesh>,
+    /// Вихри по индексу разлома: 0 = Огонь, 1 = Тьма
+    pub vortex_materials: [Handle<PortalVortexMaterial>; 2],
+}
+
+/// Инициализация ассетов временных разломов
+pub fn init_flank_rift_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut vortex_materials: ResMut<Assets<PortalVortexMaterial>>,
+) {
+    let vortex_materials = [PortalStyle::Fire, PortalStyle::Dark]
+        .map(|style| vortex_materials.add(PortalVortexMaterial { settings: portal_vortex(style) }));
+    commands.insert_resource(FlankRiftAssets {
+        disc_mesh: meshes.add(Circle::new(RIFT_RADIUS)),
+        vortex_materials,
+    });
+}
+
+/// Где можно открыть фланговый разлом: игрок, камера, flow field, безопасная зона и раскладка арены
+#[derive(SystemParam)]
+pub struct FlankLookups<'w, 's> {
+    player: Query<'w, 's, (&'static Transform, &'static LinearVelocity), With<Player>>,
+    camera_query: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<Camera3d>>,
+    flow: Res<'w, FlowField>,
+    zone: Res<'w, SafeZone>,
+    arena: Option<Res<'w, CurrentArena>>,
+}
+
+/// Выпуск врагов из разлома: ассеты врагов, веса биома и материалы колец под ними
+#[derive(SystemParam)]
+pub struct RiftSpawnContext<'w> {
+    enemy_assets: Option<Res<'w, EnemyAssets>>,
+    arena: Option<Res<'w, CurrentArena>>,
+    materials: ResMut<'w, Assets<StandardMaterial>>,
+}
+
+/// Дочерние части разлома: вихрь (масштаб) и свет
+#[derive(SystemParam)]
+pub struct RiftParts<'w, 's> {
+    transforms: Query<'w, 's, &'static mut Transform, Without<TemporaryRift>>,
+    lights: Query<'w, 's, &'static mut PointLight>,
+}
+
+/// Открывает временный разлом по запросу волны: точка за спиной игрока, за краем экрана,
+/// внутри безопасной зоны и с проходом к игроку по flow field.
+/// Места нет — враги из бюджета уходят обратно в обычные порталы.
+pub fn open_flank_rift_system(
+    mut commands: Commands,
+    mut requests: MessageReader<FlankRiftRequest>,
+    mut wave: ResMut<WaveState>,
+    lookups: FlankLookups,
+    rift_assets: Res<FlankRiftAssets>,
+    mut feed: MessageWriter<KillFeedMessage>,
+) {
+    let FlankLookups { player, camera_query, flow, zone, arena } = lookups;
+    for request in requests.read() {
+        let point = match (player.single(), camera_query.single(), &arena) {
+            (Ok((player_tf, velocity)), Ok((camera, cam_tf)), Some(arena)) => {
+                // «За спиной» — против движения; стоит на месте — низ экрана (+Z, к камере)
+                let moving = Vec2::new(velocity.x, velocity.z);
+                let behind = if moving.length_squared() > 1.0 { -moving.normalize() } else { Vec2::Y };
+                pick_flank_point(player_tf.translation, behind, camera, cam_tf, &flow, &zone, arena)
+            }
+            _ => None,
+        };
+
+        let Some(point) = point else {
+            wave.rift_budget = wave.rift_budget.saturating_sub(request.budget);
+            wave.enemies_to_spawn += request.budget;
+            if wave.phase == WavePhase::Fighting {
+                wave.phase = WavePhase::Spawning;
+            }
+            debug!("🌀 No flank point found — {} enemies go back to portals", request.budget);
+            continue;
+        };
+
+        // Стиль — как у одного из разломов арены (биом Огня не открывает зелёных)
+        let style = arena.as_ref()
+            .and_then(|arena| {
+                let sites: Vec<PortalStyle> = arena.0.portal_sites().map(|site| site.style).collect();
+                let pick = (rand_01() * sites.len() as f32) as usize;
+                sites.get(pick.min(sites.len().saturating_sub(1))).copied()
+            })
+            .unwrap_or(PortalStyle::Dark);
+        let index: u8 = match style {
+            PortalStyle::Fire => 0,
+            PortalStyle::Dark => 1,
+        };
+
+        let player_pos = player.single().map_or(Vec3::ZERO, |(tf, _)| tf.translation);
+        let to_player = Vec3::new(player_pos.x - point.x, 0.0, player_pos.z - point.z);
+        let facing = Quat::from_rotation_y(to_player.x.atan2(to_player.z));
+
+        let fill = commands.spawn((
+            Mesh3d(rift_assets.disc_mesh.clone()),
+            MeshMaterial3d(rift_assets.vortex_materials[index as usize].clone()),
+            Transform::from_scale(Vec3::splat(0.01)),
+        )).id();
+        let light = commands.spawn((
+            PointLight {
+                color: portal_light_color(style),
+                intensity: 0.0,
+                range: 12.0,
+                shadows_enabled: false,
+                ..default()
+            },
+            Transform::from_xyz(0.0, 0.5, 1.5),
+        )).id();
+        commands.spawn((
+            TemporaryRift {
+                phase: RiftPhase::Opening,
+                timer: Timer::from_seconds(OPENING_TIME, TimerMode::Once),
+                spawn_timer: Timer::from_seconds(RIFT_SPAWN_INTERVAL, TimerMode::Repeating),
+                budget: request.budget,
+                index,
+                fill,
+                light,
+            },
+            Transform::from_translation(Vec3::new(point.x, RIFT_HEIGHT, point.z)).with_rotation(facing),
+            Visibility::default(),
+        )).add_children(&[fill, light]);
+
+        feed.write(KillFeedMessage {
+            text: "Разлом открывается за спиной!".to_string(),
+            color: Color::srgb(0.75, 0.5, 1.0),
+            group_key: None,
+        });
+        info!("🌀 Flank rift opening at ({:.1}, {:.1}) with {} enemies", point.x, point.z, request.budget);
+    }
+}
+
+/// Точка для разлома или None, если за отведённые попытки подходящей нет
+fn pick_flank_point(
+    player_pos: Vec3,
+    behind: Vec2,
+    camera: &Camera,
+    cam_transform: &GlobalTransform,
+    flow: &FlowField,
+    zone: &SafeZone,
+    arena: &CurrentArena,
+) -> Option<Vec3> {
+    let viewport = camera.logical_viewport_size()?;
+    let base_angle = behind.y.atan2(behind.x);
+    let bounds = &arena.0.bounds;
+
+    for attempt in 0..FLANK_ATTEMPTS {
+        let spread = if attempt < FLANK_ATTEMPTS / 2 { FLANK_SPREAD } else { std::f32::consts::PI };
+        let angle = base_angle + (rand_01() * 2.0 - 1.0) * spread;
+        let dist = FLANK_MIN_DIST + rand_01() * (FLANK_MAX_DIST - FLANK_MIN_DIST);
+        let point = Vec3::new(
+            player_pos.x + angle.cos() * dist,
+            0.0,
+            player_pos.z + angle.sin() * dist,
+        );
+
+        let inside = point.x >= bounds.min.x + WALL_MARGIN && point.x <= bounds.max.x - WALL_MARGIN
+            && point.z >= bounds.min.y + WALL_MARGIN && point.z <= bounds.max.y - WALL_MARGIN;
+        // Проход к игроку: ячейка свободна и связана с ним flow field
+        if !inside || !zone.contains(point) || flow.is_blocked(point) || flow.sample(point).is_none() {
+            continue;
+        }
+
+        // За краем экрана целиком — и пол, и верх вихря
+        let visible = [point, point + Vec3::Y * (RIFT_HEIGHT + RIFT_RADIUS)].iter().any(|&p| {
+            camera.world_to_viewport(cam_transform, p).is_ok_and(|screen| {
+                screen.x > -OFFSCREEN_MARGIN && screen.x < viewport.x + OFFSCREEN_MARGIN
+                    && screen.y > -OFFSCREEN_MARGIN && screen.y < viewport.y + OFFSCREEN_MARGIN
+            })
+        });
+        if !visible {
+            return Some(point);
+        }
+    }
+    None
+}
+
+/// Жизнь временного разлома: телеграф → выпуск врагов из бюджета → схлопывание.
+/// Непотраченный бюджет списывается с волны, чтобы она не ждала несуществующих врагов.
+pub fn flank_rift_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut wave: ResMut<WaveState>,
+    spawn: RiftSpawnContext,
+    vfx_assets: Option<Res<PortalVfxAssets>>,
+    mut rifts: Query<(Entity, &mut TemporaryRift, &Transform)>,
+    rift_parts: RiftParts,
+) {
+    let RiftSpawnContext { enemy_assets, arena, mut materials } = spawn;
+    let RiftParts { transforms: mut parts, mut lights } = rift_parts;
+    let dt = time.delta_secs();
+    for (entity, mut rift, transform) in &mut rifts {
+        rift.timer.tick(time.delta());
+        let progress = rift.timer.fraction();
+        let facing = transform.rotation;
+
+        let (scale, glow) = match rift.phase {
+            RiftPhase::Opening => {
+                // Рваное мерцание, к концу телеграфа — всё ярче
+                let flicker = if rand_01() < 0.35 { 0.15 } else { 0.6 + rand_01() * 0.4 };
+                if let Some(assets) = &vfx_assets {
+                    if rand_01() < dt * 14.0 {
+                        spawn_rift_spark(&mut commands, assets, rift.index, transform.translation, facing);
+                    }
+                }
+                (progress * progress, progress * flicker)
+            }
+            RiftPhase::Open => {
+                rift.spawn_timer.tick(time.delta());
+                if rift.spawn_timer.just_finished() && rift.budget > 0 {
+                    if let (Some(assets), Some(arena)) = (&enemy_assets, &arena) {
+                        // Выход — перед вихрем, на полу
+                        let offset = facing * Vec3::new((rand_01() - 0.5) * 2.0, 0.0, 1.0 + rand_01() * 1.5);
+                        let pos = Vec3::new(transform.translation.x, 0.9, transform.translation.z) + offset;
+                        let spawned = spawn_wave_enemy(
+                            &mut commands, assets, &mut materials,
+                            wave.current_wave, rift.budget, &arena.enemy_weights, pos,
+                        );
+                        rift.budget -= spawned;
+                        wave.rift_budget = wave.rift_budget.saturating_sub(spawned);
+                    }
+                }
+                (1.0, 0.85 + (time.elapsed_secs() * 9.0).sin() * 0.15)
+            }
+            RiftPhase::Closing => {
+                let left = 1.0 - progress;
+                (left, left)
+            }
+        };
+
+        if let Ok(mut fill) = parts.get_mut(rift.fill) {
+            fill.scale = Vec3::splat(scale.max(0.01));
+        }
+        if let Ok(mut light) = lights.get_mut(rift.light) {
+            light.intensity = PORTAL_LIGHT_INTENSITY * 0.6 * glow;
+        }
+
+        match rift.phase {
+            RiftPhase::Opening if rift.timer.is_finished() => {
+                rift.phase = RiftPhase::Open;
+                rift.timer = Timer::from_seconds(RIFT_LIFETIME, TimerMode::Once);
+                rift.spawn_timer.reset();
+            }
+            RiftPhase::Open if rift.budget == 0 || rift.timer.is_finished() => {
+                if rift.budget > 0 {
+                    wave.rift_budget = wave.rift_budget.saturating_sub(rift.budget);
+                    debug!("🌀 Flank rift expired with {} enemies unspent", rift.budget);
+                    rift.budget = 0;
+                }
+                rift.phase = RiftPhase::Closing;
+                rift.timer = Timer::from_seconds(CLOSING_TIME, TimerMode::Once);
+            }
+            RiftPhase::Closing if rift.timer.is_finished() => {
+                commands.entity(entity).despawn();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Искра телеграфа — вылетает из вихря к арене
+fn spawn_rift_spark(commands: &mut Commands, assets: &PortalVfxAssets, index: u8, pos: Vec3, facing: Quat) {
+    let material = if index == 0 { &assets.spark_material_0 } else { &assets.spark_material_1 };
+    let dir = facing * Vec3::new(
+        (rand_01() - 0.5) * 3.0,
+        rand_01() * 2.0,
+        1.0 + rand_01() * 2.0,
+    );
+    commands.spawn((
+        Mesh3d(assets.spark_mesh.clone()),
+        MeshMaterial3d(material.clone()),
+        Transform::from_translation(pos + facing * Vec3::new(0.0, 0.0, 0.3)),
+        PortalSparkParticle {
+            velocity: dir.normalize() * (3.0 + rand_01() * 2.0),
+            timer: Timer::from_seconds(0.3 + rand_01() * 0.2, TimerMode::Once),
+        },
+    ));
+}
+
+/// Новый забег — временные разломы прошлого закрываются
+pub fn despawn_flank_rifts(mut commands: Commands, rifts: Query<Entity, With<TemporaryRift>>) {
+    for entity in &rifts {
+        commands.entity(entity).despawn();
+    }
+}
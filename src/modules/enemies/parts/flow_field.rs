@@ -0,0 +1,294 @@
+use bevy::prelude::*;
+use avian3d::prelude::*;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use crate::modules::player::components::Player;
+use crate::modules::world::CurrentArena;
+use crate::modules::hazards::HazardZone;
+
+/// Размер ячейки навигационной сетки (м)
+const NAV_CELL_SIZE: f32 = 1.0;
+/// Половина стороны сетки без раскладки арены: 50×50м + скалы по периметру
+const NAV_HALF_EXTENT: f32 = 27.0;
+/// Запас сетки за границей арены — стены по периметру тоже попадают в сетку
+const NAV_BOUNDS_MARGIN: f32 = 2.0;
+/// Радиус агента — препятствия "раздуваются" на него (враг не цепляет углы)
+const AGENT_RADIUS: f32 = 0.5;
+/// Коллайдеры ниже этой высоты — пол/плоский декор, не препятствие
+const MIN_OBSTACLE_HEIGHT: f32 = 0.3;
+/// Бюджет раскрытия ячеек Дейкстры за кадр (wasm: поле досчитывается за 1-2 кадра)
+const EXPANSIONS_PER_FRAME: usize = 1500;
+
+/// Надбавка за вход в ячейку опасной зоны: обходят, если обход не длиннее ~4м,
+/// внутрь попадают отброшенными или когда игрок стоит в зоне
+const HAZARD_PENALTY: u32 = 40;
+
+const COST_STRAIGHT: u32 = 10;
+const COST_DIAGONAL: u32 = 14;
+const UNREACHABLE: u32 = u32::MAX;
+
+/// 8 соседей: (dx, dz, стоимость)
+const NEIGHBORS: [(i32, i32, u32); 8] = [
+    (1, 0, COST_STRAIGHT), (-1, 0, COST_STRAIGHT), (0, 1, COST_STRAIGHT), (0, -1, COST_STRAIGHT),
+    (1, 1, COST_DIAGONAL), (1, -1, COST_DIAGONAL), (-1, 1, COST_DIAGONAL), (-1, -1, COST_DIAGONAL),
+];
+
+/// Flow field к игроку поверх навигационной сетки из статичных коллайдеров.
+/// Сетка строится один раз при входе в Playing, поле пересчитывается
+/// инкрементально (бюджет за кадр) только когда игрок сменил ячейку.
+/// Пока новое поле считается, агенты читают предыдущее (double buffer).
+#[derive(Resource)]
+pub struct FlowField {
+    /// Половина стороны сетки (м), центр — начало координат
+    half_extent: f32,
+    width: usize,
+    blocked: Vec<bool>,
+    /// Надбавка к стоимости входа в ячейку (опасные зоны)
+    penalty: Vec<u32>,
+    /// Готовое поле: направление к игроку для каждой ячейки (ZERO = нет пути)
+    directions: Vec<Vec2>,
+    /// Готовое поле: стоимость пути до игрока (UNREACHABLE = нет пути)
+    costs: Vec<u32>,
+    /// Рабочий буфер текущего пересчёта
+    pending_costs: Vec<u32>,
+    frontier: BinaryHeap<Reverse<(u32, usize)>>,
+    /// Ячейка игрока, для которой считается/посчитано поле
+    target_cell: Option<usize>,
+    building: bool,
+    ready: bool,
+}
+
+impl Default for FlowField {
+    fn default() -> Self {
+        Self::new(NAV_HALF_EXTENT)
+    }
+}
+
+impl FlowField {
+    fn new(half_extent: f32) -> Self {
+        let width = (half_extent * 2.0 / NAV_CELL_SIZE).ceil() as usize;
+        let len = width * width;
+        Self {
+            half_extent,
+            width,
+            blocked: vec![false; len],
+            penalty: vec![0; len],
+            directions: vec![Vec2::ZERO; len],
+            costs: vec![UNREACHABLE; len],
+            pending_costs: vec![UNREACHABLE; len],
+            frontier: BinaryHeap::new(),
+            target_cell: None,
+            building: false,
+            ready: false,
+        }
+    }
+
+    fn cell_index(&self, pos: Vec3) -> Option<usize> {
+        let x = ((pos.x + self.half_extent) / NAV_CELL_SIZE).floor();
+        let z = ((pos.z + self.half_extent) / NAV_CELL_SIZE).floor();
+        if x < 0.0 || z < 0.0 || x >= self.width as f32 || z >= self.width as f32 {
+            return None;
+        }
+        Some(z as usize * self.width + x as usize)
+    }
+
+    fn cell_center(&self, index: usize) -> Vec2 {
+        let x = (index % self.width) as f32;
+        let z = (index / self.width) as f32;
+        Vec2::new(
+            (x + 0.5) * NAV_CELL_SIZE - self.half_extent,
+            (z + 0.5) * NAV_CELL_SIZE - self.half_extent,
+        )
+    }
+
+    fn neighbor(&self, index: usize, dx: i32, dz: i32) -> Option<usize> {
+        let x = (index % self.width) as i32 + dx;
+        let z = (index / self.width) as i32 + dz;
+        if x < 0 || z < 0 || x >= self.width as i32 || z >= self.width as i32 {
+            return None;
+        }
+        Some(z as usize * self.width + x as usize)
+    }
+
+    /// Диагональ запрещена, если срезает угол препятствия
+    fn diagonal_clear(&self, index: usize, dx: i32, dz: i32) -> bool {
+        if dx == 0 || dz == 0 {
+            return true;
+        }
+        let a = self.neighbor(index, dx, 0);
+        let b = self.neighbor(index, 0, dz);
+        matches!((a, b), (Some(a), Some(b)) if !self.blocked[a] && !self.blocked[b])
+    }
+
+    /// Ячейка занята статичным препятствием
+    pub fn is_blocked(&self, pos: Vec3) -> bool {
+        self.cell_index(pos).is_some_and(|i| self.blocked[i])
+    }
+
+    /// Поле посчитано хотя бы раз (до этого sample всегда None)
+    pub fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    /// Направление потока в точке (XZ, нормализовано). None — поле не готово,
+    /// точка вне сетки или внутри препятствия (агент рулит напрямую).
+    pub fn sample(&self, pos: Vec3) -> Option<Vec3> {
+        if !self.ready {
+            return None;
+        }
+        let index = self.cell_index(pos)?;
+        let dir = self.directions[index];
+        if dir == Vec2::ZERO {
+            return None;
+        }
+        Some(Vec3::new(dir.x, 0.0, dir.y))
+    }
+
+    /// Длина пути до игрока по сетке (м). None — поле не готово, точка вне сетки или пути нет.
+    pub fn path_distance(&self, pos: Vec3) -> Option<f32> {
+        if !self.ready {
+            return None;
+        }
+        let cost = self.costs[self.cell_index(pos)?];
+        (cost != UNREACHABLE).then(|| cost as f32 / COST_STRAIGHT as f32 * NAV_CELL_SIZE)
+    }
+
+    /// Запускает пересчёт поля к новой ячейке цели
+    fn begin(&mut self, target: usize) {
+        self.pending_costs.fill(UNREACHABLE);
+        self.frontier.clear();
+        self.pending_costs[target] = 0;
+        self.frontier.push(Reverse((0, target)));
+        self.target_cell = Some(target);
+        self.building = true;
+    }
+
+    /// Дейкстра с бюджетом раскрытий. true — поле досчитано.
+    fn step(&mut self, budget: usize) -> bool {
+        for _ in 0..budget {
+            let Some(Reverse((cost, index))) = self.frontier.pop() else {
+                return true;
+            };
+            if cost > self.pending_costs[index] {
+                continue; // устаревшая запись в куче
+            }
+            for &(dx, dz, step_cost) in &NEIGHBORS {
+                let Some(n) = self.neighbor(index, dx, dz) else { continue };
+                if self.blocked[n] || !self.diagonal_clear(index, dx, dz) {
+                    continue;
+                }
+                let next = cost + step_cost + self.penalty[n];
+                if next < self.pending_costs[n] {
+                    self.pending_costs[n] = next;
+                    self.frontier.push(Reverse((next, n)));
+                }
+            }
+        }
+        self.frontier.is_empty()
+    }
+
+    /// Переводит стоимости в направления (к соседу с минимальной стоимостью)
+    fn finish(&mut self) {
+        for index in 0..self.directions.len() {
+            let own = self.pending_costs[index];
+            if own == UNREACHABLE || own == 0 || self.blocked[index] {
+                self.directions[index] = Vec2::ZERO;
+                continue;
+            }
+            let mut best = own;
+            let mut best_cell = None;
+            for &(dx, dz, _) in &NEIGHBORS {
+                let Some(n) = self.neighbor(index, dx, dz) else { continue };
+                if self.pending_costs[n] < best && self.diagonal_clear(index, dx, dz) {
+                    best = self.pending_costs[n];
+                    best_cell = Some(n);
+                }
+            }
+            self.directions[index] = best_cell
+                .map(|n| (self.cell_center(n) - self.cell_center(index)).normalize_or_zero())
+                .unwrap_or(Vec2::ZERO);
+        }
+        self.costs.copy_from_slice(&self.pending_costs);
+        self.building = false;
+        self.ready = true;
+    }
+}
+
+/// Строит навигационную сетку из статичных коллайдеров (скалы, рунные камни, валуны, деревья)
+/// и надбавки за опасные зоны.
+/// Вызывается при входе в Playing — к этому моменту ColliderAabb уже посчитаны физикой.
+pub fn build_nav_grid(
+    mut flow_field: ResMut<FlowField>,
+    colliders: Query<(&RigidBody, &ColliderAabb)>,
+    hazards: Query<(&Transform, &HazardZone)>,
+    arena: Option<Res<CurrentArena>>,
+) {
+    // Сетка накрывает арену из раскладки (+ стены по периметру)
+    *flow_field = arena.map_or_else(FlowField::default, |arena| {
+        FlowField::new(arena.bounds.half_extent() + NAV_BOUNDS_MARGIN)
+    });
+
+    let mut blocked_count = 0;
+    for (body, aabb) in &colliders {
+        if *body != RigidBody::Static || aabb.max.y - aabb.min.y < MIN_OBSTACLE_HEIGHT {
+            continue;
+        }
+        let min = Vec2::new(aabb.min.x, aabb.min.z) - Vec2::splat(AGENT_RADIUS);
+        let max = Vec2::new(aabb.max.x, aabb.max.z) + Vec2::splat(AGENT_RADIUS);
+
+        for index in 0..flow_field.blocked.len() {
+            let c = flow_field.cell_center(index);
+            if c.x >= min.x && c.x <= max.x && c.y >= min.y && c.y <= max.y && !flow_field.blocked[index] {
+                flow_field.blocked[index] = true;
+                blocked_count += 1;
+            }
+        }
+    }
+
+    // Опасные зоны — проходимы, но дороги: враги сами в огонь не лезут
+    let mut hazard_count = 0;
+    for (transform, zone) in &hazards {
+        let center = transform.translation.xz();
+        for index in 0..flow_field.penalty.len() {
+            if flow_field.cell_center(index).distance(center) <= zone.radius + AGENT_RADIUS {
+                flow_field.penalty[index] = HAZARD_PENALTY;
+                hazard_count += 1;
+            }
+        }
+    }
+
+    info!(
+        "🧭 Nav grid built: {}x{} cells, {} blocked, {} hazard",
+        flow_field.width, flow_field.width, blocked_count, hazard_count,
+    );
+}
+
+/// Инкрементальный пересчёт flow field: старт при смене ячейки игрока,
+/// затем не больше EXPANSIONS_PER_FRAME раскрытий за кадр
+pub fn update_flow_field_system(
+    mut flow_field: ResMut<FlowField>,
+    player: Query<&Transform, With<Player>>,
+) {
+    let Ok(player_transform) = player.single() else { return };
+
+    if let Some(cell) = flow_field.cell_index(player_transform.translation) {
+        if flow_field.target_cell != Some(cell) && !flow_field.blocked[cell] {
+            flow_field.begin(cell);
+        }
+    }
+
+    if flow_field.building && flow_field.step(EXPANSIONS_PER_FRAME) {
+        flow_field.finish();
+    }
+}
+
+/// Смешивает скорость агента с потоком: радиальная (к игроку) часть скорости
+/// поворачивается вдоль потока, касательная (орбита/фланг) сохраняется.
+/// На открытой местности поток ≈ прямое направление — оставляем как есть.
+pub fn blend_with_flow(velocity: Vec3, to_player: Vec3, flow: Vec3) -> Vec3 {
+    let toward = velocity.dot(to_player);
+    if toward <= 0.0 || flow.dot(to_player) > 0.95 {
+        return velocity;
+    }
+    velocity - to_player * toward + flow * toward
+}
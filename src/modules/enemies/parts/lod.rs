@@ -2,8 +2,9 @@ use bevy::prelude::*;
 use crate::modules::enemies::components::*;
 use crate::modules::player::components::Player;
 use crate::modules::world::GroundCircle;
+use super::vat_impostor::VatLibrary;
 
-/// Обновляет LOD уровень по дистанции до игрока (O(n), дешёвый)
+/// Обновляет LOD уровень по дистанции до игрока (O(n), дешёвый, параллельный)
 pub fn update_enemy_lod_system(
     player: Query<&Transform, With<Player>>,
     mut enemies: Query<(&Transform, &mut EnemyLod), (With<Enemy>, Without<EnemyDying>, Without<Player>)>,
@@ -11,7 +12,7 @@ pub fn update_enemy_lod_system(
     let Ok(player_tf) = player.single() else { return };
     let player_pos = player_tf.translation;
 
-    for (tf, mut lod) in &mut enemies {
+    enemies.par_iter_mut().for_each(|(tf, mut lod)| {
         let dist_sq = (player_pos - tf.translation).length_squared();
         let new_lod = if dist_sq < 225.0 {   // 15²
             EnemyLod::Full
@@ -24,7 +25,7 @@ pub fn update_enemy_lod_system(
         if *lod != new_lod {
             *lod = new_lod;
         }
-    }
+    });
 }
 
 /// Скрывает/показывает ground circle по LOD (только при смене LOD уровня)
@@ -42,15 +43,24 @@ pub fn lod_ground_circle_system(
     }
 }
 
-/// Замораживает/размораживает анимации по LOD (только при смене LOD уровня)
+/// Замораживает/размораживает анимации по LOD (только при смене LOD уровня).
+/// Модели с запечённым VAT не замораживаются — их подменяет vat_impostor_swap_system.
 pub fn lod_animation_freeze_system(
-    enemies: Query<(&EnemyLod, &CachedAnimPlayer), Changed<EnemyLod>>,
+    library: Res<VatLibrary>,
+    enemies: Query<(&EnemyLod, &CachedAnimPlayer, &Children), Changed<EnemyLod>>,
+    models: Query<&SceneRoot, With<EnemyModel>>,
     mut animation_query: Query<&mut AnimationPlayer>,
 ) {
-    for (lod, cached) in &enemies {
+    for (lod, cached, children) in &enemies {
         let Ok(mut player) = animation_query.get_mut(cached.entity) else { continue };
         match lod {
-            EnemyLod::Minimal => { player.pause_all(); },
+            EnemyLod::Minimal => {
+                let baked = children.iter()
+                    .any(|child| models.get(child).is_ok_and(|root| library.get(&root.0).is_some()));
+                if !baked {
+                    player.pause_all();
+                }
+            },
             _ => { player.resume_all(); },
         }
     }
@@ -1,18 +1,38 @@
 //! Реализация врагов:
 //! - spawner: волновой спавнер (Cooldown → Spawning → Fighting), масштабирование по волнам
-//! - ai: дистанционный AI (Idle/Chase/Attack), запуск смерти, конвертация в труп
+//! - ai: сбор контекста и применение узла поведения, запуск смерти, конвертация в труп
+//! - behavior: utility-выбор узла по профилю архетипа (chase/flank/charge/ambush/retreat...) + steering
+//! - behavior_debug: F5 overlay текущего узла над врагами
 //! - animation: привязка AnimationPlayer из GLB, переключение анимаций по состоянию
+//! - vat_impostor: запекание walk/run/idle в vertex animation texture, подмена Minimal LOD на инстансный импостер
 //! - cleanup: деспавн врагов и трупов, сброс волн и kill count
+//! - flow_field: навигационная сетка из статичных коллайдеров + инкрементальный flow field к игроку
+//! - support_aura: аура Колдуна (лечение + бафф соседей через SpatialIndex)
+//! - alert: тревога по орде (агро/попадание/крик → соседи через SpatialIndex), потеря игрока
+//! - debug_spawn: F1-F4 спавн/убийство, F6 стресс-сцена (1000 desktop / 300 wasm) с замером FPS
+//! - body_block: блокировка телами по режиму забега (враги окружают и зажимают игрока), F7 — отладка
+//! - portal_seal: прочность порталов, окно уязвимости после волны, печати, новые разломы, победа в Походе
+//! - flank_rift: временные разломы за спиной игрока по запросу фланговой волны (телеграф, срок, бюджет; вихрь — из кэша FlankRiftAssets)
+//! - pack: стаи волколаков (вожак, кружение, вой, согласованный бросок, разбегание)
 
 pub mod spawner;
 pub mod ai;
+pub mod behavior;
+pub mod behavior_debug;
 pub mod animation;
 pub mod cleanup;
 pub mod preload;
-pub mod spatial_grid;
+pub mod flow_field;
 pub mod separation;
 pub mod attack_slots;
 pub mod portal_spawn;
 pub mod portal_vfx;
+pub mod portal_seal;
+pub mod flank_rift;
 pub mod debug_spawn;
 pub mod lod;
+pub mod vat_impostor;
+pub mod support_aura;
+pub mod pack;
+pub mod alert;
+pub mod body_block;
@@ -0,0 +1,137 @@
+use bevy::prelude::*;
+use crate::modules::enemies::components::*;
+use crate::modules::player::components::Player;
+use super::attack_slots;
+
+/// Тайминги фаз охоты стаи
+const STALK_DURATION: f32 = 3.0;
+const STALK_RETRY_DURATION: f32 = 1.0;
+const HOWL_DURATION: f32 = 1.2;
+const LUNGE_DURATION: f32 = 2.5;
+const SCATTER_DURATION: f32 = 2.5;
+/// Скорость вращения кольца окружения (рад/с)
+const CIRCLE_SPEED: f32 = 0.5;
+/// Минимум одновременных атакующих для броска (иначе стая ждёт слоты)
+const MIN_LUNGE_SLOTS: u32 = 2;
+
+fn enter_phase(leader: &mut PackLeader, phase: PackPhase, duration: f32) {
+    leader.phase = phase;
+    leader.phase_timer = Timer::from_seconds(duration, TimerMode::Once);
+}
+
+/// Охота стаи: вожак ведёт фазы (подход → кружение → вой → бросок),
+/// члены получают приказы с углом на кольце. Слоты атаки резервируются
+/// на стаю целиком в момент броска и отдаются после него.
+/// Вожак погиб — стая разбегается (PackScatter) и дальше охотится поодиночке.
+pub fn pack_hunting_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    budget: Res<AttackTokenBudget>,
+    player: Query<&Transform, With<Player>>,
+    slotted: Query<Option<&AttackTokenCost>, (With<HasAttackSlot>, With<Enemy>, Without<EnemyDying>, Without<BypassAttackTokens>)>,
+    mut leaders: Query<(&Transform, &ChasePlayer, Option<&AttackTokenCost>, Has<Alerted>, &mut PackLeader), (With<Enemy>, Without<EnemyDying>)>,
+    mut members: Query<(Entity, &mut PackMember, Has<HasAttackSlot>), (With<Enemy>, Without<EnemyDying>)>,
+    mut scattering: Query<(Entity, &mut PackScatter)>,
+) {
+    let Ok(player_tf) = player.single() else { return };
+    let player_pos = player_tf.translation;
+    let dt = time.delta_secs();
+
+    // Свободные melee-токены делятся между стаями в порядке обхода
+    let (used_melee, _) = attack_slots::tokens_in_use(slotted.iter());
+    let mut free_tokens = budget.melee_tokens.saturating_sub(used_melee);
+
+    for (transform, chase, cost, alerted, mut leader) in &mut leaders {
+        let distance = (player_pos - transform.translation).length();
+        leader.circle_phase = (leader.circle_phase + CIRCLE_SPEED * dt) % std::f32::consts::TAU;
+        leader.phase_timer.tick(time.delta());
+        let finished = leader.phase_timer.is_finished();
+
+        match leader.phase {
+            PackPhase::Approach => {
+                if distance <= chase.aggro_range || alerted {
+                    enter_phase(&mut leader, PackPhase::Stalk, STALK_DURATION);
+                }
+            }
+            PackPhase::Stalk => {
+                if distance > chase.aggro_range * 1.2 && !alerted {
+                    enter_phase(&mut leader, PackPhase::Approach, 0.0);
+                } else if finished {
+                    enter_phase(&mut leader, PackPhase::Howl, HOWL_DURATION);
+                    debug!("🐺 Pack howl ({} wolves)", leader.member_count);
+                }
+            }
+            PackPhase::Howl => {
+                if finished {
+                    // Групповая резервация: бросок только если хватает токенов на несколько волков
+                    let wolf_cost = cost.copied().unwrap_or_default().cost.max(1);
+                    let slots = (free_tokens / wolf_cost).min(leader.member_count);
+                    if slots >= MIN_LUNGE_SLOTS.min(leader.member_count) && slots > 0 {
+                        free_tokens -= slots * wolf_cost;
+                        leader.lunge_slots = slots;
+                        enter_phase(&mut leader, PackPhase::Lunge, LUNGE_DURATION);
+                        debug!("🐺 Pack lunge: {} slots reserved", slots);
+                    } else {
+                        enter_phase(&mut leader, PackPhase::Stalk, STALK_RETRY_DURATION);
+                    }
+                }
+            }
+            PackPhase::Lunge => {
+                if finished {
+                    leader.lunge_slots = 0;
+                    enter_phase(&mut leader, PackPhase::Stalk, STALK_DURATION);
+                }
+            }
+        }
+
+        leader.member_count = 0;
+    }
+
+    for (entity, mut member, has_slot) in &mut members {
+        let Ok((_, _, _, _, mut leader)) = leaders.get_mut(member.leader) else {
+            // Вожак погиб — стая врассыпную
+            commands.entity(entity)
+                .remove::<PackMember>()
+                .remove::<HasAttackSlot>()
+                .insert(PackScatter {
+                    timer: Timer::from_seconds(SCATTER_DURATION, TimerMode::Once),
+                });
+            continue;
+        };
+        leader.member_count += 1;
+
+        let angle = leader.circle_phase
+            + member.index as f32 * std::f32::consts::TAU / member.pack_size.max(1) as f32;
+
+        member.order = match leader.phase {
+            PackPhase::Approach => PackOrder::Follow,
+            PackPhase::Stalk => PackOrder::Circle { angle },
+            PackPhase::Howl => PackOrder::Howl,
+            PackPhase::Lunge => {
+                // Уже в броске — держит слот; иначе берёт один из зарезервированных
+                let lunging = matches!(member.order, PackOrder::Lunge { .. });
+                if lunging || leader.lunge_slots > 0 {
+                    if !lunging {
+                        leader.lunge_slots -= 1;
+                        commands.entity(entity).insert(HasAttackSlot);
+                    }
+                    PackOrder::Lunge { angle }
+                } else {
+                    PackOrder::Circle { angle }
+                }
+            }
+        };
+
+        // Вне броска слоты стаи освобождаются
+        if has_slot && !matches!(member.order, PackOrder::Lunge { .. }) {
+            commands.entity(entity).remove::<HasAttackSlot>();
+        }
+    }
+
+    for (entity, mut scatter) in &mut scattering {
+        scatter.timer.tick(time.delta());
+        if scatter.timer.is_finished() {
+            commands.entity(entity).remove::<PackScatter>();
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use std::time::Duration;
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use crate::shared::GameState;
+use crate::modules::enemies::components::*;
+use crate::modules::combat::components::Destructible;
+use crate::modules::combat::parts::camera_shake::CameraShake;
+use crate::modules::combat::parts::game_over::RunOutcome;
+use crate::modules::combat::parts::impact_flash;
+use crate::modules::combat::parts::vfx_assets::HitVfxAssets;
+use crate::modules::menu::KillFeedMessage;
+use crate::modules::menu::parts::fade_transition::FadeState;
+use crate::modules::world::{
+    spawn_portal_site, CurrentArena, PortalFill, PortalVortexMaterial, PortalVortexSettings,
+    SelectedMode, GAME_MODES, PORTAL_LIGHT_INTENSITY,
+};
+use crate::shared::rand_01;
+
+/// Прочность портала — сохраняется между окнами, не добитый портал ждёт следующего
+const PORTAL_HP: f32 = 150.0;
+/// Радиус арки для удара (дистанция до края)
+const PORTAL_HIT_RADIUS: f32 = 2.5;
+/// Окно уязвимости — после каждой N-й пройденной волны
+const SEAL_WINDOW_EVERY: u32 = 3;
+/// Длина паузы с окном уязвимости (с) / обычная пауза между волнами
+const SEAL_WINDOW_SECS: f32 = 12.0;
+const WAVE_COOLDOWN_SECS: f32 = 3.0;
+/// Сколько оставить от окна, когда бить больше нечего (с)
+const WINDOW_LEFTOVER_SECS: f32 = 2.0;
+/// Новый разлом открывается через столько волн после печати
+const RIFT_OPEN_DELAY: u32 = 2;
+/// Запечатанный вихрь: доля яркости, скорости вращения и света
+const SEALED_GLOW: f32 = 0.15;
+const SEALED_SPIN: f32 = 0.2;
+const SEALED_LIGHT: f32 = 0.05;
+
+/// Вихри и свет порталов — притушить при печати, вернуть при открытии
+#[derive(SystemParam)]
+pub struct PortalVisuals<'w, 's> {
+    fills: Query<'w, 's, &'static PortalFill>,
+    vortex_materials: ResMut<'w, Assets<PortalVortexMaterial>>,
+    lights: Query<'w, 's, &'static mut PointLight, With<PortalLight>>,
+}
+
+/// Очередь разломов: фаза волн, печати, режим и свободные места раскладки
+#[derive(SystemParam)]
+pub struct RiftSchedule<'w, 's> {
+    wave: Res<'w, WaveState>,
+    rifts: ResMut<'w, RiftState>,
+    selected: Res<'w, SelectedMode>,
+    arena: Option<Res<'w, CurrentArena>>,
+    all_portals: Query<'w, 's, &'static SpawnPortal>,
+}
+
+/// Отклик печати: тряска, вспышка, строка в ленте и сообщение для музыки
+#[derive(SystemParam)]
+pub struct SealFeedback<'w> {
+    shake: ResMut<'w, CameraShake>,
+    vfx_assets: Option<Res<'w, HitVfxAssets>>,
+    sealed_writer: MessageWriter<'w, PortalSealed>,
+    feed: MessageWriter<'w, KillFeedMessage>,
+}
+
+/// Конец забега: исход, затемнение в GameOver и пауза мира
+#[derive(SystemParam)]
+pub struct RunEnd<'w> {
+    outcome: ResMut<'w, RunOutcome>,
+    fade: ResMut<'w, FadeState>,
+    time: ResMut<'w, Time<Virtual>>,
+}
+
+/// Даёт порталу прочность — и стартовым, и открывшимся по ходу забега
+pub fn init_portal_health_system(
+    mut commands: Commands,
+    new_portals: Query<Entity, Added<SpawnPortal>>,
+) {
+    for entity in &new_portals {
+        commands.entity(entity).insert(Destructible {
+            vulnerable: false,
+            ..Destructible::new(PORTAL_HP, PORTAL_HIT_RADIUS)
+        });
+    }
+}
+
+/// OnEnter(Playing): арена переживает забеги одного биома — закрываем открытые разломы,
+/// снимаем печати и возвращаем прочность стартовым порталам
+pub fn reset_portals(
+    mut commands: Commands,
+    mut rifts: ResMut<RiftState>,
+    mut portals: Query<(Entity, &Children, &mut Destructible, Has<OpenedRift>), With<SpawnPortal>>,
+    visuals: PortalVisuals,
+) {
+    let PortalVisuals { fills, mut vortex_materials, mut lights } = visuals;
+    *rifts = RiftState::default();
+    for (entity, children, mut health, opened) in &mut portals {
+        if opened {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        commands.entity(entity).remove::<SealedPortal>();
+        *health = Destructible { vulnerable: false, ..Destructible::new(PORTAL_HP, PORTAL_HIT_RADIUS) };
+        set_portal_glow(children, false, &fills, &mut vortex_materials, &mut lights);
+    }
+}
+
+/// Окно уязвимости: после каждой 3-й волны пауза растягивается, открытые порталы можно бить.
+/// Бить больше нечего — от окна остаётся пара секунд. С началом волны окно закрывается.
+pub fn seal_window_system(
+    mut wave: ResMut<WaveState>,
+    mut rifts: ResMut<RiftState>,
+    mut portals: Query<&mut Destructible, (With<SpawnPortal>, Without<SealedPortal>)>,
+    mut feed: MessageWriter<KillFeedMessage>,
+) {
+    if wave.phase != WavePhase::Cooldown {
+        if rifts.window_open {
+            rifts.window_open = false;
+            wave.wave_cooldown.set_duration(Duration::from_secs_f32(WAVE_COOLDOWN_SECS));
+            for mut health in &mut portals {
+                health.vulnerable = false;
+            }
+        }
+        return;
+    }
+
+    let cleared = wave.current_wave;
+    if cleared > 0 && cleared.is_multiple_of(SEAL_WINDOW_EVERY) && rifts.window_wave < cleared {
+        rifts.window_wave = cleared;
+        if portals.is_empty() {
+            return;
+        }
+        rifts.window_open = true;
+        wave.wave_cooldown.set_duration(Duration::from_secs_f32(SEAL_WINDOW_SECS));
+        for mut health in &mut portals {
+            health.vulnerable = true;
+        }
+        feed.write(KillFeedMessage {
+            text: "Разломы уязвимы — запечатай!".to_string(),
+            color: Color::srgb(0.75, 0.5, 1.0),
+            group_key: None,
+        });
+        info!("🌀 Seal window opened after wave {}", cleared);
+        return;
+    }
+
+    if rifts.window_open && !portals.iter().any(|health| health.vulnerable) {
+        let leftover = Duration::from_secs_f32(WINDOW_LEFTOVER_SECS);
+        if wave.wave_cooldown.remaining() > leftover {
+            let duration = wave.wave_cooldown.duration();
+            wave.wave_cooldown.set_elapsed(duration - leftover);
+        }
+    }
+}
+
+/// Добитый портал запечатывается: гаснет, перестаёт выпускать врагов, в очередь встаёт новый разлом.
+/// В Походе последняя печать на арене без свободных мест — победа.
+pub fn portal_seal_system(
+    mut commands: Commands,
+    mut portals: Query<(Entity, &SpawnPortal, &GlobalTransform, &Children, &mut Destructible), Without<SealedPortal>>,
+    schedule: RiftSchedule,
+    visuals: PortalVisuals,
+    feedback: SealFeedback,
+    run_end: RunEnd,
+) {
+    let RiftSchedule { wave, mut rifts, selected, arena, all_portals } = schedule;
+    let PortalVisuals { fills, mut vortex_materials, mut lights } = visuals;
+    let SealFeedback { mut shake, vfx_assets, mut sealed_writer, mut feed } = feedback;
+    let RunEnd { mut outcome, mut fade, mut time } = run_end;
+    let mut remaining = portals.iter().count();
+    let mut sealed_now = false;
+    for (entity, portal, transform, children, mut health) in &mut portals {
+        if !health.is_broken() {
+            continue;
+        }
+        health.vulnerable = false;
+        commands.entity(entity).insert(SealedPortal);
+        set_portal_glow(children, true, &fills, &mut vortex_materials, &mut lights);
+
+        let pos = transform.translation();
+        shake.trigger(0.5, 0.4, Vec3::ZERO);
+        if let Some(assets) = &vfx_assets {
+            impact_flash::spawn_impact_flash(&mut commands, assets, pos);
+        }
+
+        remaining -= 1;
+        sealed_now = true;
+        rifts.sealed += 1;
+        sealed_writer.write(PortalSealed { site: portal.site, remaining });
+        feed.write(KillFeedMessage {
+            text: "Разлом запечатан!".to_string(),
+            color: Color::srgb(0.95, 0.78, 0.3),
+            group_key: None,
+        });
+        info!("🔒 Portal at site {} sealed ({} still open)", portal.site, remaining);
+
+        // Взамен — новый разлом через пару волн (в Походе — пока есть свободные места)
+        let free_sites = arena.as_ref().map_or(0, |arena| {
+            arena.0.portal_sites().count().saturating_sub(all_portals.iter().count())
+        });
+        if !GAME_MODES[selected.0].seal_to_win || free_sites > rifts.pending_opens.len() {
+            rifts.pending_opens.push(wave.current_wave + RIFT_OPEN_DELAY);
+        }
+    }
+
+    if sealed_now && GAME_MODES[selected.0].seal_to_win && remaining == 0 && rifts.pending_opens.is_empty() && !fade.is_active() {
+        info!("🏆 All rifts sealed — run won on wave {}", wave.current_wave);
+        outcome.victory = true;
+        time.pause();
+        fade.start_fade(GameState::GameOver, false);
+    }
+}
+
+/// С началом волны открывает назначенные разломы; если открытых не осталось — один сразу.
+/// Свободного места нет — вне Похода вновь раскрывается случайный запечатанный.
+pub fn rift_opening_system(
+    mut commands: Commands,
+    schedule: RiftSchedule,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    visuals: PortalVisuals,
+    mut sealed: Query<(Entity, &Children, &mut Destructible), With<SealedPortal>>,
+    mut feed: MessageWriter<KillFeedMessage>,
+) {
+    let RiftSchedule { wave, mut rifts, selected, arena, all_portals } = schedule;
+    let PortalVisuals { fills, mut vortex_materials, mut lights } = visuals;
+    if wave.phase != WavePhase::Spawning || rifts.opened_for_wave >= wave.current_wave {
+        return;
+    }
+    rifts.opened_for_wave = wave.current_wave;
+    let Some(arena) = arena else { return };
+
+    let current = wave.current_wave;
+    let mut to_open = rifts.pending_opens.iter().filter(|&&at| at <= current).count();
+    rifts.pending_opens.retain(|&at| at > current);
+    let no_active = all_portals.iter().count() == sealed.iter().count();
+    if to_open == 0 && no_active {
+        // Порталов не осталось — волне нужен выход; ближайший из очереди открываем досрочно
+        to_open = 1;
+        if !rifts.pending_opens.is_empty() {
+            rifts.pending_opens.remove(0);
+        }
+    }
+    if to_open == 0 {
+        return;
+    }
+
+    let mut occupied: Vec<usize> = all_portals.iter().map(|portal| portal.site).collect();
+    let mut reopened: Vec<Entity> = Vec::new();
+    for _ in 0..to_open {
+        let free = arena.0.portal_sites().enumerate().find(|(site, _)| !occupied.contains(site));
+        if let Some((site, def)) = free {
+            let rift = spawn_portal_site(
+                &mut commands, &asset_server, &mut meshes, &mut vortex_materials, site, def,
+            );
+            commands.entity(rift).insert(OpenedRift);
+            occupied.push(site);
+            info!("🌀 New rift opened at site {} (wave {})", site, current);
+        } else if !GAME_MODES[selected.0].seal_to_win {
+            let candidates: Vec<Entity> = sealed.iter()
+                .map(|(entity, _, _)| entity)
+                .filter(|entity| !reopened.contains(entity))
+                .collect();
+            if candidates.is_empty() {
+                continue;
+            }
+            let pick = candidates[((rand_01() * candidates.len() as f32) as usize).min(candidates.len() - 1)];
+            let Ok((entity, children, mut health)) = sealed.get_mut(pick) else { continue };
+            commands.entity(entity).remove::<SealedPortal>();
+            *health = Destructible { vulnerable: false, ..Destructible::new(PORTAL_HP, PORTAL_HIT_RADIUS) };
+            set_portal_glow(children, false, &fills, &mut vortex_materials, &mut lights);
+            reopened.push(entity);
+            info!("🌀 Sealed rift torn open again (wave {})", current);
+        } else {
+            continue;
+        }
+        feed.write(KillFeedMessage {
+            text: "Открылся новый разлом!".to_string(),
+            color: Color::srgb(0.75, 0.5, 1.0),
+            group_key: None,
+        });
+    }
+}
+
+/// Притушивает вихрь и свет запечатанного портала или возвращает исходные
+fn set_portal_glow(
+    children: &Children,
+    sealed: bool,
+    fills: &Query<&PortalFill>,
+    vortex_materials: &mut Assets<PortalVortexMaterial>,
+    lights: &mut Query<&mut PointLight, With<PortalLight>>,
+) {
+    for child in children.iter() {
+        if let Ok(fill) = fills.get(child) {
+            if let Some(material) = vortex_materials.get_mut(&fill.material) {
+                material.settings = if sealed {
+                    let base = &fill.base;
+                    PortalVortexSettings {
+                        color: LinearRgba::new(
+                            base.color.red * SEALED_GLOW,
+                            base.color.green * SEALED_GLOW,
+                            base.color.blue * SEALED_GLOW,
+                            base.color.alpha,
+                        ),
+                        speed: base.speed * SEALED_SPIN,
+                        ..base.clone()
+                    }
+                } else {
+                    fill.base.clone()
+                };
+            }
+        }
+        if let Ok(mut light) = lights.get_mut(child) {
+            light.intensity = if sealed { PORTAL_LIGHT_INTENSITY * SEALED_LIGHT } else { PORTAL_LIGHT_INTENSITY };
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use crate::modules::enemies::components::{SpawnPortal, WavePhase, WaveState};
+use crate::modules::enemies::components::{SpawnPortal, SealedPortal, WavePhase, WaveState};
 use crate::shared::rand_01;
 
 /// Кэшированные ассеты для частиц порталов
@@ -98,12 +98,12 @@ impl Default for PortalEmitTimer {
     }
 }
 
-/// Спавнит частицы дыма и искр из порталов
+/// Спавнит частицы дыма и искр из открытых порталов (запечатанные молчат)
 pub fn portal_particle_emitter_system(
     time: Res<Time>,
     wave: Res<WaveState>,
     mut emit_timer: ResMut<PortalEmitTimer>,
-    portals: Query<(&SpawnPortal, &Transform)>,
+    portals: Query<(&SpawnPortal, &Transform), Without<SealedPortal>>,
     vfx_assets: Option<Res<PortalVfxAssets>>,
     mut commands: Commands,
 ) {
@@ -123,6 +123,8 @@ pub fn portal_particle_emitter_system(
     for (portal, transform) in &portals {
         let pos = transform.translation;
         let idx = portal.index;
+        // Направления в локальных осях портала: +Z смотрит вглубь арены
+        let facing = transform.rotation;
 
         // Выбираем материалы по индексу портала
         let (smoke_mat, spark_mat) = if idx == 0 {
@@ -133,7 +135,7 @@ pub fn portal_particle_emitter_system(
 
         // 2 частицы дыма
         for _ in 0..2 {
-            let dir = Vec3::new(
+            let dir = facing * Vec3::new(
                 (rand_01() - 0.5) * 2.0,           // Разброс по X
                 (rand_01() - 0.3) * 1.5,
                 1.0 + rand_01() * 2.0,              // Вглубь арены (+Z)
@@ -141,7 +143,7 @@ pub fn portal_particle_emitter_system(
             commands.spawn((
                 Mesh3d(assets.smoke_mesh.clone()),
                 MeshMaterial3d(smoke_mat.clone()),
-                Transform::from_translation(pos + Vec3::new(0.0, 0.0, 0.5)),
+                Transform::from_translation(pos + facing * Vec3::new(0.0, 0.0, 0.5)),
                 PortalSmokeParticle {
                     velocity: dir.normalize() * (1.0 + rand_01()),
                     timer: Timer::from_seconds(0.8 + rand_01() * 0.4, TimerMode::Once),
@@ -150,7 +152,7 @@ pub fn portal_particle_emitter_system(
         }
 
         // 1 искра
-        let spark_dir = Vec3::new(
+        let spark_dir = facing * Vec3::new(
             (rand_01() - 0.5) * 3.0,
             rand_01() * 2.0,
             1.5 + rand_01() * 3.0,              // Вглубь арены (+Z)
@@ -158,7 +160,7 @@ pub fn portal_particle_emitter_system(
         commands.spawn((
             Mesh3d(assets.spark_mesh.clone()),
             MeshMaterial3d(spark_mat.clone()),
-            Transform::from_translation(pos + Vec3::new(0.0, 0.0, 0.3)),
+            Transform::from_translation(pos + facing * Vec3::new(0.0, 0.0, 0.3)),
             PortalSparkParticle {
                 velocity: spark_dir.normalize() * (3.0 + rand_01() * 2.0),
                 timer: Timer::from_seconds(0.3 + rand_01() * 0.2, TimerMode::Once),
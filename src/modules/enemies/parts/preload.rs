@@ -1,9 +1,12 @@
 use bevy::prelude::*;
+use bevy::gltf::Gltf;
 use crate::toolkit::asset_paths;
+use crate::modules::anim_clips::{AnimClipLibrary, AnimSlot, ClipGraph, UPYR_CLIPS, LESHIY_CLIPS, VOLKOLAK_CLIPS};
 use super::spawner::EnemyAnimationIndices;
 
 /// Кэшированные Handle ассетов врагов — загружаются при старте раунда.
-/// AnimationGraph создаётся 1 раз на тип врага и шарится между всеми entity.
+/// AnimationGraph создаётся 1 раз на тип врага и шарится между всеми entity;
+/// клипы в нём — по имени из таблиц anim_clips.
 #[derive(Resource)]
 pub struct EnemyAssets {
     // Модели
@@ -15,6 +18,10 @@ pub struct EnemyAssets {
     pub upyr_ring_mesh: Handle<Mesh>,
     pub leshiy_ring_mesh: Handle<Mesh>,
     pub volkolak_ring_mesh: Handle<Mesh>,
+    pub koldun_ring_mesh: Handle<Mesh>,
+
+    // Кольцо импульса ауры Колдуна (радиус 1м, масштабируется в aura_pulse_vfx_system)
+    pub aura_pulse_mesh: Handle<Mesh>,
 
     // Shared AnimationGraph + индексы (1 граф на тип, handle клонируется между entity)
     pub upyr_graph: Handle<AnimationGraph>,
@@ -31,44 +38,47 @@ pub fn preload_enemy_assets(
     asset_server: Res<AssetServer>,
     mut graphs: ResMut<Assets<AnimationGraph>>,
     mut meshes: ResMut<Assets<Mesh>>,
+    mut library: ResMut<AnimClipLibrary>,
+    gltfs: Res<Assets<Gltf>>,
 ) {
     // --- Упырь ---
-    let mut upyr_graph = AnimationGraph::new();
-    let upyr_idle = upyr_graph.add_clip(asset_server.load(asset_paths::UPYR_ANIM_IDLE), 1.0, upyr_graph.root);
-    let upyr_walk = upyr_graph.add_clip(asset_server.load(asset_paths::UPYR_ANIM_WALK), 1.0, upyr_graph.root);
-    let upyr_run = upyr_graph.add_clip(asset_server.load(asset_paths::UPYR_ANIM_RUN), 1.0, upyr_graph.root);
-    let upyr_attack = upyr_graph.add_clip(asset_server.load(asset_paths::UPYR_ANIM_ATTACK), 1.0, upyr_graph.root);
-    let upyr_death = upyr_graph.add_clip(asset_server.load(asset_paths::UPYR_ANIM_DEATH), 1.0, upyr_graph.root);
-    let upyr_hit = upyr_graph.add_clip(asset_server.load(asset_paths::UPYR_ANIM_HIT), 1.0, upyr_graph.root);
-    let upyr_scream = upyr_graph.add_clip(asset_server.load(asset_paths::UPYR_ANIM_SCREAM), 1.0, upyr_graph.root);
-    let upyr_graph_handle = graphs.add(upyr_graph);
+    let mut upyr_graph = ClipGraph::new(&UPYR_CLIPS);
+    let upyr_idle = upyr_graph.add(AnimSlot::Idle, 1.0);
+    let upyr_walk = upyr_graph.add(AnimSlot::Walk, 1.0);
+    let upyr_run = upyr_graph.add(AnimSlot::Run, 1.0);
+    let upyr_attack = upyr_graph.add(AnimSlot::Attack, 1.0);
+    let upyr_death = upyr_graph.add(AnimSlot::Death, 1.0);
+    let upyr_hit = upyr_graph.add(AnimSlot::Hit, 1.0);
+    let upyr_scream = upyr_graph.add(AnimSlot::Scream, 1.0);
+    let upyr_graph_handle = library.build(upyr_graph, &asset_server, &gltfs, &mut graphs);
 
     // --- Леший ---
-    let mut leshiy_graph = AnimationGraph::new();
-    let leshiy_idle = leshiy_graph.add_clip(asset_server.load(asset_paths::LESHIY_ANIM_IDLE), 1.0, leshiy_graph.root);
-    let leshiy_walk = leshiy_graph.add_clip(asset_server.load(asset_paths::LESHIY_ANIM_WALK), 1.0, leshiy_graph.root);
-    let leshiy_run = leshiy_graph.add_clip(asset_server.load(asset_paths::LESHIY_ANIM_RUN), 1.0, leshiy_graph.root);
-    let leshiy_attack = leshiy_graph.add_clip(asset_server.load(asset_paths::LESHIY_ANIM_ATTACK), 1.0, leshiy_graph.root);
-    let leshiy_death = leshiy_graph.add_clip(asset_server.load(asset_paths::LESHIY_ANIM_DEATH), 1.0, leshiy_graph.root);
-    let leshiy_hit = leshiy_graph.add_clip(asset_server.load(asset_paths::LESHIY_ANIM_HIT), 1.0, leshiy_graph.root);
-    let leshiy_graph_handle = graphs.add(leshiy_graph);
+    let mut leshiy_graph = ClipGraph::new(&LESHIY_CLIPS);
+    let leshiy_idle = leshiy_graph.add(AnimSlot::Idle, 1.0);
+    let leshiy_walk = leshiy_graph.add(AnimSlot::Walk, 1.0);
+    let leshiy_run = leshiy_graph.add(AnimSlot::Run, 1.0);
+    let leshiy_attack = leshiy_graph.add(AnimSlot::Attack, 1.0);
+    let leshiy_death = leshiy_graph.add(AnimSlot::Death, 1.0);
+    let leshiy_hit = leshiy_graph.add(AnimSlot::Hit, 1.0);
+    let leshiy_graph_handle = library.build(leshiy_graph, &asset_server, &gltfs, &mut graphs);
 
     // --- Волколак ---
-    let mut volkolak_graph = AnimationGraph::new();
-    let volkolak_idle_handle = asset_server.load(asset_paths::VOLKOLAK_ANIM_IDLE);
-    let volkolak_idle = volkolak_graph.add_clip(volkolak_idle_handle.clone(), 1.0, volkolak_graph.root);
-    let volkolak_walk = volkolak_graph.add_clip(asset_server.load(asset_paths::VOLKOLAK_ANIM_WALK), 1.0, volkolak_graph.root);
-    let volkolak_run = volkolak_graph.add_clip(asset_server.load(asset_paths::VOLKOLAK_ANIM_RUN), 1.0, volkolak_graph.root);
-    let volkolak_attack = volkolak_graph.add_clip(asset_server.load(asset_paths::VOLKOLAK_ANIM_ATTACK), 1.0, volkolak_graph.root);
-    let volkolak_death = volkolak_graph.add_clip(asset_server.load(asset_paths::VOLKOLAK_ANIM_DEATH), 1.0, volkolak_graph.root);
-    let volkolak_hit = volkolak_graph.add_clip(asset_server.load(asset_paths::VOLKOLAK_ANIM_HIT), 1.0, volkolak_graph.root);
-    let volkolak_scream = volkolak_graph.add_clip(volkolak_idle_handle, 0.5, volkolak_graph.root);
-    let volkolak_graph_handle = graphs.add(volkolak_graph);
+    let mut volkolak_graph = ClipGraph::new(&VOLKOLAK_CLIPS);
+    let volkolak_idle = volkolak_graph.add(AnimSlot::Idle, 1.0);
+    let volkolak_walk = volkolak_graph.add(AnimSlot::Walk, 1.0);
+    let volkolak_run = volkolak_graph.add(AnimSlot::Run, 1.0);
+    let volkolak_attack = volkolak_graph.add(AnimSlot::Attack, 1.0);
+    let volkolak_death = volkolak_graph.add(AnimSlot::Death, 1.0);
+    let volkolak_hit = volkolak_graph.add(AnimSlot::Hit, 1.0);
+    let volkolak_scream = volkolak_graph.add(AnimSlot::Scream, 0.5);
+    let volkolak_graph_handle = library.build(volkolak_graph, &asset_server, &gltfs, &mut graphs);
 
     // Shared ring meshes (1 на тип, все entity клонируют handle)
     let upyr_ring_mesh = meshes.add(Annulus::new(0.45, 0.6));
     let leshiy_ring_mesh = meshes.add(Annulus::new(0.5, 0.65));
     let volkolak_ring_mesh = meshes.add(Annulus::new(0.55, 0.7));
+    let koldun_ring_mesh = meshes.add(Annulus::new(0.5, 0.65));
+    let aura_pulse_mesh = meshes.add(Annulus::new(0.9, 1.0));
 
     commands.insert_resource(EnemyAssets {
         upyr_model: asset_server.load(asset_paths::UPYR_MODEL),
@@ -78,6 +88,8 @@ pub fn preload_enemy_assets(
         upyr_ring_mesh,
         leshiy_ring_mesh,
         volkolak_ring_mesh,
+        koldun_ring_mesh,
+        aura_pulse_mesh,
 
         upyr_graph: upyr_graph_handle,
         upyr_indices: EnemyAnimationIndices {
@@ -1,27 +1,46 @@
 use bevy::prelude::*;
 use avian3d::prelude::*;
 use crate::modules::enemies::components::*;
-use crate::modules::enemies::parts::spatial_grid::SpatialGrid;
+use crate::modules::spatial::{SpatialIndex, SpatialLayer};
+use crate::modules::enemies::parts::flow_field::FlowField;
 use crate::modules::combat::parts::knockback::{Staggered, StaggerRecovery};
+use crate::modules::player::components::Player;
+use super::body_block::{PLAYER_BODY_RADIUS, ENEMY_BODY_RADIUS};
 
 const SEPARATION_RADIUS: f32 = 2.5;
 const SEPARATION_FORCE: f32 = 4.0;
 
-/// Boid-like separation: враги отталкиваются от соседей (O(n×k) через SpatialGrid, 0 аллокаций)
+/// Boid-like separation: враги отталкиваются от соседей (O(n×k) через SpatialIndex, 0 аллокаций).
+/// Отталкивание не вдавливает в препятствия и гасится против потока FlowField (толпа в проходе не стопорится).
+/// Time-slicing как у AI (тикает вместе с ним), обход параллельный.
+/// С блокировкой телами отталкивание у самого игрока ослаблено — кольцо окружения смыкается.
 pub fn enemy_separation_system(
-    grid: Res<SpatialGrid>,
+    index: Res<SpatialIndex>,
+    flow: Res<FlowField>,
+    tick: Res<AiTick>,
+    blocking: Res<BodyBlocking>,
+    player: Query<&Transform, (With<Player>, Without<Enemy>)>,
     mut enemies: Query<
-        (Entity, &Transform, &mut LinearVelocity),
+        (Entity, &Transform, &EnemyLod, &mut LinearVelocity),
         (With<Enemy>, Without<EnemyDying>, Without<Staggered>, Without<StaggerRecovery>)
     >,
 ) {
+    // Кольцо контакта (+ запас на шаг атаки): внутри него separation слабее
+    let ring = PLAYER_BODY_RADIUS + ENEMY_BODY_RADIUS * 2.0 + 0.5;
+    let ring_center = player.single().ok()
+        .filter(|_| blocking.enabled)
+        .map(|tf| tf.translation);
+
     // Позиция читается напрямую из Transform — без промежуточного HashMap
-    for (entity, transform, mut velocity) in &mut enemies {
+    enemies.par_iter_mut().for_each(|(entity, transform, lod, mut velocity)| {
+        if !tick.is_due(entity, lod.ai_period()) {
+            return;
+        }
         let pos = transform.translation;
         let mut repulsion = Vec3::ZERO;
 
         // Callback: 0 аллокаций (без возврата Vec)
-        grid.for_each_in_radius(pos, SEPARATION_RADIUS, |other_entity, other_pos| {
+        index.for_each_in_radius(SpatialLayer::Enemy, pos, SEPARATION_RADIUS, |other_entity, other_pos| {
             if other_entity == entity {
                 return;
             }
@@ -34,6 +53,29 @@ pub fn enemy_separation_system(
             }
         });
 
+        if ring_center.is_some_and(|center| center.xz().distance_squared(pos.xz()) < ring * ring) {
+            repulsion *= blocking.ring_separation_scale;
+        }
+
+        // Скольжение вдоль препятствия: оставляем свободную ось (или ничего)
+        if flow.is_blocked(pos + repulsion.normalize_or_zero() * 0.75) {
+            let x_only = Vec3::new(repulsion.x, 0.0, 0.0);
+            let z_only = Vec3::new(0.0, 0.0, repulsion.z);
+            repulsion = if !flow.is_blocked(pos + x_only.normalize_or_zero() * 0.75) {
+                x_only
+            } else if !flow.is_blocked(pos + z_only.normalize_or_zero() * 0.75) {
+                z_only
+            } else {
+                Vec3::ZERO
+            };
+        }
+
+        // Половина отталкивания против потока гасится
+        if let Some(flow_dir) = flow.sample(pos) {
+            let against = repulsion.dot(flow_dir).min(0.0);
+            repulsion -= flow_dir * against * 0.5;
+        }
+
         velocity.0 += repulsion;
-    }
+    });
 }
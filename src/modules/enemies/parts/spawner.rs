@@ -4,9 +4,10 @@ use crate::modules::enemies::components::*;
 use crate::modules::combat::components::EnemyAttackCooldown;
 use crate::modules::world::GroundCircle;
 use crate::modules::enemies::components::PortalSpawnAnim;
-use crate::shared::constants::{PORTAL_1_SPAWN, PORTAL_2_SPAWN};
+use crate::modules::world::{CurrentArena, EnemyWeights};
 use crate::shared::rand_01;
 use crate::modules::menu::KillFeedMessage;
+use crate::modules::spatial::{SpatialIndexed, SpatialLayer};
 use super::preload::EnemyAssets;
 
 /// Индексы анимаций врага в AnimationGraph — хранится на EnemyModel перманентно.
@@ -53,6 +54,7 @@ pub fn spawn_upyr_at(
         Collider::cylinder(0.5, 1.8),
     )).insert((
         LinearVelocity::default(),
+        SpatialIndexed(SpatialLayer::Enemy),
         LinearDamping(12.0),
         AngularDamping(8.0),
         crate::shared::GameLayer::enemy_layers(),
@@ -61,6 +63,8 @@ pub fn spawn_upyr_at(
             .lock_rotation_y()
             .lock_rotation_z(),
         EnemyAttackCooldown::new(5.0, 1.0, 1.5),
+        EnemyBehavior::new(BehaviorProfile::Brute),
+        AttackTokenCost::melee(2),
     )).id();
 
     let model_child = commands.spawn((
@@ -132,12 +136,15 @@ fn spawn_leshiy_at(
         LinearDamping(12.0),
         AngularDamping(8.0),
     )).insert((
+        SpatialIndexed(SpatialLayer::Enemy),
         crate::shared::GameLayer::enemy_layers(),
         LockedAxes::new()
             .lock_rotation_x()
             .lock_rotation_y()
             .lock_rotation_z(),
         EnemyAttackCooldown::new(8.0, 0.8, 1.8),
+        EnemyBehavior::new(BehaviorProfile::Flanker),
+        AttackTokenCost::melee(1),
     )).id();
 
     let model_child = commands.spawn((
@@ -184,7 +191,7 @@ fn spawn_volkolak_at(
     assets: &EnemyAssets,
     materials: &mut ResMut<Assets<StandardMaterial>>,
     spawn_pos: Vec3,
-) {
+) -> Entity {
     let enemy_entity = commands.spawn((
         Enemy,
         EnemyType::Volkolak,
@@ -210,12 +217,15 @@ fn spawn_volkolak_at(
         LinearDamping(12.0),
         AngularDamping(8.0),
     )).insert((
+        SpatialIndexed(SpatialLayer::Enemy),
         crate::shared::GameLayer::enemy_layers(),
         LockedAxes::new()
             .lock_rotation_x()
             .lock_rotation_y()
             .lock_rotation_z(),
         EnemyAttackCooldown::new(6.0, 0.8, 1.3),
+        EnemyBehavior::new(BehaviorProfile::Predator),
+        AttackTokenCost::melee(1),
     )).id();
 
     let model_child = commands.spawn((
@@ -252,10 +262,133 @@ fn spawn_volkolak_at(
         },
     )).id();
 
+    commands.entity(enemy_entity).add_child(model_child);
+    commands.entity(enemy_entity).add_child(ground_circle);
+    enemy_entity
+}
+
+/// Спавнит стаю волколаков вокруг точки: первый — вожак (крепче), остальные — члены
+fn spawn_volkolak_pack(
+    commands: &mut Commands,
+    assets: &EnemyAssets,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    center: Vec3,
+    size: u32,
+) {
+    let leader = spawn_volkolak_at(commands, assets, materials, center);
+    commands.entity(leader).insert((Health::new(18.0), PackLeader::new()));
+
+    for index in 0..size {
+        let entity = if index == 0 {
+            leader
+        } else {
+            let angle = index as f32 * std::f32::consts::TAU / size as f32;
+            let pos = center + Vec3::new(angle.cos(), 0.0, angle.sin()) * 1.5;
+            spawn_volkolak_at(commands, assets, materials, pos)
+        };
+        commands.entity(entity).insert(PackMember {
+            leader,
+            index,
+            pack_size: size,
+            order: PackOrder::Follow,
+        });
+    }
+    debug!("🐺 Volkolak pack spawned: {} wolves", size);
+}
+
+/// Спавнит одного Колдуна Нави (поддержка) — модель Упыря, фиолетовое кольцо
+fn spawn_koldun_at(
+    commands: &mut Commands,
+    assets: &EnemyAssets,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    spawn_pos: Vec3,
+) {
+    let enemy_entity = commands.spawn((
+        Enemy,
+        EnemyType::Koldun,
+        EnemyLod::default(),
+        CachedAnimSpeed::default(),
+        Health::new(18.0),
+        ChasePlayer {
+            speed: 4.0,
+            aggro_range: 18.0,
+            attack_range: 1.5,
+            anim_base_speed: 3.0,
+        },
+        OrbitDirection {
+            clockwise: rand_01() > 0.5,
+            change_timer: Timer::from_seconds(3.0 + rand_01() * 3.0, TimerMode::Repeating),
+        },
+        SupportAura {
+            radius: 3.0,
+            heal_amount: 4.0,
+            buff_duration: 3.0,
+            pulse_timer: Timer::from_seconds(2.5, TimerMode::Repeating),
+        },
+        FleeFromPlayer { flee_range: 7.0 },
+        TargetPriority { weight: 2.0 },
+        EnemyAnimState::new(EnemyAnim::Screaming),
+        SpawnScream { timer: Timer::from_seconds(1.5, TimerMode::Once) },
+        PortalSpawnAnim::new(),
+        Transform::from_translation(spawn_pos).with_scale(Vec3::splat(0.01)),
+    )).insert((
+        RigidBody::Dynamic,
+        Collider::cylinder(0.5, 1.8),
+        LinearVelocity::default(),
+        SpatialIndexed(SpatialLayer::Enemy),
+        LinearDamping(12.0),
+        AngularDamping(8.0),
+        crate::shared::GameLayer::enemy_layers(),
+        LockedAxes::new()
+            .lock_rotation_x()
+            .lock_rotation_y()
+            .lock_rotation_z(),
+        EnemyAttackCooldown::new(3.0, 1.2, 1.5),
+        EnemyBehavior::new(BehaviorProfile::Support),
+    )).id();
+
+    let model_child = commands.spawn((
+        SceneRoot(assets.upyr_model.clone()),
+        Transform::from_xyz(0.0, -0.9, 0.0)
+            .with_scale(Vec3::splat(1.1)),
+        EnemyModel,
+        assets.upyr_indices,
+        AnimationGraphHandle(assets.upyr_graph.clone()),
+    )).id();
+
+    let ring_mesh = assets.koldun_ring_mesh.clone();
+    let ring_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.6, 0.2, 0.9, 0.45),
+        emissive: LinearRgba::new(0.6, 0.2, 1.0, 0.0) * 1.5,
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    });
+    let ground_circle = commands.spawn((
+        Mesh3d(ring_mesh),
+        MeshMaterial3d(ring_material.clone()),
+        Transform::from_xyz(0.0, -0.89, 0.0)
+            .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+        GroundCircle {
+            inner_radius: 0.5,
+            outer_radius: 0.65,
+            base_alpha: 0.45,
+            pulse_speed: 2.0,
+            material_handle: ring_material,
+            last_hp_fraction: -1.0,
+            last_facing: 0.0,
+            last_alpha: 0.0,
+        },
+    )).id();
+
     commands.entity(enemy_entity).add_child(model_child);
     commands.entity(enemy_entity).add_child(ground_circle);
 }
 
+/// Фланговые волны (временный разлом за спиной игрока): с этой волны, каждая N-я
+const FLANK_FROM_WAVE: u32 = 4;
+const FLANK_EVERY: u32 = 2;
+
 /// Волновая система спавна врагов
 pub fn wave_spawner_system(
     time: Res<Time>,
@@ -265,6 +398,9 @@ pub fn wave_spawner_system(
     mut materials: ResMut<Assets<StandardMaterial>>,
     alive_enemies: Query<Entity, (With<Enemy>, Without<EnemyDying>, Without<EnemyCorpse>)>,
     mut feed: MessageWriter<KillFeedMessage>,
+    mut flank: MessageWriter<FlankRiftRequest>,
+    arena: Option<Res<CurrentArena>>,
+    portals: Query<(&SpawnPortal, &Transform), Without<SealedPortal>>,
 ) {
     match wave.phase {
         WavePhase::Cooldown => {
@@ -273,38 +409,32 @@ pub fn wave_spawner_system(
                 wave.current_wave += 1;
                 wave.enemies_to_spawn = 2 + wave.current_wave;
                 wave.spawn_timer.reset();
-                wave.portal_0_count = 0;
-                wave.portal_1_count = 0;
+                wave.portal_counts.clear();
                 wave.phase = WavePhase::Spawning;
+                // Фланговая волна: часть врагов выйдет из временного разлома за спиной игрока
+                if wave.current_wave >= FLANK_FROM_WAVE && wave.current_wave.is_multiple_of(FLANK_EVERY) {
+                    let budget = (wave.enemies_to_spawn / 3).max(2);
+                    wave.enemies_to_spawn -= budget;
+                    wave.rift_budget += budget;
+                    flank.write(FlankRiftRequest { budget });
+                }
                 // Баннер "ВОЛНА N" спавнится через wave_banner система (по wave.is_changed)
                 debug!("Wave {} started! Spawning {} enemies", wave.current_wave, wave.enemies_to_spawn);
             }
         }
         WavePhase::Spawning => {
             let Some(assets) = &enemy_assets else { return };
+            let Some(arena) = &arena else { return };
 
             wave.spawn_timer.tick(time.delta());
             if wave.spawn_timer.just_finished() && wave.enemies_to_spawn > 0 {
-                let pos = portal_spawn_position(&mut wave);
+                let Some(pos) = portal_spawn_position(&mut wave, &portals) else { return };
 
-                let roll = rand_01();
-                if wave.current_wave >= 3 && roll < 0.3 {
-                    spawn_leshiy_at(
-                        &mut commands, assets,
-                        &mut materials, pos,
-                    );
-                } else if wave.current_wave >= 2 && roll < 0.5 {
-                    spawn_volkolak_at(
-                        &mut commands, assets,
-                        &mut materials, pos,
-                    );
-                } else {
-                    spawn_upyr_at(
-                        &mut commands, assets,
-                        &mut materials, pos,
-                    );
-                }
-                wave.enemies_to_spawn -= 1;
+                let spawned = spawn_wave_enemy(
+                    &mut commands, assets, &mut materials,
+                    wave.current_wave, wave.enemies_to_spawn, &arena.enemy_weights, pos,
+                );
+                wave.enemies_to_spawn -= spawned;
 
                 if wave.enemies_to_spawn == 0 {
                     wave.phase = WavePhase::Fighting;
@@ -314,7 +444,7 @@ pub fn wave_spawner_system(
         }
         WavePhase::Fighting => {
             let alive_count = alive_enemies.iter().count();
-            if alive_count == 0 {
+            if alive_count == 0 && wave.rift_budget == 0 {
                 wave.wave_cooldown.reset();
                 wave.phase = WavePhase::Cooldown;
                 feed.write(KillFeedMessage {
@@ -328,36 +458,104 @@ pub fn wave_spawner_system(
     }
 }
 
-/// Выбирает позицию спавна из одного из двух порталов (~50/50 ±10%)
-fn portal_spawn_position(wave: &mut WaveState) -> Vec3 {
-    let total = wave.portal_0_count + wave.portal_1_count;
-    let use_portal_0 = if total == 0 {
-        rand_01() < 0.5
-    } else {
-        let ratio = wave.portal_0_count as f32 / total as f32;
-        if ratio > 0.6 {
-            false
-        } else if ratio < 0.4 {
-            true
-        } else {
-            rand_01() < 0.5
+/// Спавнит очередного врага волны в `pos` (волколаки с 3-й волны — стаей).
+/// `budget` — сколько врагов ещё положено; возвращает, сколько из него потрачено.
+pub fn spawn_wave_enemy(
+    commands: &mut Commands,
+    assets: &EnemyAssets,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    wave: u32,
+    budget: u32,
+    weights: &EnemyWeights,
+    pos: Vec3,
+) -> u32 {
+    match pick_enemy_kind(wave, weights) {
+        EnemyType::Koldun => {
+            spawn_koldun_at(commands, assets, materials, pos);
+            1
         }
-    };
+        EnemyType::Leshiy => {
+            spawn_leshiy_at(commands, assets, materials, pos);
+            1
+        }
+        EnemyType::Volkolak if wave >= 3 && budget >= 3 => {
+            // С 3-й волны волколаки приходят стаями (с 6-й — по 4)
+            let size = if wave >= 6 { 4 } else { 3 }.min(budget);
+            spawn_volkolak_pack(commands, assets, materials, pos, size);
+            size
+        }
+        EnemyType::Volkolak => {
+            spawn_volkolak_at(commands, assets, materials, pos);
+            1
+        }
+        EnemyType::Upyr => {
+            spawn_upyr_at(commands, assets, materials, pos);
+            1
+        }
+    }
+}
 
-    let (base_pos, count) = if use_portal_0 {
-        wave.portal_0_count += 1;
-        (PORTAL_1_SPAWN, wave.portal_0_count)
-    } else {
-        wave.portal_1_count += 1;
-        (PORTAL_2_SPAWN, wave.portal_1_count)
+/// Архетип следующего врага: базовые шансы по волне (колдун с 4-й, леший с 3-й,
+/// волколак со 2-й) × веса биома
+fn pick_enemy_kind(wave: u32, weights: &EnemyWeights) -> EnemyType {
+    let base: [(EnemyType, f32); 4] = match wave {
+        0..=1 => [(EnemyType::Upyr, 1.0), (EnemyType::Volkolak, 0.0), (EnemyType::Leshiy, 0.0), (EnemyType::Koldun, 0.0)],
+        2 => [(EnemyType::Upyr, 0.5), (EnemyType::Volkolak, 0.5), (EnemyType::Leshiy, 0.0), (EnemyType::Koldun, 0.0)],
+        3 => [(EnemyType::Upyr, 0.5), (EnemyType::Volkolak, 0.2), (EnemyType::Leshiy, 0.3), (EnemyType::Koldun, 0.0)],
+        _ => [(EnemyType::Upyr, 0.5), (EnemyType::Volkolak, 0.2), (EnemyType::Leshiy, 0.2), (EnemyType::Koldun, 0.1)],
     };
+    let weighted = base.map(|(kind, chance)| {
+        let weight = match kind {
+            EnemyType::Upyr => weights.upyr,
+            EnemyType::Volkolak => weights.volkolak,
+            EnemyType::Leshiy => weights.leshiy,
+            EnemyType::Koldun => weights.koldun,
+        };
+        (kind, chance * weight.max(0.0))
+    });
+
+    let total: f32 = weighted.iter().map(|&(_, w)| w).sum();
+    let mut roll = rand_01() * total;
+    for (kind, w) in weighted {
+        if roll < w {
+            return kind;
+        }
+        roll -= w;
+    }
+    EnemyType::Upyr
+}
+
+/// Выбирает позицию спавна у открытого (не запечатанного) портала: тот, из которого
+/// в этой волне вышло меньше всех, при равенстве — случайный. Нет открытых — спавнить неоткуда.
+fn portal_spawn_position(
+    wave: &mut WaveState,
+    portals: &Query<(&SpawnPortal, &Transform), Without<SealedPortal>>,
+) -> Option<Vec3> {
+    let mut best: Option<(&SpawnPortal, &Transform, u32, f32)> = None;
+    for (portal, transform) in portals.iter() {
+        let count = wave.portal_counts.get(portal.site).copied().unwrap_or(0);
+        let tie = rand_01();
+        let better = match best {
+            None => true,
+            Some((_, _, best_count, best_tie)) => count < best_count || (count == best_count && tie < best_tie),
+        };
+        if better {
+            best = Some((portal, transform, count, tie));
+        }
+    }
+    let (portal, transform, _, _) = best?;
+
+    if wave.portal_counts.len() <= portal.site {
+        wave.portal_counts.resize(portal.site + 1, 0);
+    }
+    wave.portal_counts[portal.site] += 1;
 
-    let offset = Vec3::new(
+    // Разброс перед аркой — в локальных осях портала (+Z смотрит вглубь арены)
+    let offset = transform.rotation * Vec3::new(
         (rand_01() - 0.5) * 3.0,
         0.0,
         rand_01() * 3.0,
     );
 
-    let _ = count;
-    base_pos + offset
+    Some(portal.spawn + offset)
 }
@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+use bevy::light::NotShadowCaster;
+use crate::modules::enemies::components::*;
+use crate::modules::spatial::{SpatialIndex, SpatialLayer};
+use super::preload::EnemyAssets;
+
+/// Расширяющееся кольцо импульса ауры (визуал, без геймплея)
+#[derive(Component)]
+pub struct AuraPulseVfx {
+    pub timer: Timer,
+    pub max_radius: f32,
+    pub material: Handle<StandardMaterial>,
+}
+
+/// Импульс ауры Колдуна: лечит союзников в радиусе и вешает AuraBuff (обновляет таймер).
+/// Соседи ищутся через SpatialIndex — O(k) вместо обхода всех врагов.
+pub fn support_aura_pulse_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    index: Res<SpatialIndex>,
+    enemy_assets: Option<Res<EnemyAssets>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut casters: Query<(Entity, &Transform, &mut SupportAura), (With<Enemy>, Without<EnemyDying>)>,
+    mut allies: Query<&mut Health, (With<Enemy>, Without<EnemyDying>)>,
+    mut buf: Local<Vec<Entity>>,
+) {
+    for (caster, transform, mut aura) in &mut casters {
+        aura.pulse_timer.tick(time.delta());
+        if !aura.pulse_timer.just_finished() {
+            continue;
+        }
+
+        let pos = transform.translation;
+        buf.clear();
+        index.for_each_in_radius(SpatialLayer::Enemy, pos, aura.radius, |entity, _| {
+            if entity != caster {
+                buf.push(entity);
+            }
+        });
+
+        for &ally in buf.iter() {
+            let Ok(mut health) = allies.get_mut(ally) else { continue };
+            health.current = (health.current + aura.heal_amount).min(health.max);
+
+            // insert заменяет существующий бафф — таймер обновляется
+            commands.entity(ally).insert(AuraBuff {
+                timer: Timer::from_seconds(aura.buff_duration, TimerMode::Once),
+                speed_multiplier: 1.3,
+                damage_multiplier: 1.25,
+            });
+        }
+
+        if let Some(assets) = &enemy_assets {
+            let material = materials.add(StandardMaterial {
+                base_color: Color::srgba(0.7, 0.3, 1.0, 0.6),
+                emissive: LinearRgba::new(1.2, 0.4, 2.0, 0.0),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            });
+            commands.spawn((
+                Mesh3d(assets.aura_pulse_mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                Transform::from_xyz(pos.x, 0.03, pos.z)
+                    .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+                    .with_scale(Vec3::splat(0.1)),
+                NotShadowCaster,
+                AuraPulseVfx {
+                    timer: Timer::from_seconds(0.6, TimerMode::Once),
+                    max_radius: aura.radius,
+                    material,
+                },
+            ));
+        }
+
+        debug!("🔮 Koldun aura pulse: {} allies healed/buffed", buf.len());
+    }
+}
+
+/// Тикает AuraBuff, снимает по истечении
+pub fn aura_buff_decay_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut AuraBuff)>,
+) {
+    for (entity, mut buff) in &mut query {
+        buff.timer.tick(time.delta());
+        if buff.timer.is_finished() {
+            commands.entity(entity).remove::<AuraBuff>();
+        }
+    }
+}
+
+/// Анимация кольца импульса: рост до радиуса ауры + затухание alpha
+pub fn aura_pulse_vfx_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut AuraPulseVfx, &mut Transform)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (entity, mut vfx, mut transform) in &mut query {
+        vfx.timer.tick(time.delta());
+        let t = vfx.timer.fraction();
+
+        // Ease-out: быстрый рост, плавное завершение (меш — кольцо радиусом 1м)
+        let eased = 1.0 - (1.0 - t) * (1.0 - t);
+        transform.scale = Vec3::splat((vfx.max_radius * eased).max(0.1));
+
+        if let Some(mat) = materials.get_mut(&vfx.material) {
+            mat.base_color = mat.base_color.with_alpha(0.6 * (1.0 - t));
+        }
+
+        if vfx.timer.is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
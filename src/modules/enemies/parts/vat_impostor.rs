@@ -0,0 +1,617 @@
+use bevy::prelude::*;
+use bevy::animation::graph::AnimationNodeType;
+use bevy::asset::{LoadState, RenderAssetUsages};
+use bevy::ecs::system::SystemParam;
+use bevy::camera::primitives::Aabb;
+use bevy::math::Affine3A;
+use bevy::mesh::{MeshTag, VertexAttributeValues};
+use bevy::mesh::skinning::{SkinnedMesh, SkinnedMeshInverseBindposes};
+use bevy::pbr::{ExtendedMaterial, MaterialExtension};
+use bevy::platform::collections::HashMap;
+use bevy::render::render_resource::{AsBindGroup, Extent3d, ShaderType, TextureDimension, TextureFormat};
+use bevy::shader::ShaderRef;
+use std::time::Duration;
+use crate::modules::enemies::components::*;
+use crate::modules::world::{RimLightSettings, character_rim_light};
+use super::preload::EnemyAssets;
+
+/// Кадров на клип в VAT (клип зациклен, шейдер интерполирует соседние кадры)
+const VAT_FRAMES_PER_CLIP: u32 = 16;
+/// Максимальная ширина VAT (лимит WebGL2) — длинные меши переносятся на несколько строк
+const VAT_MAX_WIDTH: usize = 2048;
+/// Запекающие риги прячутся под ареной
+const BAKE_RIG_POSITION: Vec3 = Vec3::new(0.0, -50.0, 0.0);
+
+/// Запекаемые клипы: только циклы передвижения (остальные состояния — всегда скиннутая модель)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VatClip {
+    Idle,
+    Walk,
+    Run,
+}
+
+impl VatClip {
+    const ALL: [VatClip; 3] = [VatClip::Idle, VatClip::Walk, VatClip::Run];
+
+    pub fn from_anim(anim: EnemyAnim) -> Option<Self> {
+        match anim {
+            EnemyAnim::Idle => Some(VatClip::Idle),
+            EnemyAnim::Walking => Some(VatClip::Walk),
+            EnemyAnim::Running => Some(VatClip::Run),
+            _ => None,
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn node(self, animations: &EnemyAnimations) -> AnimationNodeIndex {
+        match self {
+            VatClip::Idle => animations.idle,
+            VatClip::Walk => animations.walk,
+            VatClip::Run => animations.run,
+        }
+    }
+}
+
+/// Параметры VAT для шейдера (раскладка — в vat_common.wgsl)
+#[derive(Clone, Debug, Reflect, ShaderType)]
+pub struct VatSettings {
+    pub clip_durations: Vec4,
+    pub texture_width: u32,
+    pub rows_per_frame: u32,
+    pub frames_per_clip: u32,
+    pub _pad: u32,
+}
+
+/// Расширение материала импостера: поза из VAT в вершинном шейдере,
+/// фрагмент — тот же stylized PBR + rim, что у скиннутой модели (бесшовная подмена)
+#[derive(Asset, AsBindGroup, Reflect, Debug, Clone)]
+pub struct VatExtension {
+    #[uniform(100)]
+    pub rim: RimLightSettings,
+    #[texture(101)]
+    pub positions: Handle<Image>,
+    #[texture(102)]
+    pub normals: Handle<Image>,
+    #[uniform(103)]
+    pub settings: VatSettings,
+}
+
+impl MaterialExtension for VatExtension {
+    fn vertex_shader() -> ShaderRef {
+        "shaders/vat_impostor.wgsl".into()
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        "shaders/stylized_pbr.wgsl".into()
+    }
+
+    fn prepass_vertex_shader() -> ShaderRef {
+        "shaders/vat_impostor_prepass.wgsl".into()
+    }
+}
+
+pub type VatMaterial = ExtendedMaterial<StandardMaterial, VatExtension>;
+
+/// Запечённый примитив модели: меш без костей + материал с VAT
+struct VatPart {
+    mesh: Handle<Mesh>,
+    material: Handle<VatMaterial>,
+    aabb: Aabb,
+}
+
+/// Запечённая модель (1 на Scene, Колдун шарит запекание Упыря)
+pub struct VatBake {
+    parts: Vec<VatPart>,
+    clip_durations: [f32; 3],
+}
+
+/// Готовые VAT по моделям — переживают рестарт раунда (запекание один раз за сессию)
+#[derive(Resource, Default)]
+pub struct VatLibrary {
+    bakes: HashMap<AssetId<Scene>, VatBake>,
+}
+
+impl VatLibrary {
+    pub fn get(&self, scene: &Handle<Scene>) -> Option<&VatBake> {
+        self.bakes.get(&scene.id())
+    }
+}
+
+/// Скиннутый примитив рига + накопленные кадры (frames × vertices)
+struct BakePart {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+    skin: SkinnedMesh,
+    positions: Vec<Vec3>,
+    normals: Vec<Vec3>,
+}
+
+/// Задание запекания: скрытый риг, который покадрово ставится в позы через AnimationPlayer
+struct VatBakeJob {
+    scene: Handle<Scene>,
+    graph: Handle<AnimationGraph>,
+    clips: [AnimationNodeIndex; 3],
+    rig: Entity,
+    player: Option<Entity>,
+    durations: [f32; 3],
+    parts: Vec<BakePart>,
+    step: u32,
+}
+
+#[derive(Resource, Default)]
+pub struct VatBaker {
+    jobs: Vec<VatBakeJob>,
+}
+
+/// Скрытый риг для запекания
+#[derive(Component)]
+pub struct VatBakeRig;
+
+/// Состояние импостера врага: активен ли VAT, MeshTag (клип + фаза + скорость),
+/// меши импостера и скрываемые скиннутые меши
+#[derive(Component)]
+pub struct VatImpostor {
+    active: bool,
+    tag: u32,
+    meshes: Vec<Entity>,
+    skinned: Vec<Entity>,
+}
+
+/// MeshTag: биты 0-1 = клип, 2-15 = сдвиг фазы, 16-31 = скорость × 1000 (см. vat_common.wgsl)
+fn encode_tag(clip: VatClip, phase_offset: f32, speed: f32) -> u32 {
+    let offset = ((phase_offset.rem_euclid(1.0) * 16384.0).round() as u32) & 0x3FFF;
+    let speed = (speed * 1000.0).round().clamp(0.0, 65535.0) as u32;
+    clip.index() as u32 | (offset << 2) | (speed << 16)
+}
+
+fn decode_tag(tag: u32) -> (VatClip, f32, f32) {
+    let clip = VatClip::ALL[((tag & 3) as usize).min(2)];
+    let offset = ((tag >> 2) & 0x3FFF) as f32 / 16384.0;
+    let speed = (tag >> 16) as f32 / 1000.0;
+    (clip, offset, speed)
+}
+
+/// Запуск запекания при входе в раунд: по ригу на каждую ещё не запечённую модель
+pub fn start_vat_bake(
+    mut commands: Commands,
+    assets: Res<EnemyAssets>,
+    library: Res<VatLibrary>,
+    mut baker: ResMut<VatBaker>,
+) {
+    let models = [
+        (&assets.upyr_model, &assets.upyr_graph, assets.upyr_indices),
+        (&assets.leshiy_model, &assets.leshiy_graph, assets.leshiy_indices),
+        (&assets.volkolak_model, &assets.volkolak_graph, assets.volkolak_indices),
+    ];
+
+    for (scene, graph, indices) in models {
+        if library.get(scene).is_some() || baker.jobs.iter().any(|job| job.scene == *scene) {
+            continue;
+        }
+        let rig = commands.spawn((
+            SceneRoot(scene.clone()),
+            Transform::from_translation(BAKE_RIG_POSITION),
+            Visibility::Hidden,
+            VatBakeRig,
+        )).id();
+        baker.jobs.push(VatBakeJob {
+            scene: scene.clone(),
+            graph: graph.clone(),
+            clips: [indices.idle, indices.walk, indices.run],
+            rig,
+            player: None,
+            durations: [0.0; 3],
+            parts: Vec::new(),
+            step: 0,
+        });
+    }
+
+    if !baker.jobs.is_empty() {
+        debug!("🧊 VAT bake started for {} models", baker.jobs.len());
+    }
+}
+
+/// Ставит риг в позу шага запекания (применится в PostUpdate, снимется в следующем кадре)
+fn request_pose(player: &mut AnimationPlayer, job: &VatBakeJob) {
+    let clip = (job.step / VAT_FRAMES_PER_CLIP) as usize;
+    let frame = job.step % VAT_FRAMES_PER_CLIP;
+    let time = frame as f32 / VAT_FRAMES_PER_CLIP as f32 * job.durations[clip];
+    player.stop_all();
+    player.start(job.clips[clip]).seek_to(time).pause();
+}
+
+/// CPU-скиннинг текущей позы рига в пространство корня сцены (= пространство EnemyModel)
+fn capture_pose(
+    part: &mut BakePart,
+    root_inverse: Affine3A,
+    globals: &Query<&GlobalTransform>,
+    meshes: &Assets<Mesh>,
+    bindposes: &Assets<SkinnedMeshInverseBindposes>,
+) -> bool {
+    let (Some(mesh), Some(inverse_bindposes)) = (meshes.get(&part.mesh), bindposes.get(&part.skin.inverse_bindposes)) else {
+        return false;
+    };
+    let (
+        Some(positions),
+        Some(normals),
+        Some(VertexAttributeValues::Uint16x4(joints)),
+        Some(VertexAttributeValues::Float32x4(weights)),
+    ) = (
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION).and_then(|a| a.as_float3()),
+        mesh.attribute(Mesh::ATTRIBUTE_NORMAL).and_then(|a| a.as_float3()),
+        mesh.attribute(Mesh::ATTRIBUTE_JOINT_INDEX),
+        mesh.attribute(Mesh::ATTRIBUTE_JOINT_WEIGHT),
+    ) else {
+        return false;
+    };
+
+    let matrices: Vec<Affine3A> = part.skin.joints.iter()
+        .zip(inverse_bindposes.iter())
+        .map(|(joint, bindpose)| {
+            let joint_global = globals.get(*joint).map(|g| g.affine()).unwrap_or_default();
+            root_inverse * joint_global * Affine3A::from_mat4(*bindpose)
+        })
+        .collect();
+
+    for (v, position) in positions.iter().enumerate() {
+        let position = Vec3::from(*position);
+        let normal = Vec3::from(normals[v]);
+        let mut skinned_position = Vec3::ZERO;
+        let mut skinned_normal = Vec3::ZERO;
+        for k in 0..4 {
+            let weight = weights[v][k];
+            let Some(matrix) = matrices.get(joints[v][k] as usize) else { continue };
+            if weight == 0.0 {
+                continue;
+            }
+            skinned_position += matrix.transform_point3(position) * weight;
+            skinned_normal += matrix.transform_vector3(normal) * weight;
+        }
+        part.positions.push(skinned_position);
+        part.normals.push(skinned_normal.normalize_or(Vec3::Y));
+    }
+    true
+}
+
+/// Упаковка кадров примитива в текстуры + меш без костей + VAT-материал
+fn finish_part(
+    part: BakePart,
+    durations: [f32; 3],
+    meshes: &mut Assets<Mesh>,
+    images: &mut Assets<Image>,
+    standard_materials: &Assets<StandardMaterial>,
+    vat_materials: &mut Assets<VatMaterial>,
+) -> Option<VatPart> {
+    let frames = (VAT_FRAMES_PER_CLIP * 3) as usize;
+    let vertex_count = part.positions.len() / frames;
+    if vertex_count == 0 {
+        return None;
+    }
+    let width = vertex_count.min(VAT_MAX_WIDTH);
+    let rows_per_frame = vertex_count.div_ceil(width);
+    let height = frames * rows_per_frame;
+
+    // Позиции — Rgba16Float (8 байт/тексель), нормали — Rgba8Snorm (4 байта/тексель)
+    let mut position_data = vec![0u8; width * height * 8];
+    let mut normal_data = vec![0u8; width * height * 4];
+    for frame in 0..frames {
+        for v in 0..vertex_count {
+            let texel = (frame * rows_per_frame + v / width) * width + v % width;
+            let p = part.positions[frame * vertex_count + v];
+            let n = part.normals[frame * vertex_count + v];
+            for (c, value) in [p.x, p.y, p.z, 1.0].into_iter().enumerate() {
+                let bytes = half::f16::from_f32(value).to_le_bytes();
+                position_data[texel * 8 + c * 2..texel * 8 + c * 2 + 2].copy_from_slice(&bytes);
+            }
+            for (c, value) in [n.x, n.y, n.z, 0.0].into_iter().enumerate() {
+                normal_data[texel * 4 + c] = ((value.clamp(-1.0, 1.0) * 127.0).round() as i8) as u8;
+            }
+        }
+    }
+
+    let size = Extent3d { width: width as u32, height: height as u32, depth_or_array_layers: 1 };
+    let positions = images.add(Image::new(
+        size, TextureDimension::D2, position_data, TextureFormat::Rgba16Float, RenderAssetUsages::RENDER_WORLD,
+    ));
+    let normals = images.add(Image::new(
+        size, TextureDimension::D2, normal_data, TextureFormat::Rgba8Snorm, RenderAssetUsages::RENDER_WORLD,
+    ));
+
+    // Меш импостера: исходная топология/UV, поза первого кадра вместо bind pose, без костей
+    let mut mesh = meshes.get(&part.mesh)?.clone();
+    mesh.remove_attribute(Mesh::ATTRIBUTE_JOINT_INDEX);
+    mesh.remove_attribute(Mesh::ATTRIBUTE_JOINT_WEIGHT);
+    mesh.remove_attribute(Mesh::ATTRIBUTE_TANGENT);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, part.positions[..vertex_count].to_vec());
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, part.normals[..vertex_count].to_vec());
+
+    // AABB по всем кадрам — frustum culling не обрежет вытянутую позу
+    let aabb = Aabb::enclosing(part.positions.iter())?;
+
+    let material = vat_materials.add(VatMaterial {
+        base: standard_materials.get(&part.material).cloned().unwrap_or_default(),
+        extension: VatExtension {
+            rim: character_rim_light(),
+            positions,
+            normals,
+            settings: VatSettings {
+                clip_durations: Vec4::new(durations[0], durations[1], durations[2], 0.0),
+                texture_width: width as u32,
+                rows_per_frame: rows_per_frame as u32,
+                frames_per_clip: VAT_FRAMES_PER_CLIP,
+                _pad: 0,
+            },
+        },
+    });
+
+    Some(VatPart { mesh: meshes.add(mesh), material, aabb })
+}
+
+/// Сущности рига при запекании: иерархия сцены, плеер, скиннутые меши и их мировые трансформы
+#[derive(SystemParam)]
+pub struct VatRigQueries<'w, 's> {
+    hierarchy: Query<'w, 's, &'static Children>,
+    players: Query<'w, 's, &'static mut AnimationPlayer>,
+    skinned: Query<'w, 's, (&'static SkinnedMesh, &'static Mesh3d, &'static MeshMaterial3d<StandardMaterial>)>,
+    globals: Query<'w, 's, &'static GlobalTransform>,
+}
+
+/// Ассеты запекания: читаем граф, клипы и кости, пишем меши, текстуры VAT и материалы
+#[derive(SystemParam)]
+pub struct VatBakeAssets<'w> {
+    graphs: Res<'w, Assets<AnimationGraph>>,
+    clips: Res<'w, Assets<AnimationClip>>,
+    bindposes: Res<'w, Assets<SkinnedMeshInverseBindposes>>,
+    standard_materials: Res<'w, Assets<StandardMaterial>>,
+    meshes: ResMut<'w, Assets<Mesh>>,
+    images: ResMut<'w, Assets<Image>>,
+    vat_materials: ResMut<'w, Assets<VatMaterial>>,
+}
+
+/// Покадровое запекание VAT: ожидание загрузки рига → шаг (снять прошлую позу, поставить следующую) → упаковка.
+/// 3 клипа × 16 кадров ≈ 48 кадров на модель, идёт параллельно кулдауну первой волны.
+pub fn vat_bake_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut baker: ResMut<VatBaker>,
+    mut library: ResMut<VatLibrary>,
+    rig: VatRigQueries,
+    assets: VatBakeAssets,
+) {
+    let VatRigQueries { hierarchy, mut players, skinned, globals } = rig;
+    let VatBakeAssets { graphs, clips, bindposes, standard_materials, mut meshes, mut images, mut vat_materials } = assets;
+    let total_steps = VAT_FRAMES_PER_CLIP * 3;
+
+    baker.jobs.retain_mut(|job| {
+        // --- Ожидание загрузки сцены, клипов и костей ---
+        let Some(player_entity) = job.player else {
+            if matches!(asset_server.load_state(&job.scene), LoadState::Failed(_)) {
+                warn!("🧊 VAT bake skipped: scene {:?} failed to load", job.scene.path());
+                commands.entity(job.rig).despawn();
+                return false;
+            }
+
+            let mut player = None;
+            let mut parts = Vec::new();
+            for entity in hierarchy.iter_descendants(job.rig) {
+                if players.contains(entity) {
+                    player = Some(entity);
+                }
+                if let Ok((skin, mesh, material)) = skinned.get(entity) {
+                    parts.push(BakePart {
+                        mesh: mesh.0.clone(),
+                        material: material.0.clone(),
+                        skin: skin.clone(),
+                        positions: Vec::new(),
+                        normals: Vec::new(),
+                    });
+                }
+            }
+            let Some(player) = player else { return true };
+            if parts.is_empty() {
+                return true;
+            }
+            let assets_ready = parts.iter().all(|part| {
+                meshes.get(&part.mesh).is_some() && bindposes.get(&part.skin.inverse_bindposes).is_some()
+            });
+            let Some(graph) = graphs.get(&job.graph) else { return true };
+            let mut durations = [0.0; 3];
+            for (duration, index) in durations.iter_mut().zip(job.clips) {
+                let clip = graph.get(index).and_then(|node| match &node.node_type {
+                    AnimationNodeType::Clip(handle) => clips.get(handle),
+                    _ => None,
+                });
+                let Some(clip) = clip else { return true };
+                *duration = clip.duration();
+            }
+            if !assets_ready {
+                return true;
+            }
+
+            commands.entity(player).insert(AnimationGraphHandle(job.graph.clone()));
+            job.player = Some(player);
+            job.durations = durations;
+            job.parts = parts;
+            job.step = 0;
+            if let Ok(mut anim_player) = players.get_mut(player) {
+                request_pose(&mut anim_player, job);
+            }
+            return true;
+        };
+
+        // --- Снимаем позу, выставленную в прошлом кадре ---
+        let root_inverse = globals.get(job.rig).map(|g| g.affine().inverse()).unwrap_or_default();
+        for part in &mut job.parts {
+            if !capture_pose(part, root_inverse, &globals, &meshes, &bindposes) {
+                warn!("🧊 VAT bake skipped: mesh without skinning attributes");
+                commands.entity(job.rig).despawn();
+                return false;
+            }
+        }
+
+        job.step += 1;
+        if job.step < total_steps {
+            if let Ok(mut anim_player) = players.get_mut(player_entity) {
+                request_pose(&mut anim_player, job);
+            }
+            return true;
+        }
+
+        // --- Все кадры сняты: упаковка в текстуры ---
+        let parts: Vec<VatPart> = std::mem::take(&mut job.parts).into_iter()
+            .filter_map(|part| finish_part(
+                part, job.durations, &mut meshes, &mut images, &standard_materials, &mut vat_materials,
+            ))
+            .collect();
+        info!("🧊 VAT baked: {:?} ({} parts, {} frames)", job.scene.path(), parts.len(), total_steps);
+        library.bakes.insert(job.scene.id(), VatBake { parts, clip_durations: job.durations });
+        commands.entity(job.rig).despawn();
+        false
+    });
+}
+
+/// Прерванное запекание (выход из раунда) — риги удаляются, запуск повторится в следующем раунде
+pub fn cleanup_vat_bake(
+    mut commands: Commands,
+    mut baker: ResMut<VatBaker>,
+    rigs: Query<Entity, With<VatBakeRig>>,
+) {
+    baker.jobs.clear();
+    for rig in &rigs {
+        commands.entity(rig).despawn();
+    }
+}
+
+/// MeshTag по текущему состоянию скиннутого плеера: фаза клипа сохраняется при подмене
+fn capture_tag(player: &AnimationPlayer, animations: &EnemyAnimations, clip: VatClip, bake: &VatBake, now: f32) -> u32 {
+    let duration = bake.clip_durations[clip.index()].max(0.001);
+    let (phase, speed) = player.animation(clip.node(animations))
+        .map(|active| (active.seek_time() / duration, active.speed()))
+        .unwrap_or((0.0, 1.0));
+    encode_tag(clip, phase - now * speed / duration, speed)
+}
+
+/// Меши под моделью врага: скиннутые прячутся, инстансы VAT получают MeshTag с фазой клипа
+#[derive(SystemParam)]
+pub struct ImpostorMeshes<'w, 's> {
+    hierarchy: Query<'w, 's, &'static Children>,
+    skinned: Query<'w, 's, (), With<SkinnedMesh>>,
+    visibility: Query<'w, 's, &'static mut Visibility>,
+    tags: Query<'w, 's, &'static mut MeshTag>,
+}
+
+fn set_visibility(entities: &[Entity], visible: bool, visibility: &mut Query<&mut Visibility>) {
+    for &entity in entities {
+        if let Ok(mut vis) = visibility.get_mut(entity) {
+            *vis = if visible { Visibility::Inherited } else { Visibility::Hidden };
+        }
+    }
+}
+
+/// Подмена скиннутой модели на VAT-импостер для Minimal LOD (и обратно).
+/// Вход: фаза и скорость клипа снимаются с AnimationPlayer в MeshTag, плеер останавливается
+/// (кости не вычисляются). Выход: клип перезапускается с фазой, до которой дошёл шейдер.
+/// Смена клипа под импостером: центральная система анимаций уже запустила новый клип —
+/// фаза снимается заново. Незапечённые состояния (крик, смерть) возвращают скиннутую модель.
+pub fn vat_impostor_swap_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    library: Res<VatLibrary>,
+    mut enemies: Query<
+        (Entity, &EnemyLod, &EnemyAnimState, &CachedAnimPlayer, &Children, Option<&mut VatImpostor>),
+        With<Enemy>
+    >,
+    models: Query<&SceneRoot, With<EnemyModel>>,
+    mut players: Query<(&EnemyAnimations, &mut AnimationPlayer, &mut AnimationTransitions)>,
+    impostor_meshes: ImpostorMeshes,
+) {
+    let ImpostorMeshes { hierarchy, skinned, mut visibility, mut tags } = impostor_meshes;
+    let now = time.elapsed_secs_wrapped();
+
+    for (entity, lod, anim_state, cached, children, impostor) in &mut enemies {
+        let clip = VatClip::from_anim(anim_state.current);
+        let active = impostor.as_ref().is_some_and(|imp| imp.active);
+        let want = *lod == EnemyLod::Minimal && clip.is_some();
+        if !want && !active {
+            continue;
+        }
+
+        let Some((model, bake)) = children.iter().find_map(|child| {
+            models.get(child).ok()
+                .and_then(|root| library.get(&root.0))
+                .map(|bake| (child, bake))
+        }) else { continue };
+        let Ok((animations, mut player, mut transitions)) = players.get_mut(cached.entity) else { continue };
+
+        match (clip.filter(|_| want), impostor) {
+            // Первый вход в импостер: спавним инстансы VAT под моделью
+            (Some(clip), None) => {
+                let tag = capture_tag(&player, animations, clip, bake, now);
+                player.stop_all();
+
+                let meshes: Vec<Entity> = bake.parts.iter().map(|part| {
+                    commands.spawn((
+                        Mesh3d(part.mesh.clone()),
+                        MeshMaterial3d(part.material.clone()),
+                        MeshTag(tag),
+                        part.aabb,
+                        Transform::default(),
+                        Visibility::Inherited,
+                        ChildOf(model),
+                    )).id()
+                }).collect();
+                let skinned_meshes: Vec<Entity> = hierarchy.iter_descendants(model)
+                    .filter(|e| skinned.contains(*e))
+                    .collect();
+                set_visibility(&skinned_meshes, false, &mut visibility);
+
+                commands.entity(entity).insert(VatImpostor {
+                    active: true,
+                    tag,
+                    meshes,
+                    skinned: skinned_meshes,
+                });
+            }
+            // Повторный вход или смена клипа под импостером
+            (Some(clip), Some(mut imp)) => {
+                if imp.active && decode_tag(imp.tag).0 == clip {
+                    continue;
+                }
+                imp.tag = capture_tag(&player, animations, clip, bake, now);
+                player.stop_all();
+                for &mesh in &imp.meshes {
+                    if let Ok(mut tag) = tags.get_mut(mesh) {
+                        tag.0 = imp.tag;
+                    }
+                }
+                if !imp.active {
+                    imp.active = true;
+                    set_visibility(&imp.meshes, true, &mut visibility);
+                    set_visibility(&imp.skinned, false, &mut visibility);
+                }
+            }
+            // Выход: скиннутая модель продолжает с фазы шейдера
+            (None, Some(mut imp)) => {
+                imp.active = false;
+                set_visibility(&imp.meshes, false, &mut visibility);
+                set_visibility(&imp.skinned, true, &mut visibility);
+
+                let (baked_clip, offset, speed) = decode_tag(imp.tag);
+                // Состояние сменилось на незапечённое — новый клип уже запущен центральной системой
+                if clip != Some(baked_clip) {
+                    continue;
+                }
+                let duration = bake.clip_durations[baked_clip.index()].max(0.001);
+                let phase = (offset + now * speed / duration).rem_euclid(1.0);
+                transitions.play(&mut player, baked_clip.node(animations), Duration::ZERO)
+                    .repeat()
+                    .set_speed(speed)
+                    .seek_to(phase * duration);
+            }
+            (None, None) => {}
+        }
+    }
+}
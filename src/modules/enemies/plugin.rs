@@ -1,8 +1,10 @@
 use bevy::prelude::*;
 use crate::shared::GameState;
-use crate::modules::enemies::parts::{spawner, ai, animation, cleanup, preload, spatial_grid, separation, attack_slots, portal_spawn, portal_vfx, debug_spawn, lod};
-use crate::modules::enemies::components::{WaveState, AttackSlotManager};
-use crate::modules::enemies::parts::spatial_grid::SpatialGrid;
+use crate::modules::enemies::parts::{spawner, ai, animation, cleanup, preload, separation, attack_slots, portal_spawn, portal_vfx, portal_seal, flank_rift, debug_spawn, lod, support_aura, behavior_debug, flow_field, pack, alert, vat_impostor, body_block};
+use crate::modules::enemies::components::{WaveState, AttackTokenBudget, AiTick, BodyBlocking, RiftState, PortalSealed, FlankRiftRequest};
+use crate::modules::player::parts::movement::player_movement_system;
+use crate::modules::enemies::components::EnemyCoreSet;
+use crate::modules::spatial::SpatialIndexSet;
 use crate::modules::enemies::parts::portal_vfx::PortalEmitTimer;
 
 pub struct EnemiesPlugin;
@@ -10,30 +12,73 @@ pub struct EnemiesPlugin;
 impl Plugin for EnemiesPlugin {
     fn build(&self, app: &mut App) {
         app
+            .add_plugins(MaterialPlugin::<vat_impostor::VatMaterial>::default())
+            .add_message::<alert::EnemyAlertMessage>()
+            .add_message::<PortalSealed>()
+            .add_message::<FlankRiftRequest>()
+            // Соседи врагов берутся из SpatialIndex — он перестраивается до AI
+            .configure_sets(Update, EnemyCoreSet.after(SpatialIndexSet))
             .init_resource::<WaveState>()
-            .init_resource::<AttackSlotManager>()
-            .init_resource::<SpatialGrid>()
+            .init_resource::<RiftState>()
+            .init_resource::<AttackTokenBudget>()
+            .init_resource::<BodyBlocking>()
+            .init_resource::<flow_field::FlowField>()
             .init_resource::<PortalEmitTimer>()
+            .init_resource::<behavior_debug::BehaviorDebugOverlay>()
+            .init_resource::<debug_spawn::StressTest>()
+            .init_resource::<AiTick>()
+            .init_resource::<vat_impostor::VatLibrary>()
+            .init_resource::<vat_impostor::VatBaker>()
             .add_systems(OnEnter(GameState::Playing), (
                 cleanup::despawn_enemies,
                 cleanup::reset_wave_state,
+                attack_slots::apply_mode_token_budget,
+                body_block::apply_mode_body_blocking,
                 cleanup::reset_kill_count,
                 preload::preload_enemy_assets,
+                vat_impostor::start_vat_bake,
                 portal_vfx::init_portal_vfx_assets,
+                flank_rift::init_flank_rift_assets,
                 debug_spawn::setup_debug_counter,
+                flow_field::build_nav_grid,
+                portal_seal::reset_portals,
+                flank_rift::despawn_flank_rifts,
             ).chain())
             .add_systems(Update, spawner::wave_spawner_system
                 .run_if(in_state(GameState::Playing)))
+            // Порталы: прочность ставится ещё на загрузке арены; окно печатей и новые разломы — по фазам волн
+            .add_systems(Update, portal_seal::init_portal_health_system)
+            .add_systems(Update, (
+                portal_seal::seal_window_system,
+                portal_seal::portal_seal_system,
+                portal_seal::rift_opening_system,
+            ).chain()
+             .after(spawner::wave_spawner_system)
+             .run_if(in_state(GameState::Playing)))
+            // Фланговые волны: временный разлом открывается по запросу спавнера
+            .add_systems(Update, (
+                flank_rift::open_flank_rift_system,
+                flank_rift::flank_rift_system,
+            ).chain()
+             .after(spawner::wave_spawner_system)
+             .run_if(in_state(GameState::Playing)))
             // LOD: обновление уровня детализации + заморозка анимаций/скрытие кругов
             .add_systems(Update, (
                 lod::update_enemy_lod_system,
                 lod::lod_ground_circle_system,
                 lod::lod_animation_freeze_system,
             ).run_if(in_state(GameState::Playing)))
-            // AI chain: строгий порядок (spatial grid → AI → separation → slots → death)
+            // AI chain: строгий порядок (после SpatialIndexSet: flow field → тревога → приказы стай → AI → separation → slots → death)
             .add_systems(Update, (
-                spatial_grid::rebuild_spatial_grid_system,
+                ai::advance_ai_tick,
+                flow_field::update_flow_field_system,
+                alert::alert_trigger_system,
+                alert::alert_propagation_system,
+                alert::alert_decay_system,
+                attack_slots::update_attack_token_budget,
+                pack::pack_hunting_system,
                 ai::enemy_ai_system,
+                ai::enemy_facing_system,
                 separation::enemy_separation_system,
                 attack_slots::attack_slot_system,
                 attack_slots::release_attack_slot_system,
@@ -41,13 +86,34 @@ impl Plugin for EnemiesPlugin {
                 ai::process_dying_enemies,
                 ai::strip_corpse_system,
                 ai::corpse_limit_system,
-            ).chain().in_set(crate::modules::enemies::components::EnemyCoreSet).run_if(in_state(GameState::Playing)))
+            ).chain().in_set(EnemyCoreSet).run_if(in_state(GameState::Playing)))
+            // Блокировка телами: поверх velocity от AI и движения игрока, до физики
+            .add_systems(Update, (
+                body_block::toggle_body_blocking_system,
+                body_block::body_block_system
+                    .after(EnemyCoreSet)
+                    .after(player_movement_system),
+            ).run_if(in_state(GameState::Playing)))
+            // Аура Колдуна: после rebuild grid (соседи актуальны)
+            .add_systems(Update, (
+                support_aura::support_aura_pulse_system
+                    .after(SpatialIndexSet),
+                support_aura::aura_buff_decay_system,
+                support_aura::aura_pulse_vfx_system,
+            ).run_if(in_state(GameState::Playing)))
             // Анимация: без chain, needs_transition() подхватит изменения (макс. 1 кадр задержки)
             .add_systems(Update, (
                 animation::enemy_animation_state_system,
                 animation::enemy_anim_speed_system,
                 animation::enemy_attack_anim_replay_system,
             ).run_if(in_state(GameState::Playing)))
+            // VAT-импостеры: запекание при входе в раунд + подмена Minimal LOD после переходов анимаций
+            .add_systems(Update, (
+                vat_impostor::vat_bake_system,
+                vat_impostor::vat_impostor_swap_system
+                    .after(animation::enemy_animation_state_system)
+                    .after(lod::update_enemy_lod_system),
+            ).run_if(in_state(GameState::Playing)))
             .add_systems(Update, (
                 animation::setup_enemy_animation,
                 animation::spawn_scream_decay_system,
@@ -57,12 +123,21 @@ impl Plugin for EnemiesPlugin {
                 portal_vfx::portal_spark_system,
             ).after(spawner::wave_spawner_system)
              .run_if(in_state(GameState::Playing)))
-            // Debug: F1-F4 спавн/убийство, счётчик врагов (только native)
+            // Debug: F1-F4 спавн/убийство, F5 overlay поведения, F6 (или удержание кнопки) стресс-сцена, счётчик врагов
             .add_systems(Update, (
                 debug_spawn::debug_spawn_system,
+                debug_spawn::stress_scene_system,
+                debug_spawn::stress_measure_system,
                 debug_spawn::update_debug_counter,
+                behavior_debug::toggle_behavior_debug_system,
+                behavior_debug::behavior_debug_label_system
+                    .after(ai::enemy_ai_system),
             ).run_if(in_state(GameState::Playing)))
-            .add_systems(OnExit(GameState::Playing), debug_spawn::cleanup_debug_counter);
+            .add_systems(OnExit(GameState::Playing), (
+                debug_spawn::cleanup_debug_counter,
+                behavior_debug::cleanup_behavior_debug_labels,
+                vat_impostor::cleanup_vat_bake,
+            ));
 
         info!("👾 EnemiesPlugin loaded (wave system + portals + animations)");
     }
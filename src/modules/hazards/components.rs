@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use crate::modules::world::HazardKind;
+
+/// Опасная зона на полу: раз в тик жжёт и/или замедляет всех внутри (героя и врагов)
+#[derive(Component)]
+pub struct HazardZone {
+    pub kind: HazardKind,
+    pub radius: f32,
+    pub tick: Timer,
+}
+
+/// Замедление от зоны: каждый тик внутри обновляет таймер, после выхода — истекает
+#[derive(Component)]
+pub struct HazardSlow {
+    pub multiplier: f32,
+    pub timer: Timer,
+}
+
+impl HazardSlow {
+    pub fn new(multiplier: f32, duration: f32) -> Self {
+        Self {
+            multiplier,
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+        }
+    }
+}
+
+/// Мерцание огня жаровни: язык пламени (scale) и свет (intensity)
+#[derive(Component)]
+pub struct FireFlicker {
+    pub base_intensity: f32,
+    /// Сдвиг фазы — соседние жаровни мерцают вразнобой
+    pub phase: f32,
+}
+
+/// Предупреждение о камнепаде: круг на земле заполняется к моменту удара
+#[derive(Component)]
+pub struct RockfallWarning {
+    pub timer: Timer,
+    /// Заливка круга (child) — растёт от центра
+    pub fill: Entity,
+}
+
+/// Падающий камень: разгоняется вниз, на касании бьёт по площади
+#[derive(Component)]
+pub struct FallingRock {
+    pub target: Vec3,
+    pub speed: f32,
+}
+
+/// Камень после удара — уходит в землю и исчезает
+#[derive(Component)]
+pub struct RockDebris {
+    pub timer: Timer,
+}
+
+/// Маркер опасностей от событий волн (снимаются при выходе из Playing)
+#[derive(Component)]
+pub struct WaveHazard;
+
+/// Расписание камнепадов: интервал сокращается с номером волны
+#[derive(Resource)]
+pub struct RockfallSchedule {
+    pub timer: Timer,
+}
+
+/// Светящаяся граница безопасной зоны на полу; меш перестраивается при сжатии
+#[derive(Component)]
+pub struct SafeZoneBorder {
+    pub mesh: Handle<Mesh>,
+    pub last_radius: f32,
+}
@@ -0,0 +1,6 @@
+pub mod components;
+pub mod plugin;
+pub(crate) mod parts;
+
+pub use plugin::HazardsPlugin;
+pub use components::{HazardZone, HazardSlow, WaveHazard};
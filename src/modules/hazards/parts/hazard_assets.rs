@@ -0,0 +1,134 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use crate::modules::combat::parts::camera_shake::CameraShake;
+use crate::modules::combat::parts::damage_vignette::DamageVignette;
+use crate::modules::combat::parts::vfx_assets::HitVfxAssets;
+use crate::modules::world::parts::ground_circle::create_annular_arc;
+
+/// Радиус камня камнепада (м)
+pub const ROCK_SIZE: f32 = 0.8;
+
+/// Кэшированные ассеты опасностей (создаются один раз на Startup — арена спавнится ещё на титуле).
+/// Меши кругов единичного радиуса — размер зоны задаёт scale.
+#[derive(Resource)]
+pub struct HazardAssets {
+    pub disc_mesh: Handle<Mesh>,
+    pub ring_mesh: Handle<Mesh>,
+    pub brazier_mesh: Handle<Mesh>,
+    pub flame_mesh: Handle<Mesh>,
+    pub rock_mesh: Handle<Mesh>,
+    pub fire_glow_material: Handle<StandardMaterial>,
+    pub brazier_material: Handle<StandardMaterial>,
+    pub flame_material: Handle<StandardMaterial>,
+    pub bog_material: Handle<StandardMaterial>,
+    pub poison_bog_material: Handle<StandardMaterial>,
+    pub warning_ring_material: Handle<StandardMaterial>,
+    pub warning_fill_material: Handle<StandardMaterial>,
+    pub rock_material: Handle<StandardMaterial>,
+    pub zone_border_material: Handle<StandardMaterial>,
+}
+
+/// Отклик удара опасности: виньетка урона героя, тряска камеры, VFX и цифры урона
+#[derive(SystemParam)]
+pub struct HazardFeedback<'w> {
+    pub vignette: ResMut<'w, DamageVignette>,
+    pub camera_shake: ResMut<'w, CameraShake>,
+    pub vfx_assets: Res<'w, HitVfxAssets>,
+}
+
+/// Инициализация ассетов опасностей
+pub fn init_hazard_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Отсвет жаровни на полу — граница зоны огня
+    let fire_glow_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(1.0, 0.4, 0.1, 0.25),
+        emissive: LinearRgba::new(1.5, 0.5, 0.1, 1.0),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    });
+    let brazier_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.12, 0.1, 0.09),
+        metallic: 0.6,
+        perceptual_roughness: 0.7,
+        ..default()
+    });
+    // Язык пламени: аддитивный, через bloom
+    let flame_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(1.0, 0.55, 0.15, 0.9),
+        emissive: LinearRgba::new(12.0, 4.0, 0.6, 1.0),
+        alpha_mode: AlphaMode::Add,
+        unlit: true,
+        ..default()
+    });
+
+    // Трясина: тёмная стоячая вода с бликом; ядовитая — болотно-зелёная с отсветом
+    let bog_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.08, 0.12, 0.06, 0.85),
+        perceptual_roughness: 0.15,
+        reflectance: 0.3,
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+    let poison_bog_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.2, 0.35, 0.08, 0.85),
+        emissive: LinearRgba::new(0.15, 0.45, 0.05, 1.0),
+        perceptual_roughness: 0.15,
+        reflectance: 0.3,
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+
+    // Круг камнепада: яркая кромка + полупрозрачная заливка
+    let warning_ring_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(1.0, 0.25, 0.1, 0.8),
+        emissive: LinearRgba::new(2.0, 0.4, 0.1, 1.0),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        cull_mode: None,
+        ..default()
+    });
+    let warning_fill_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.9, 0.2, 0.05, 0.3),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        cull_mode: None,
+        ..default()
+    });
+    let rock_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.32, 0.3, 0.28),
+        perceptual_roughness: 0.95,
+        ..default()
+    });
+
+    // Граница безопасной зоны: лиловая кромка тумана Нави
+    let zone_border_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.6, 0.3, 0.9, 0.7),
+        emissive: LinearRgba::new(1.2, 0.4, 2.0, 1.0),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        cull_mode: None,
+        ..default()
+    });
+
+    commands.insert_resource(HazardAssets {
+        disc_mesh: meshes.add(Circle::new(1.0)),
+        ring_mesh: meshes.add(create_annular_arc(0.9, 1.0, 1.0, 48)),
+        brazier_mesh: meshes.add(Cylinder::new(0.45, 0.5)),
+        flame_mesh: meshes.add(Cone { radius: 0.3, height: 0.8 }),
+        // Низкополигональная сфера — грубый обломок скалы
+        rock_mesh: meshes.add(Sphere::new(ROCK_SIZE).mesh().uv(7, 5)),
+        fire_glow_material,
+        brazier_material,
+        flame_material,
+        bog_material,
+        poison_bog_material,
+        warning_ring_material,
+        warning_fill_material,
+        rock_material,
+        zone_border_material,
+    });
+}
@@ -0,0 +1,10 @@
+//! Реализация опасностей арены:
+//! - hazard_assets: общие меши и материалы (жаровня, трясина, круг камнепада, камень) и отклик удара (HazardFeedback)
+//! - zones: статичные зоны из раскладки — урон и замедление всем внутри, мерцание огня
+//! - rockfall: камнепад по событиям волн — предупреждающий круг, падение, удар по площади
+//! - navi_fog: сжатие безопасной зоны по расписанию режима, урон туманом за границей, кромка на полу
+
+pub mod hazard_assets;
+pub mod zones;
+pub mod rockfall;
+pub mod navi_fog;
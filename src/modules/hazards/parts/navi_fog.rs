@@ -0,0 +1,135 @@
+use bevy::prelude::*;
+use bevy::light::NotShadowCaster;
+use std::f32::consts::FRAC_PI_2;
+use crate::modules::player::components::Player;
+use crate::modules::enemies::components::WaveState;
+use crate::modules::combat::components::PlayerHealth;
+use crate::modules::combat::parts::damage_vignette::DamageVignette;
+use crate::modules::world::{CurrentArena, SafeZone, SelectedMode, GAME_MODES};
+use crate::modules::world::parts::ground_circle::{create_annular_arc, update_arc_positions_inplace};
+use crate::modules::hazards::components::{SafeZoneBorder, WaveHazard};
+use super::hazard_assets::HazardAssets;
+
+/// Период урона туманом (с) — как у опасных зон
+const FOG_TICK: f32 = 0.5;
+/// Ширина светящейся кромки на полу (м)
+const BORDER_WIDTH: f32 = 0.25;
+const BORDER_SEGMENTS: u32 = 128;
+const BORDER_Y: f32 = 0.025;
+
+/// Новый забег: режим со сжатием — зона с центром арены, в начале накрывает её целиком
+/// (радиус до углов), на полу — кромка. Без сжатия — зона без ограничений.
+pub fn reset_safe_zone(
+    mut commands: Commands,
+    selected: Res<SelectedMode>,
+    arena: Option<Res<CurrentArena>>,
+    mut zone: ResMut<SafeZone>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    assets: Res<HazardAssets>,
+) {
+    *zone = SafeZone::default();
+    let mode = &GAME_MODES[selected.0];
+    let (Some(_), Some(arena)) = (&mode.shrink, arena) else { return };
+
+    zone.center = (arena.bounds.min + arena.bounds.max) * 0.5;
+    zone.radius = (arena.bounds.max - arena.bounds.min).length() * 0.5;
+    zone.target_radius = zone.radius;
+
+    let mesh = meshes.add(create_annular_arc(zone.radius - BORDER_WIDTH, zone.radius, 1.0, BORDER_SEGMENTS));
+    commands.spawn((
+        Mesh3d(mesh.clone()),
+        MeshMaterial3d(assets.zone_border_material.clone()),
+        Transform::from_xyz(zone.center.x, BORDER_Y, zone.center.y)
+            .with_rotation(Quat::from_rotation_x(-FRAC_PI_2)),
+        NotShadowCaster,
+        SafeZoneBorder { mesh, last_radius: zone.radius },
+        WaveHazard,
+    ));
+    info!("🌫️ Mode '{}': safe zone r={:.1} at {:?}", mode.name, zone.radius, zone.center);
+}
+
+/// Расписание режима: на волне стадии зона начинает стягиваться к её радиусу
+/// за `duration` секунд. Сжатие идёт и в паузах между волнами.
+pub fn safe_zone_schedule_system(
+    time: Res<Time>,
+    wave: Res<WaveState>,
+    selected: Res<SelectedMode>,
+    mut zone: ResMut<SafeZone>,
+) {
+    if !zone.is_bounded() {
+        return;
+    }
+    let Some(schedule) = &GAME_MODES[selected.0].shrink else { return };
+
+    if let Some(stage) = schedule.stages.get(zone.next_stage) {
+        if wave.current_wave >= stage.wave {
+            zone.target_radius = stage.radius.min(zone.radius);
+            zone.shrink_speed = (zone.radius - zone.target_radius) / stage.duration.max(0.1);
+            zone.next_stage += 1;
+            info!("🌫️ Navi fog closes in: r {:.1} → {:.1} over {:.0}s (wave {})",
+                zone.radius, zone.target_radius, stage.duration, wave.current_wave);
+        }
+    }
+
+    if zone.radius > zone.target_radius {
+        zone.radius = (zone.radius - zone.shrink_speed * time.delta_secs()).max(zone.target_radius);
+    }
+}
+
+/// Туман за границей жжёт героя: урон растёт, пока он снаружи подряд.
+/// Рывок не спасает — туман не огонь, его не проскочить.
+pub fn navi_fog_damage_system(
+    time: Res<Time>,
+    selected: Res<SelectedMode>,
+    mut zone: ResMut<SafeZone>,
+    mut player: Query<(&Transform, &mut PlayerHealth), With<Player>>,
+    mut vignette: ResMut<DamageVignette>,
+    mut since_tick: Local<f32>,
+) {
+    if !zone.is_bounded() {
+        return;
+    }
+    let Some(schedule) = &GAME_MODES[selected.0].shrink else { return };
+    let Ok((transform, mut health)) = player.single_mut() else { return };
+
+    if zone.contains(transform.translation) {
+        zone.time_outside = 0.0;
+        *since_tick = 0.0;
+        return;
+    }
+    zone.time_outside += time.delta_secs();
+    *since_tick += time.delta_secs();
+    if *since_tick < FOG_TICK {
+        return;
+    }
+    *since_tick -= FOG_TICK;
+
+    let dps = (schedule.damage_per_second + schedule.damage_growth * zone.time_outside)
+        .min(schedule.max_damage_per_second);
+    health.take_damage(dps * FOG_TICK);
+    vignette.trigger(0.3 + 0.4 * dps / schedule.max_damage_per_second, 0.3);
+    debug!("🌫️ Navi fog: {:.1} dps after {:.1}s outside", dps, zone.time_outside);
+}
+
+/// Кромка на полу следует за радиусом зоны (перестройка вершин in-place)
+pub fn safe_zone_border_system(
+    zone: Res<SafeZone>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut borders: Query<&mut SafeZoneBorder>,
+) {
+    for mut border in &mut borders {
+        if (border.last_radius - zone.radius).abs() < 0.02 {
+            continue;
+        }
+        border.last_radius = zone.radius;
+        if let Some(mesh) = meshes.get_mut(&border.mesh) {
+            let inner = (zone.radius - BORDER_WIDTH).max(0.0);
+            update_arc_positions_inplace(mesh, inner, zone.radius, 1.0, BORDER_SEGMENTS);
+        }
+    }
+}
+
+/// Выход из Playing: туман отступает (титул и следующий режим — без стены)
+pub fn clear_safe_zone(mut zone: ResMut<SafeZone>) {
+    *zone = SafeZone::default();
+}
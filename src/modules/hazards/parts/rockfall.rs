@@ -0,0 +1,223 @@
+use bevy::prelude::*;
+use bevy::light::NotShadowCaster;
+use avian3d::prelude::*;
+use std::f32::consts::{FRAC_PI_2, TAU};
+use std::time::Duration;
+use crate::modules::player::components::Player;
+use crate::modules::enemies::components::{Enemy, Health, EnemyDying, EnemyAnimState, EnemyAnim, WaveState, WavePhase};
+use crate::modules::combat::components::{PlayerHealth, Invulnerable};
+use crate::modules::combat::parts::knockback::Staggered;
+use crate::modules::combat::parts::{damage_numbers, impact_flash};
+use crate::modules::world::CurrentArena;
+use crate::modules::spatial::{SpatialIndex, SpatialLayer};
+use crate::modules::hazards::components::{RockfallWarning, FallingRock, RockDebris, WaveHazard, RockfallSchedule};
+use crate::shared::rand_01;
+use super::hazard_assets::{HazardAssets, HazardFeedback, ROCK_SIZE};
+
+/// С этой волны со скал начинают падать камни
+const ROCKFALL_FROM_WAVE: u32 = 3;
+/// Время от появления круга до удара (с) — успеть выйти или загнать врагов
+const ROCKFALL_TELEGRAPH: f32 = 1.4;
+const ROCKFALL_RADIUS: f32 = 1.8;
+const ROCKFALL_PLAYER_DAMAGE: f32 = 20.0;
+const ROCKFALL_ENEMY_DAMAGE: f32 = 40.0;
+const ROCKFALL_KNOCKBACK: f32 = 7.0;
+const ROCKFALL_STAGGER: f32 = 0.5;
+/// Разброс камней вокруг героя: первый почти под ногами, остальные шире
+const FIRST_ROCK_SPREAD: f32 = 1.5;
+const VOLLEY_SPREAD: f32 = 7.0;
+/// Отступ от границы арены — камни не падают в стены
+const BOUNDS_INSET: f32 = 3.0;
+const ROCK_DROP_HEIGHT: f32 = 14.0;
+const ROCK_GRAVITY: f32 = 40.0;
+const DEBRIS_LIFETIME: f32 = 1.5;
+const WARNING_Y: f32 = 0.03;
+
+/// Интервал между камнепадами: 8.8с на 3-й волне, не чаще раза в 4с
+fn rockfall_interval(wave: u32) -> f32 {
+    (10.0 - wave as f32 * 0.4).max(4.0)
+}
+
+/// Камней в залпе: +1 каждые 4 волны, максимум 4
+fn rocks_per_volley(wave: u32) -> u32 {
+    (1 + wave / 4).min(4)
+}
+
+/// Новый забег — расписание с нуля
+pub fn reset_rockfall_schedule(mut commands: Commands) {
+    commands.insert_resource(RockfallSchedule {
+        timer: Timer::from_seconds(rockfall_interval(ROCKFALL_FROM_WAVE), TimerMode::Repeating),
+    });
+}
+
+/// Событие волны: с ROCKFALL_FROM_WAVE, пока идёт бой, по таймеру — залп камней вокруг героя.
+/// В паузе между волнами камни не падают.
+pub fn rockfall_event_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    wave: Res<WaveState>,
+    mut schedule: ResMut<RockfallSchedule>,
+    assets: Res<HazardAssets>,
+    player: Query<&Transform, With<Player>>,
+    arena: Option<Res<CurrentArena>>,
+) {
+    if wave.current_wave < ROCKFALL_FROM_WAVE || wave.phase == WavePhase::Cooldown {
+        return;
+    }
+    if !schedule.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    schedule.timer.set_duration(Duration::from_secs_f32(rockfall_interval(wave.current_wave)));
+    let Ok(player_tf) = player.single() else { return };
+    let player_pos = player_tf.translation;
+
+    let count = rocks_per_volley(wave.current_wave);
+    for i in 0..count {
+        let spread = if i == 0 { FIRST_ROCK_SPREAD } else { VOLLEY_SPREAD };
+        let offset = Vec2::from_angle(rand_01() * TAU) * rand_01().sqrt() * spread;
+        let mut target = Vec2::new(player_pos.x + offset.x, player_pos.z + offset.y);
+        if let Some(arena) = &arena {
+            let inset = Vec2::splat(BOUNDS_INSET);
+            target = target.clamp(arena.bounds.min + inset, arena.bounds.max - inset);
+        }
+        spawn_rockfall_warning(&mut commands, &assets, Vec3::new(target.x, 0.0, target.y));
+    }
+    info!("🪨 Rockfall on wave {}: {} rocks", wave.current_wave, count);
+}
+
+/// Круг на земле (кромка + заливка от центра); через ROCKFALL_TELEGRAPH — камень
+pub fn spawn_rockfall_warning(commands: &mut Commands, assets: &HazardAssets, target: Vec3) {
+    let fill = commands.spawn((
+        Mesh3d(assets.disc_mesh.clone()),
+        MeshMaterial3d(assets.warning_fill_material.clone()),
+        // Чуть над кромкой (локальный Z родителя смотрит вверх)
+        Transform::from_xyz(0.0, 0.0, 0.005).with_scale(Vec3::splat(0.01)),
+        NotShadowCaster,
+    )).id();
+
+    commands.spawn((
+        Mesh3d(assets.ring_mesh.clone()),
+        MeshMaterial3d(assets.warning_ring_material.clone()),
+        Transform::from_xyz(target.x, WARNING_Y, target.z)
+            .with_rotation(Quat::from_rotation_x(-FRAC_PI_2))
+            .with_scale(Vec3::splat(ROCKFALL_RADIUS)),
+        NotShadowCaster,
+        RockfallWarning {
+            timer: Timer::from_seconds(ROCKFALL_TELEGRAPH, TimerMode::Once),
+            fill,
+        },
+        WaveHazard,
+    )).add_child(fill);
+}
+
+/// Заливка круга растёт к удару; по таймеру круг сменяется падающим камнем
+pub fn rockfall_warning_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    assets: Res<HazardAssets>,
+    mut warnings: Query<(Entity, &Transform, &mut RockfallWarning)>,
+    mut fills: Query<&mut Transform, Without<RockfallWarning>>,
+) {
+    for (entity, transform, mut warning) in &mut warnings {
+        warning.timer.tick(time.delta());
+        if let Ok(mut fill) = fills.get_mut(warning.fill) {
+            fill.scale = Vec3::splat(warning.timer.fraction().max(0.01));
+        }
+        if !warning.timer.is_finished() {
+            continue;
+        }
+
+        let target = Vec3::new(transform.translation.x, ROCK_SIZE * 0.6, transform.translation.z);
+        commands.entity(entity).despawn();
+        commands.spawn((
+            Mesh3d(assets.rock_mesh.clone()),
+            MeshMaterial3d(assets.rock_material.clone()),
+            Transform::from_translation(target + Vec3::Y * ROCK_DROP_HEIGHT)
+                .with_rotation(Quat::from_euler(EulerRot::XYZ, rand_01() * TAU, rand_01() * TAU, 0.0))
+                .with_scale(Vec3::new(1.0, 0.75, 1.1)),
+            FallingRock { target, speed: 0.0 },
+            WaveHazard,
+        ));
+    }
+}
+
+/// Падение камня; на касании — урон всем в радиусе, враги отлетают в Staggered
+/// (камнепадом можно загнать их в огонь), тряска и вспышка. Камень остаётся обломком.
+pub fn falling_rock_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    index: Res<SpatialIndex>,
+    mut rocks: Query<(Entity, &mut Transform, &mut FallingRock)>,
+    mut player: Query<(&Transform, &mut PlayerHealth, Has<Invulnerable>), (With<Player>, Without<FallingRock>)>,
+    mut enemies: Query<(&mut Health, &mut LinearVelocity, &mut EnemyAnimState), (With<Enemy>, Without<EnemyDying>, Without<Player>)>,
+    feedback: HazardFeedback,
+) {
+    let HazardFeedback { mut vignette, mut camera_shake, vfx_assets } = feedback;
+    let dt = time.delta_secs();
+    for (entity, mut transform, mut rock) in &mut rocks {
+        rock.speed += ROCK_GRAVITY * dt;
+        transform.translation.y -= rock.speed * dt;
+        if transform.translation.y > rock.target.y {
+            continue;
+        }
+        transform.translation = rock.target;
+        let center = rock.target;
+
+        if let Ok((player_tf, mut health, invulnerable)) = player.single_mut() {
+            let offset = player_tf.translation - center;
+            if Vec2::new(offset.x, offset.z).length() <= ROCKFALL_RADIUS && !invulnerable {
+                health.take_damage(ROCKFALL_PLAYER_DAMAGE);
+                vignette.trigger(0.7, 0.35);
+            }
+        }
+
+        let mut hits = 0;
+        index.for_each_in_radius(SpatialLayer::Enemy, center, ROCKFALL_RADIUS, |enemy, pos| {
+            let Ok((mut health, mut velocity, mut anim_state)) = enemies.get_mut(enemy) else { return };
+            health.take_damage(ROCKFALL_ENEMY_DAMAGE);
+            let dir = Vec3::new(pos.x - center.x, 0.0, pos.z - center.z).normalize_or_zero();
+            velocity.0 = dir * ROCKFALL_KNOCKBACK;
+            commands.entity(enemy).insert(Staggered::new(ROCKFALL_STAGGER));
+            if anim_state.current != EnemyAnim::HitReaction {
+                anim_state.current = EnemyAnim::HitReaction;
+            }
+            damage_numbers::spawn_damage_number(&mut commands, &vfx_assets.font, pos, ROCKFALL_ENEMY_DAMAGE);
+            hits += 1;
+        });
+
+        camera_shake.trigger(0.25, 0.15, Vec3::ZERO);
+        impact_flash::spawn_impact_flash(&mut commands, &vfx_assets, center - Vec3::Y * ROCK_SIZE);
+        commands.entity(entity)
+            .remove::<FallingRock>()
+            .insert(RockDebris { timer: Timer::from_seconds(DEBRIS_LIFETIME, TimerMode::Once) });
+        debug!("🪨 Rock landed at {:.1?}: {} enemies hit", center, hits);
+    }
+}
+
+/// Обломок: вторую половину жизни уходит в землю, затем despawn
+pub fn rock_debris_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Transform, &mut RockDebris)>,
+) {
+    for (entity, mut transform, mut debris) in &mut query {
+        debris.timer.tick(time.delta());
+        if debris.timer.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        if debris.timer.fraction() > 0.5 {
+            transform.translation.y -= ROCK_SIZE * 2.0 / DEBRIS_LIFETIME * time.delta_secs();
+        }
+    }
+}
+
+/// Despawn кругов и камней при выходе из Playing
+pub fn cleanup_wave_hazards(
+    mut commands: Commands,
+    query: Query<Entity, With<WaveHazard>>,
+) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
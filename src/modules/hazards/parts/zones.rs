@@ -0,0 +1,188 @@
+use bevy::prelude::*;
+use bevy::light::NotShadowCaster;
+use std::f32::consts::FRAC_PI_2;
+use crate::modules::player::components::Player;
+use crate::modules::enemies::components::{Enemy, Health, EnemyDying};
+use crate::modules::combat::components::{PlayerHealth, Invulnerable};
+use crate::modules::combat::parts::knockback::Staggered;
+use crate::modules::combat::parts::damage_numbers;
+use crate::modules::world::{ArenaEntity, CurrentArena, HazardDef, HazardKind};
+use crate::modules::spatial::{SpatialIndex, SpatialLayer};
+use crate::modules::hazards::components::{HazardZone, HazardSlow, FireFlicker};
+use super::hazard_assets::{HazardAssets, HazardFeedback};
+
+/// Период урона/замедления зоны (с)
+const HAZARD_TICK: f32 = 0.5;
+/// Замедление держится чуть дольше тика — внутри зоны не мигает
+const SLOW_LINGER: f32 = HAZARD_TICK + 0.15;
+/// Враг, отброшенный ударом в зону (Staggered), получает больше — награда за позиционирование
+const STAGGERED_DAMAGE_MULTIPLIER: f32 = 2.0;
+/// Высота кругов над полом (без z-fighting с травой)
+const HAZARD_Y: f32 = 0.02;
+const FIRE_LIGHT_INTENSITY: f32 = 80_000.0;
+
+/// Спавнит опасные зоны раскладки, как только арена заспавнена (и при смене биома).
+/// Зоны — ArenaEntity: снимаются вместе с ареной.
+pub fn spawn_arena_hazards(
+    mut commands: Commands,
+    arena: Option<Res<CurrentArena>>,
+    assets: Res<HazardAssets>,
+) {
+    let Some(arena) = arena else { return };
+    if !arena.is_added() {
+        return;
+    }
+    for (i, def) in arena.hazards.iter().enumerate() {
+        spawn_hazard(&mut commands, &assets, def, i as f32 * 1.7);
+    }
+    if !arena.hazards.is_empty() {
+        info!("🔥 Spawned {} arena hazards", arena.hazards.len());
+    }
+}
+
+fn spawn_hazard(commands: &mut Commands, assets: &HazardAssets, def: &HazardDef, phase: f32) {
+    let floor_disc = Transform::from_xyz(0.0, HAZARD_Y, 0.0)
+        .with_rotation(Quat::from_rotation_x(-FRAC_PI_2))
+        .with_scale(Vec3::splat(def.radius));
+
+    let mut zone = commands.spawn((
+        Transform::from_xyz(def.position.x, 0.0, def.position.z),
+        Visibility::default(),
+        HazardZone {
+            kind: def.kind,
+            radius: def.radius,
+            tick: Timer::from_seconds(HAZARD_TICK, TimerMode::Repeating),
+        },
+        ArenaEntity,
+    ));
+
+    match def.kind {
+        HazardKind::FirePit { .. } => {
+            zone.with_children(|parent| {
+                parent.spawn((
+                    Mesh3d(assets.disc_mesh.clone()),
+                    MeshMaterial3d(assets.fire_glow_material.clone()),
+                    floor_disc,
+                    NotShadowCaster,
+                ));
+                parent.spawn((
+                    Mesh3d(assets.brazier_mesh.clone()),
+                    MeshMaterial3d(assets.brazier_material.clone()),
+                    Transform::from_xyz(0.0, 0.25, 0.0),
+                ));
+                parent.spawn((
+                    Mesh3d(assets.flame_mesh.clone()),
+                    MeshMaterial3d(assets.flame_material.clone()),
+                    Transform::from_xyz(0.0, 0.9, 0.0),
+                    NotShadowCaster,
+                    FireFlicker { base_intensity: 0.0, phase },
+                ));
+                parent.spawn((
+                    PointLight {
+                        color: Color::srgb(1.0, 0.55, 0.2),
+                        intensity: FIRE_LIGHT_INTENSITY,
+                        range: def.radius * 5.0,
+                        shadows_enabled: false,
+                        ..default()
+                    },
+                    Transform::from_xyz(0.0, 1.4, 0.0),
+                    FireFlicker { base_intensity: FIRE_LIGHT_INTENSITY, phase },
+                ));
+            });
+        }
+        HazardKind::Bog { damage_per_second, .. } => {
+            let material = if damage_per_second > 0.0 {
+                assets.poison_bog_material.clone()
+            } else {
+                assets.bog_material.clone()
+            };
+            zone.with_child((
+                Mesh3d(assets.disc_mesh.clone()),
+                MeshMaterial3d(material),
+                floor_disc,
+                NotShadowCaster,
+            ));
+        }
+    }
+}
+
+/// Мерцание жаровен: язык пламени тянется, свет дрожит (сумма синусов — без явного периода)
+pub fn fire_flicker_system(
+    time: Res<Time>,
+    mut flames: Query<(&FireFlicker, &mut Transform), Without<PointLight>>,
+    mut lights: Query<(&FireFlicker, &mut PointLight)>,
+) {
+    let t = time.elapsed_secs();
+    let flicker = |phase: f32| ((t * 9.0 + phase).sin() * 0.6 + (t * 23.0 + phase * 2.3).sin() * 0.4) * 0.5;
+
+    for (fire, mut transform) in &mut flames {
+        let f = flicker(fire.phase);
+        transform.scale = Vec3::new(1.0 - f * 0.15, 1.0 + f * 0.3, 1.0 - f * 0.15);
+    }
+    for (fire, mut light) in &mut lights {
+        light.intensity = fire.base_intensity * (1.0 + flicker(fire.phase) * 0.35);
+    }
+}
+
+/// Тик зон: урон и замедление всем внутри — герою и врагам.
+/// Рывок (Invulnerable) проскакивает огонь без урона, но не трясину.
+pub fn hazard_zone_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    index: Res<SpatialIndex>,
+    mut zones: Query<(&Transform, &mut HazardZone)>,
+    mut player: Query<(Entity, &Transform, &mut PlayerHealth, Has<Invulnerable>), With<Player>>,
+    mut enemies: Query<(&mut Health, Has<Staggered>), (With<Enemy>, Without<EnemyDying>)>,
+    feedback: HazardFeedback,
+) {
+    let HazardFeedback { mut vignette, vfx_assets, .. } = feedback;
+    let mut player = player.single_mut().ok();
+
+    for (transform, mut zone) in &mut zones {
+        if !zone.tick.tick(time.delta()).just_finished() {
+            continue;
+        }
+        let center = transform.translation;
+        let damage = zone.kind.damage_per_second() * HAZARD_TICK;
+        let slow = zone.kind.slow();
+
+        if let Some((entity, player_tf, health, invulnerable)) = player.as_mut() {
+            let offset = player_tf.translation - center;
+            if Vec2::new(offset.x, offset.z).length() <= zone.radius {
+                if let Some(multiplier) = slow {
+                    commands.entity(*entity).insert(HazardSlow::new(multiplier, SLOW_LINGER));
+                }
+                if damage > 0.0 && !*invulnerable {
+                    health.take_damage(damage);
+                    vignette.trigger(0.35, 0.25);
+                }
+            }
+        }
+
+        index.for_each_in_radius(SpatialLayer::Enemy, center, zone.radius, |enemy, pos| {
+            let Ok((mut health, staggered)) = enemies.get_mut(enemy) else { return };
+            if let Some(multiplier) = slow {
+                commands.entity(enemy).insert(HazardSlow::new(multiplier, SLOW_LINGER));
+            }
+            if damage > 0.0 {
+                let dealt = if staggered { damage * STAGGERED_DAMAGE_MULTIPLIER } else { damage };
+                health.take_damage(dealt);
+                damage_numbers::spawn_damage_number(&mut commands, &vfx_assets.font, pos, dealt);
+            }
+        });
+    }
+}
+
+/// Тикает HazardSlow, снимает после выхода из зоны
+pub fn hazard_slow_decay_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut HazardSlow)>,
+) {
+    for (entity, mut slow) in &mut query {
+        slow.timer.tick(time.delta());
+        if slow.timer.is_finished() {
+            commands.entity(entity).remove::<HazardSlow>();
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+use crate::shared::GameState;
+use crate::modules::spatial::SpatialIndexSet;
+use super::parts::{hazard_assets, zones, rockfall, navi_fog};
+
+pub struct HazardsPlugin;
+
+impl Plugin for HazardsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Ассеты до спавна арены (арена стоит уже на титульном экране)
+            .add_systems(Startup, hazard_assets::init_hazard_assets)
+            .add_systems(Update, (
+                zones::spawn_arena_hazards,
+                zones::fire_flicker_system,
+            ))
+            .add_systems(OnEnter(GameState::Playing), (
+                rockfall::reset_rockfall_schedule,
+                navi_fog::reset_safe_zone,
+            ))
+            .add_systems(Update, (
+                zones::hazard_zone_system
+                    .after(SpatialIndexSet),
+                zones::hazard_slow_decay_system,
+                rockfall::rockfall_event_system,
+                rockfall::rockfall_warning_system,
+                rockfall::falling_rock_system
+                    .after(SpatialIndexSet),
+                rockfall::rock_debris_system,
+                navi_fog::safe_zone_schedule_system,
+                navi_fog::navi_fog_damage_system
+                    .after(navi_fog::safe_zone_schedule_system),
+                navi_fog::safe_zone_border_system
+                    .after(navi_fog::safe_zone_schedule_system),
+            ).run_if(in_state(GameState::Playing)))
+            .add_systems(OnExit(GameState::Playing), (
+                rockfall::cleanup_wave_hazards,
+                navi_fog::clear_safe_zone,
+            ));
+
+        info!("🔥 HazardsPlugin loaded (fire pits, bogs, rockfall, Navi fog)");
+    }
+}
@@ -5,6 +5,8 @@ pub struct InputState {
     pub movement: Vec3,
     pub is_running: bool,
     pub zoom_delta: f32,  // Mouse wheel / pinch для зума камеры
+    pub dash_requested: bool, // Рывок запрошен (Space / double-tap / кнопка), сбрасывает dash система
+    pub ultimate_requested: bool, // Ульта запрошена (Q / шкала ярости / автоплей), сбрасывает combat система
 
     // Touch-specific state
     pub touch_start: Option<Vec2>,       // Стартовая позиция касания для "invisible joystick"
@@ -12,6 +14,7 @@ pub struct InputState {
     pub primary_touch_id: Option<u64>,   // ID пальца для движения (защита от multi-touch)
     pub pinch_distance: Option<f32>,     // Расстояние между двумя пальцами (для зума)
     pub touch_current: Option<Vec2>,     // Текущая позиция primary пальца (для joystick визуала)
+    pub last_tap: Option<(f32, Vec2)>,   // Время и позиция прошлого касания (double-tap → рывок)
 
     // Keyboard state (для WC3/Dota override)
     pub has_keyboard_input: bool,        // WASD нажат в этом кадре (auto-play уступает)
@@ -38,6 +38,16 @@ pub fn handle_keyboard_input(
         input_state.is_running = false;
     }
 
+    // Space — рывок (флаг гасит dash система, даже если рывок на кулдауне)
+    if keyboard.just_pressed(KeyCode::Space) {
+        input_state.dash_requested = true;
+    }
+
+    // Q — ульта (сработает только при полной ярости)
+    if keyboard.just_pressed(KeyCode::KeyQ) {
+        input_state.ultimate_requested = true;
+    }
+
     // Mouse wheel для зума камеры (zoom_delta сбрасывается в camera_zoom_system)
     for event in mouse_wheel.read() {
         input_state.zoom_delta += event.y;
@@ -1,6 +1,6 @@
 //! Обработчики ввода:
-//! - keyboard: WASD движение + Shift бег → запись в InputState
-//! - touch: виртуальный джойстик для мобильных устройств
+//! - keyboard: WASD движение + Shift бег + Space рывок → запись в InputState
+//! - touch: виртуальный джойстик для мобильных устройств, double-tap → рывок
 
 pub(super) mod keyboard;
 pub(super) mod touch;
@@ -6,9 +6,12 @@ use crate::modules::input::data::input_state::InputState;
 const DEAD_ZONE: f32 = 5.0;        // Минимальное движение для регистрации (pixels)
 const RUN_THRESHOLD: f32 = 80.0;   // После этого расстояния — бег
 const PINCH_SENSITIVITY: f32 = 0.05; // Чувствительность pinch-to-zoom
+const DOUBLE_TAP_WINDOW: f32 = 0.3;  // Макс. пауза между касаниями double-tap (с)
+const DOUBLE_TAP_RADIUS: f32 = 40.0; // Макс. смещение второго касания (pixels)
 
-/// Система обработки touch input: drag-to-move + pinch-to-zoom
+/// Система обработки touch input: drag-to-move + pinch-to-zoom + double-tap → рывок
 pub fn handle_touch_input(
+    time: Res<Time>,
     touches: Res<Touches>,
     mut input_state: ResMut<InputState>,
 ) {
@@ -43,6 +46,18 @@ pub fn handle_touch_input(
         input_state.primary_touch_id = Some(touch.id());
         input_state.touch_start = Some(touch.position());
         input_state.is_touch_active = true;
+
+        // Double-tap: второе касание быстро и рядом с первым → рывок
+        let now = time.elapsed_secs();
+        let is_double = input_state.last_tap.is_some_and(|(at, pos)| {
+            now - at <= DOUBLE_TAP_WINDOW && pos.distance(touch.position()) <= DOUBLE_TAP_RADIUS
+        });
+        if is_double {
+            input_state.dash_requested = true;
+            input_state.last_tap = None;
+        } else {
+            input_state.last_tap = Some((now, touch.position()));
+        }
     }
 
     // Движение — ТОЛЬКО по primary finger (защита от multi-touch)
@@ -2,10 +2,15 @@ use bevy::prelude::*;
 use crate::modules::input::data::input_state::InputState;
 use crate::modules::player::Player;
 use crate::modules::enemies::components::{Enemy, EnemyDying};
-use crate::modules::combat::components::Weapon;
+use crate::modules::combat::components::{Weapon, Rage, PlayerHealth};
+use crate::modules::combat::HeroUltimate;
+use crate::modules::spatial::{SpatialIndex, SpatialLayer};
 use crate::toolkit::asset_paths;
 
 const AUTO_PLAY_SEEK_RADIUS: f32 = 20.0;
+/// Автоплей жмёт ульту, когда в её радиусе столько врагов (или HP низкое)
+const AUTO_ULTIMATE_MIN_ENEMIES: usize = 6;
+const AUTO_ULTIMATE_LOW_HP: f32 = 0.3;
 
 /// Состояние автоплея
 #[derive(Resource, Default)]
@@ -64,6 +69,30 @@ pub fn auto_play_movement(
     }
 }
 
+/// Автоплей тратит полную ярость: толпа в радиусе ульты или HP на исходе
+pub fn auto_play_ultimate(
+    auto_play: Res<AutoPlayState>,
+    mut input_state: ResMut<InputState>,
+    player_query: Query<(&Transform, &Rage, &HeroUltimate, &PlayerHealth), With<Player>>,
+    index: Res<SpatialIndex>,
+) {
+    if !auto_play.enabled {
+        return;
+    }
+    let Ok((player_tf, rage, ultimate, health)) = player_query.single() else { return };
+    if !rage.is_full() {
+        return;
+    }
+
+    let mut in_range = 0;
+    index.for_each_in_radius(SpatialLayer::Enemy, player_tf.translation, ultimate.0.radius, |_, _| in_range += 1);
+    let low_hp = health.current / health.max < AUTO_ULTIMATE_LOW_HP;
+
+    if in_range >= AUTO_ULTIMATE_MIN_ENEMIES || (low_hp && in_range > 0) {
+        input_state.ultimate_requested = true;
+    }
+}
+
 /// Обработка клика по кнопке автоплея
 pub fn toggle_auto_play(
     mut auto_play: ResMut<AutoPlayState>,
@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use crate::modules::input::data::input_state::InputState;
+use crate::modules::player::{Player, DashCooldown};
+use crate::toolkit::asset_paths;
+
+/// Маркер UI кнопки рывка (touch: альтернатива double-tap)
+#[derive(Component)]
+pub struct DashButton;
+
+/// Нажатие кнопки → запрос рывка
+pub fn dash_button_interaction(
+    mut input_state: ResMut<InputState>,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<DashButton>)>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            input_state.dash_requested = true;
+        }
+    }
+}
+
+/// Кнопка тусклая пока рывок на кулдауне
+pub fn dash_button_cooldown_tint(
+    player: Query<&DashCooldown, With<Player>>,
+    mut button: Query<&mut BackgroundColor, With<DashButton>>,
+) {
+    let Ok(cooldown) = player.single() else { return };
+    let Ok(mut bg) = button.single_mut() else { return };
+    let color = if cooldown.timer.is_finished() {
+        Color::srgba(0.2, 0.4, 0.7, 0.9)
+    } else {
+        Color::srgba(0.12, 0.12, 0.18, 0.6 + 0.3 * cooldown.timer.fraction())
+    };
+    if bg.0 != color {
+        bg.0 = color;
+    }
+}
+
+/// Спавн UI кнопки рывка (над кнопкой автоплея)
+pub fn spawn_dash_button(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    let font = asset_server.load(asset_paths::FONT_UI_BOLD);
+
+    commands.spawn((
+        DashButton,
+        Button,
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(140.0),
+            right: Val::Px(16.0),
+            width: Val::Px(56.0),
+            height: Val::Px(56.0),
+            border_radius: BorderRadius::all(Val::Percent(50.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.2, 0.4, 0.7, 0.9)),
+        GlobalZIndex(200),
+    )).with_children(|parent| {
+        // Frame overlay — текстура кнопки
+        parent.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                top: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                border_radius: BorderRadius::all(Val::Percent(50.0)),
+                ..default()
+            },
+            ImageNode::new(asset_server.load(asset_paths::UI_BUTTON_FRAME)),
+        ));
+        // Стрелка рывка
+        parent.spawn((
+            Text::new("»"),
+            TextFont { font, font_size: 28.0, ..default() },
+            TextColor(Color::srgb(1.0, 1.0, 1.0)),
+        ));
+    });
+}
+
+/// Despawn кнопки при выходе из Playing
+pub fn cleanup_dash_button(
+    mut commands: Commands,
+    query: Query<Entity, With<DashButton>>,
+) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
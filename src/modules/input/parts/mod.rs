@@ -1,3 +1,4 @@
 pub(super) mod touch_joystick;
 pub(super) mod tap_ripple;
 pub(super) mod auto_play;
+pub(super) mod dash_button;
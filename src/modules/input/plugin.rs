@@ -2,7 +2,7 @@ use bevy::prelude::*;
 use crate::shared::GameState;
 use super::data::input_state::InputState;
 use super::handlers::{keyboard, touch};
-use super::parts::{touch_joystick, tap_ripple, auto_play};
+use super::parts::{touch_joystick, tap_ripple, auto_play, dash_button};
 
 pub struct InputPlugin;
 
@@ -23,8 +23,11 @@ impl Plugin for InputPlugin {
                     .after(keyboard::handle_keyboard_input)
                     .after(touch::handle_touch_input),
                 auto_play::toggle_auto_play,
+                auto_play::auto_play_ultimate,
+                dash_button::dash_button_interaction,
+                dash_button::dash_button_cooldown_tint,
             ).run_if(in_state(GameState::Playing)))
-            .add_systems(OnEnter(GameState::Playing), auto_play::spawn_auto_play_button)
-            .add_systems(OnExit(GameState::Playing), auto_play::cleanup_auto_play_button);
+            .add_systems(OnEnter(GameState::Playing), (auto_play::spawn_auto_play_button, dash_button::spawn_dash_button))
+            .add_systems(OnExit(GameState::Playing), (auto_play::cleanup_auto_play_button, dash_button::cleanup_dash_button));
     }
 }
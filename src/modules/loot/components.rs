@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+use crate::modules::shrines::BuffKind;
+
+/// Вид разбиваемого контейнера
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BreakableKind {
+    /// Глиняная урна: с одного удара, внутри чаще опыт
+    Urn,
+    /// Бочка: крепче, внутри чаще золото
+    Barrel,
+    /// Груда костей: опыт и лечение
+    BonePile,
+}
+
+impl BreakableKind {
+    /// Прочность (здоровье Destructible)
+    pub fn health(&self) -> f32 {
+        match self {
+            BreakableKind::Urn => 10.0,
+            BreakableKind::Barrel => 30.0,
+            BreakableKind::BonePile => 18.0,
+        }
+    }
+
+    /// Радиус «тела» для дистанции удара
+    pub fn hit_radius(&self) -> f32 {
+        match self {
+            BreakableKind::Urn => 0.35,
+            BreakableKind::Barrel => 0.45,
+            BreakableKind::BonePile => 0.6,
+        }
+    }
+}
+
+/// Разбиваемый контейнер — прочность в Destructible, автоатака бьёт его, когда врагов рядом нет
+#[derive(Component)]
+pub struct Breakable {
+    pub kind: BreakableKind,
+}
+
+/// Обломок разбитого контейнера: летит по физике, в конце тает
+#[derive(Component)]
+pub struct BreakableDebris {
+    pub timer: Timer,
+    pub base_scale: Vec3,
+}
+
+/// Что выпало из контейнера
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PickupKind {
+    Gold(u32),
+    /// Временная находка — бафф как у святилища
+    Buff(BuffKind),
+}
+
+/// Находка на полу (SpatialLayer::Pickup): золото тянет магнит, бафф — подобрать вплотную.
+/// Не подобранная за срок — мигает и исчезает.
+#[derive(Component)]
+pub struct LootPickup {
+    pub kind: PickupKind,
+    pub age: f32,
+    /// Летит к герою (золото в радиусе магнита)
+    pub magnetized: bool,
+    /// Высота покоя — над ней покачивается
+    pub rest_y: f32,
+}
+
+/// Золото, собранное за забег
+#[derive(Resource, Default)]
+pub struct RunGold {
+    pub amount: u32,
+}
+
+/// Волна, к которой россыпь контейнеров уже пополнена
+#[derive(Resource, Default)]
+pub struct BreakableScatter {
+    pub wave: Option<u32>,
+}
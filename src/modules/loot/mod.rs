@@ -0,0 +1,6 @@
+pub mod components;
+pub mod plugin;
+pub(crate) mod parts;
+
+pub use plugin::LootPlugin;
+pub use components::{Breakable, BreakableKind, LootPickup, PickupKind, RunGold};
@@ -0,0 +1,186 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use crate::modules::player::components::Player;
+use crate::modules::combat::components::Destructible;
+use crate::modules::enemies::components::WaveState;
+use crate::modules::enemies::FlowField;
+use crate::modules::hazards::HazardZone;
+use crate::modules::progression::parts::orb_assets::OrbAssets;
+use crate::modules::world::{BreakablesDef, CurrentArena, SafeZone};
+use crate::modules::loot::components::{Breakable, BreakableKind, BreakableScatter, BreakableDebris, LootPickup, RunGold};
+use crate::shared::rand_01;
+use super::loot_assets::LootAssets;
+use super::debris::spawn_debris;
+use super::pickups::drop_loot;
+
+/// Отступ от стен арены (м)
+const EDGE_MARGIN: f32 = 3.0;
+/// Не ставим контейнер вплотную к герою (м)
+const PLAYER_CLEARANCE: f32 = 4.0;
+/// Попыток на один контейнер
+const ATTEMPTS_PER_CONTAINER: usize = 20;
+
+/// Ограничения расстановки: проходимость по flow field, безопасная зона, опасные зоны и герой
+#[derive(SystemParam)]
+pub struct PlacementRules<'w, 's> {
+    flow: Res<'w, FlowField>,
+    zone: Res<'w, SafeZone>,
+    hazards: Query<'w, 's, (&'static Transform, &'static HazardZone)>,
+    player: Query<'w, 's, &'static Transform, With<Player>>,
+}
+
+/// К каждой волне пополняет россыпь контейнеров до нормы арены: точка проходима
+/// (flow field ведёт к герою), внутри безопасной зоны, вне опасных зон
+pub fn scatter_containers_system(
+    mut commands: Commands,
+    wave: Res<WaveState>,
+    mut scatter: ResMut<BreakableScatter>,
+    arena: Option<Res<CurrentArena>>,
+    loot_assets: Option<Res<LootAssets>>,
+    rules: PlacementRules,
+    existing: Query<&Transform, With<Breakable>>,
+) {
+    let PlacementRules { flow, zone, hazards, player } = rules;
+    if scatter.wave == Some(wave.current_wave) {
+        return;
+    }
+    let (Some(arena), Some(assets)) = (arena, loot_assets) else { return };
+    let Some(def) = &arena.breakables else { return };
+    // Проходимость проверяем по готовому полю — первое считается пару кадров после входа
+    if !flow.is_ready() {
+        return;
+    }
+    let Ok(player_tf) = player.single() else { return };
+    scatter.wave = Some(wave.current_wave);
+
+    let mut placed: Vec<Vec3> = existing.iter().map(|t| t.translation).collect();
+    let missing = def.count.saturating_sub(placed.len());
+    let bounds = &arena.bounds;
+    let mut spawned = 0;
+
+    for _ in 0..missing * ATTEMPTS_PER_CONTAINER {
+        if spawned == missing {
+            break;
+        }
+        let point = Vec3::new(
+            bounds.min.x + EDGE_MARGIN + rand_01() * (bounds.max.x - bounds.min.x - EDGE_MARGIN * 2.0),
+            0.0,
+            bounds.min.y + EDGE_MARGIN + rand_01() * (bounds.max.y - bounds.min.y - EDGE_MARGIN * 2.0),
+        );
+        if point.xz().distance(player_tf.translation.xz()) < PLAYER_CLEARANCE
+            || !zone.contains(point)
+            || flow.is_blocked(point)
+            || flow.sample(point).is_none()
+            || placed.iter().any(|p| p.xz().distance(point.xz()) < def.min_distance)
+            || hazards.iter().any(|(t, hazard)| t.translation.xz().distance(point.xz()) < hazard.radius + 1.0)
+        {
+            continue;
+        }
+        spawn_container(&mut commands, &assets, pick_kind(def), point);
+        placed.push(point);
+        spawned += 1;
+    }
+
+    if spawned > 0 {
+        debug!("🏺 Wave {}: {} containers placed ({} total)", wave.current_wave, spawned, placed.len());
+    }
+}
+
+/// Вид по весам арены
+fn pick_kind(def: &BreakablesDef) -> BreakableKind {
+    let weights = [
+        (BreakableKind::Urn, def.urn.max(0.0)),
+        (BreakableKind::Barrel, def.barrel.max(0.0)),
+        (BreakableKind::BonePile, def.bones.max(0.0)),
+    ];
+    let total: f32 = weights.iter().map(|&(_, w)| w).sum();
+    let mut roll = rand_01() * total;
+    for (kind, w) in weights {
+        if roll < w {
+            return kind;
+        }
+        roll -= w;
+    }
+    BreakableKind::Urn
+}
+
+/// Спавнит контейнер на полу: без коллайдера (не мешает толпе и flow field), прочность — Destructible
+fn spawn_container(commands: &mut Commands, assets: &LootAssets, kind: BreakableKind, pos: Vec3) {
+    let rotation = Quat::from_rotation_y(rand_01() * std::f32::consts::TAU);
+    let root = commands.spawn((
+        Breakable { kind },
+        Destructible::new(kind.health(), kind.hit_radius()),
+        Transform::from_translation(pos).with_rotation(rotation),
+        Visibility::default(),
+    )).id();
+
+    commands.entity(root).with_children(|parent| match kind {
+        BreakableKind::Urn => {
+            parent.spawn((
+                Mesh3d(assets.urn_body_mesh.clone()),
+                MeshMaterial3d(assets.urn_material.clone()),
+                Transform::from_xyz(0.0, 0.32, 0.0).with_scale(Vec3::new(1.0, 1.2, 1.0)),
+            ));
+            parent.spawn((
+                Mesh3d(assets.urn_neck_mesh.clone()),
+                MeshMaterial3d(assets.urn_material.clone()),
+                Transform::from_xyz(0.0, 0.78, 0.0),
+            ));
+        }
+        BreakableKind::Barrel => {
+            parent.spawn((
+                Mesh3d(assets.barrel_mesh.clone()),
+                MeshMaterial3d(assets.barrel_material.clone()),
+                Transform::from_xyz(0.0, 0.425, 0.0),
+            ));
+            for y in [0.18, 0.67] {
+                parent.spawn((
+                    Mesh3d(assets.barrel_hoop_mesh.clone()),
+                    MeshMaterial3d(assets.hoop_material.clone()),
+                    Transform::from_xyz(0.0, y, 0.0),
+                ));
+            }
+        }
+        BreakableKind::BonePile => {
+            parent.spawn((
+                SceneRoot(assets.bone_scene.clone()),
+                Transform::from_scale(Vec3::splat(1.1)),
+            ));
+        }
+    });
+}
+
+/// Разбитый контейнер: обломки по физике + добыча из таблицы вида
+pub fn break_containers_system(
+    mut commands: Commands,
+    loot_assets: Option<Res<LootAssets>>,
+    orb_assets: Option<Res<OrbAssets>>,
+    containers: Query<(Entity, &Breakable, &Destructible, &GlobalTransform)>,
+) {
+    let (Some(loot_assets), Some(orb_assets)) = (loot_assets, orb_assets) else { return };
+
+    for (entity, breakable, health, transform) in &containers {
+        if !health.is_broken() {
+            continue;
+        }
+        let pos = transform.translation();
+        commands.entity(entity).despawn();
+        spawn_debris(&mut commands, &loot_assets, breakable.kind, pos);
+        drop_loot(&mut commands, &loot_assets, &orb_assets, breakable.kind, pos);
+        debug!("🏺 {:?} broken at ({:.1}, {:.1})", breakable.kind, pos.x, pos.z);
+    }
+}
+
+/// OnEnter(Playing): контейнеры, обломки и находки прошлого забега убираем, золото — с нуля
+pub fn reset_loot(
+    mut commands: Commands,
+    mut gold: ResMut<RunGold>,
+    mut scatter: ResMut<BreakableScatter>,
+    leftovers: Query<Entity, Or<(With<Breakable>, With<BreakableDebris>, With<LootPickup>)>>,
+) {
+    for entity in &leftovers {
+        commands.entity(entity).despawn();
+    }
+    *gold = RunGold::default();
+    *scatter = BreakableScatter::default();
+}
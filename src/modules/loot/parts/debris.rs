@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use avian3d::prelude::*;
+use crate::modules::loot::components::{BreakableDebris, BreakableKind};
+use crate::shared::rand_01;
+use super::loot_assets::LootAssets;
+
+/// Сколько секунд обломки лежат на полу
+const DEBRIS_LIFETIME: f32 = 2.5;
+/// Последние секунды — тают (масштаб → 0)
+const DEBRIS_FADE: f32 = 0.6;
+
+/// Разлёт обломков: вверх и в стороны, с вращением; сталкиваются только со статикой
+pub fn spawn_debris(commands: &mut Commands, assets: &LootAssets, kind: BreakableKind, pos: Vec3) {
+    let (count, material, scale) = match kind {
+        BreakableKind::Urn => (7, &assets.urn_material, Vec3::ONE),
+        BreakableKind::Barrel => (8, &assets.barrel_material, Vec3::new(0.8, 0.8, 2.2)), // доски
+        BreakableKind::BonePile => (6, &assets.bone_material, Vec3::new(0.5, 0.6, 2.0)), // кости
+    };
+
+    for _ in 0..count {
+        let angle = rand_01() * std::f32::consts::TAU;
+        let outward = Vec3::new(angle.cos(), 0.0, angle.sin());
+        let velocity = outward * (1.5 + rand_01() * 2.5) + Vec3::Y * (3.0 + rand_01() * 2.5);
+        let spin = Vec3::new(rand_01() - 0.5, rand_01() - 0.5, rand_01() - 0.5) * 16.0;
+
+        commands.spawn((
+            Mesh3d(assets.shard_mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_translation(pos + Vec3::Y * 0.4 + outward * 0.2)
+                .with_rotation(Quat::from_rotation_y(angle))
+                .with_scale(scale),
+            RigidBody::Dynamic,
+            Collider::cuboid(0.18 * scale.x, 0.08 * scale.y, 0.14 * scale.z),
+            crate::shared::GameLayer::debris_layers(),
+            LinearVelocity(velocity),
+            AngularVelocity(spin),
+            BreakableDebris {
+                timer: Timer::from_seconds(DEBRIS_LIFETIME + rand_01() * 0.5, TimerMode::Once),
+                base_scale: scale,
+            },
+        ));
+    }
+}
+
+/// Обломки тают под конец и исчезают
+pub fn debris_fade_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut debris: Query<(Entity, &mut BreakableDebris, &mut Transform)>,
+) {
+    for (entity, mut piece, mut transform) in &mut debris {
+        piece.timer.tick(time.delta());
+        let left = piece.timer.remaining_secs();
+        if left < DEBRIS_FADE {
+            transform.scale = piece.base_scale * (left / DEBRIS_FADE).max(0.01);
+        }
+        if piece.timer.is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use crate::modules::shrines::BuffKind;
+use crate::toolkit::asset_paths;
+
+/// Кэшированные ассеты контейнеров и находок (создаются один раз на OnEnter Playing)
+#[derive(Resource)]
+pub struct LootAssets {
+    /// Урна: пузатое тело + горлышко
+    pub urn_body_mesh: Handle<Mesh>,
+    pub urn_neck_mesh: Handle<Mesh>,
+    pub urn_material: Handle<StandardMaterial>,
+    pub barrel_mesh: Handle<Mesh>,
+    pub barrel_hoop_mesh: Handle<Mesh>,
+    pub barrel_material: Handle<StandardMaterial>,
+    pub hoop_material: Handle<StandardMaterial>,
+    pub bone_scene: Handle<Scene>,
+    pub bone_material: Handle<StandardMaterial>,
+    /// Обломок — общий меш, материал по виду контейнера
+    pub shard_mesh: Handle<Mesh>,
+    pub coin_mesh: Handle<Mesh>,
+    pub gold_material: Handle<StandardMaterial>,
+    pub pickup_mesh: Handle<Mesh>,
+    /// Светящиеся сферы находок — по порядку BuffKind::ALL
+    pub pickup_materials: Vec<Handle<StandardMaterial>>,
+}
+
+impl LootAssets {
+    pub fn pickup_material(&self, kind: BuffKind) -> Handle<StandardMaterial> {
+        let index = BuffKind::ALL.iter().position(|&k| k == kind).unwrap_or(0);
+        self.pickup_materials[index].clone()
+    }
+}
+
+/// Инициализация ассетов контейнеров и находок
+pub fn init_loot_assets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Урна: обожжённая глина
+    let urn_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.55, 0.3, 0.18),
+        perceptual_roughness: 0.85,
+        ..default()
+    });
+    // Бочка: тёмное дерево, железные обручи
+    let barrel_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.35, 0.22, 0.12),
+        perceptual_roughness: 0.9,
+        ..default()
+    });
+    let hoop_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.2, 0.2, 0.22),
+        metallic: 0.7,
+        perceptual_roughness: 0.5,
+        ..default()
+    });
+    // Кости: обломки под цвет модели bone_pile
+    let bone_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.82, 0.78, 0.66),
+        perceptual_roughness: 0.8,
+        ..default()
+    });
+    // Золото: тёплый блеск, видно в тёмных биомах
+    let gold_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(1.0, 0.8, 0.25),
+        emissive: LinearRgba::new(2.0, 1.4, 0.3, 1.0),
+        metallic: 0.9,
+        perceptual_roughness: 0.3,
+        ..default()
+    });
+    let pickup_materials = BuffKind::ALL.iter().map(|kind| {
+        let color = kind.color().to_linear();
+        materials.add(StandardMaterial {
+            base_color: kind.color(),
+            emissive: LinearRgba::new(color.red * 4.0, color.green * 4.0, color.blue * 4.0, 1.0),
+            unlit: true,
+            ..default()
+        })
+    }).collect();
+
+    commands.insert_resource(LootAssets {
+        urn_body_mesh: meshes.add(Sphere::new(0.32)),
+        urn_neck_mesh: meshes.add(Cylinder::new(0.13, 0.22)),
+        urn_material,
+        barrel_mesh: meshes.add(Cylinder::new(0.38, 0.85)),
+        barrel_hoop_mesh: meshes.add(Torus::new(0.36, 0.41)),
+        barrel_material,
+        hoop_material,
+        bone_scene: asset_server.load(asset_paths::BONE_PILE_MODEL),
+        bone_material,
+        shard_mesh: meshes.add(Cuboid::new(0.18, 0.08, 0.14)),
+        coin_mesh: meshes.add(Cylinder::new(0.14, 0.04)),
+        gold_material,
+        pickup_mesh: meshes.add(Sphere::new(0.22)),
+        pickup_materials,
+    });
+}
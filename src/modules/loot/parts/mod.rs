@@ -0,0 +1,10 @@
+//! Реализация разбиваемых контейнеров и добычи:
+//! - loot_assets: меши и материалы урн, бочек, обломков и находок
+//! - containers: россыпь контейнеров к каждой волне (проходимые точки, вне опасных зон), разбивание
+//! - debris: физические обломки разбитого контейнера
+//! - pickups: таблицы добычи, золото и временные находки (SpatialLayer::Pickup), подбор
+
+pub mod loot_assets;
+pub mod containers;
+pub mod debris;
+pub mod pickups;
@@ -0,0 +1,191 @@
+use bevy::prelude::*;
+use crate::modules::player::components::Player;
+use crate::modules::progression::components::PlayerXp;
+use crate::modules::progression::parts::orb_assets::OrbAssets;
+use crate::modules::progression::parts::orbs::{spawn_hp_orb, spawn_xp_orb};
+use crate::modules::shrines::{ActiveBuffs, BuffKind};
+use crate::modules::menu::KillFeedMessage;
+use crate::modules::spatial::{SpatialIndex, SpatialIndexed, SpatialLayer};
+use crate::modules::loot::components::{BreakableKind, LootPickup, PickupKind, RunGold};
+use crate::shared::rand_01;
+use super::loot_assets::LootAssets;
+
+/// Находка лежит столько секунд, последние PICKUP_BLINK — мигает
+const PICKUP_LIFETIME: f32 = 15.0;
+const PICKUP_BLINK: f32 = 3.0;
+/// Подбор вплотную (м) — временные находки магнит не тянет
+const PICKUP_RADIUS: f32 = 1.2;
+/// Золото в полёте к герою: скорость (м/с) и дистанция сбора
+const GOLD_FLY_SPEED: f32 = 14.0;
+const GOLD_COLLECT_DIST: f32 = 0.6;
+
+/// Что выпадает из контейнера
+#[derive(Clone, Copy)]
+enum LootRoll {
+    /// Несколько XP орбов по `value`
+    Xp { orbs: u32, value: f32 },
+    Heal(f32),
+    Gold { min: u32, max: u32 },
+    /// Случайный временный бафф
+    Pickup,
+}
+
+/// Таблицы добычи: (вес, что выпадает). Урна — опыт, бочка — золото, кости — опыт и лечение
+const URN_LOOT: &[(f32, LootRoll)] = &[
+    (0.45, LootRoll::Xp { orbs: 3, value: 5.0 }),
+    (0.3, LootRoll::Gold { min: 2, max: 5 }),
+    (0.1, LootRoll::Heal(10.0)),
+    (0.15, LootRoll::Pickup),
+];
+const BARREL_LOOT: &[(f32, LootRoll)] = &[
+    (0.5, LootRoll::Gold { min: 4, max: 9 }),
+    (0.25, LootRoll::Xp { orbs: 2, value: 6.0 }),
+    (0.1, LootRoll::Heal(15.0)),
+    (0.15, LootRoll::Pickup),
+];
+const BONE_LOOT: &[(f32, LootRoll)] = &[
+    (0.5, LootRoll::Xp { orbs: 4, value: 4.0 }),
+    (0.2, LootRoll::Heal(10.0)),
+    (0.15, LootRoll::Gold { min: 1, max: 3 }),
+    (0.15, LootRoll::Pickup),
+];
+
+/// Бросок по таблице вида контейнера → орбы / золото / находка на полу
+pub fn drop_loot(
+    commands: &mut Commands,
+    assets: &LootAssets,
+    orb_assets: &OrbAssets,
+    kind: BreakableKind,
+    pos: Vec3,
+) {
+    let table = match kind {
+        BreakableKind::Urn => URN_LOOT,
+        BreakableKind::Barrel => BARREL_LOOT,
+        BreakableKind::BonePile => BONE_LOOT,
+    };
+    let total: f32 = table.iter().map(|&(w, _)| w).sum();
+    let mut roll = rand_01() * total;
+    let mut pick = table[0].1;
+    for &(w, loot) in table {
+        if roll < w {
+            pick = loot;
+            break;
+        }
+        roll -= w;
+    }
+
+    match pick {
+        LootRoll::Xp { orbs, value } => {
+            for _ in 0..orbs {
+                spawn_xp_orb(commands, orb_assets, pos, value);
+            }
+        }
+        LootRoll::Heal(amount) => spawn_hp_orb(commands, orb_assets, pos, amount),
+        LootRoll::Gold { min, max } => {
+            let amount = min + (rand_01() * (max - min + 1) as f32) as u32;
+            spawn_pickup(commands, assets, PickupKind::Gold(amount.min(max)), pos);
+        }
+        LootRoll::Pickup => {
+            let index = ((rand_01() * BuffKind::ALL.len() as f32) as usize).min(BuffKind::ALL.len() - 1);
+            spawn_pickup(commands, assets, PickupKind::Buff(BuffKind::ALL[index]), pos);
+        }
+    }
+}
+
+fn spawn_pickup(commands: &mut Commands, assets: &LootAssets, kind: PickupKind, pos: Vec3) {
+    let (mesh, material, rest_y) = match kind {
+        PickupKind::Gold(_) => (assets.coin_mesh.clone(), assets.gold_material.clone(), 0.3),
+        PickupKind::Buff(buff) => (assets.pickup_mesh.clone(), assets.pickup_material(buff), 0.5),
+    };
+    commands.spawn((
+        Mesh3d(mesh),
+        MeshMaterial3d(material),
+        Transform::from_translation(Vec3::new(pos.x, rest_y, pos.z)),
+        LootPickup { kind, age: 0.0, magnetized: false, rest_y },
+        SpatialIndexed(SpatialLayer::Pickup),
+    ));
+}
+
+/// Подбор из SpatialIndex: золото в радиусе магнита начинает лететь к герою,
+/// временная находка — сразу, если герой встал вплотную
+pub fn pickup_collect_system(
+    mut commands: Commands,
+    index: Res<SpatialIndex>,
+    player_xp: Res<PlayerXp>,
+    player: Query<&Transform, With<Player>>,
+    mut pickups: Query<&mut LootPickup>,
+    mut buffs: ResMut<ActiveBuffs>,
+    mut feed: MessageWriter<KillFeedMessage>,
+) {
+    let Ok(player_tf) = player.single() else { return };
+    let player_pos = player_tf.translation;
+    let radius = player_xp.magnet_radius.max(PICKUP_RADIUS);
+
+    index.for_each_in_radius(SpatialLayer::Pickup, player_pos, radius, |entity, pos| {
+        let Ok(mut pickup) = pickups.get_mut(entity) else { return };
+        match pickup.kind {
+            PickupKind::Gold(_) => {
+                pickup.magnetized = true;
+                commands.entity(entity).remove::<SpatialIndexed>();
+            }
+            PickupKind::Buff(kind) => {
+                if pos.xz().distance(player_pos.xz()) > PICKUP_RADIUS {
+                    return;
+                }
+                buffs.grant(kind);
+                feed.write(KillFeedMessage {
+                    text: format!("Находка: {}!", kind.name()),
+                    color: kind.color(),
+                    group_key: None,
+                });
+                commands.entity(entity).despawn();
+                info!("✨ Pickup collected: {}", kind.name());
+            }
+        }
+    });
+}
+
+/// Находки покачиваются и вращаются, в конце срока мигают и исчезают;
+/// притянутое золото летит к герою и засчитывается
+pub fn pickup_motion_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut gold: ResMut<RunGold>,
+    player: Query<&Transform, With<Player>>,
+    mut pickups: Query<(Entity, &mut LootPickup, &mut Transform, &mut Visibility), Without<Player>>,
+) {
+    let dt = time.delta_secs();
+    if dt == 0.0 { return; } // Игра на паузе
+    let player_pos = player.single().map_or(Vec3::ZERO, |t| t.translation);
+
+    for (entity, mut pickup, mut transform, mut visibility) in &mut pickups {
+        pickup.age += dt;
+
+        if pickup.magnetized {
+            // Притянуто во время мигания — в полёте всегда видно
+            visibility.set_if_neq(Visibility::Inherited);
+            let target = player_pos + Vec3::Y * 0.5;
+            let to_player = target - transform.translation;
+            if to_player.length() < GOLD_COLLECT_DIST {
+                if let PickupKind::Gold(amount) = pickup.kind {
+                    gold.amount += amount;
+                }
+                commands.entity(entity).despawn();
+                continue;
+            }
+            transform.translation += to_player.normalize() * (GOLD_FLY_SPEED * dt).min(to_player.length());
+            continue;
+        }
+
+        transform.translation.y = pickup.rest_y + (pickup.age * 3.0).sin() * 0.08;
+        transform.rotate_y(dt * 2.5);
+
+        let left = PICKUP_LIFETIME - pickup.age;
+        if left <= 0.0 {
+            commands.entity(entity).despawn();
+        } else if left < PICKUP_BLINK {
+            let on = (left * 8.0).sin() > 0.0;
+            visibility.set_if_neq(if on { Visibility::Inherited } else { Visibility::Hidden });
+        }
+    }
+}
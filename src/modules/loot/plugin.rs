@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+use crate::shared::GameState;
+use crate::modules::spatial::SpatialIndexSet;
+use super::components::{BreakableScatter, RunGold};
+use super::parts::{loot_assets, containers, debris, pickups};
+
+pub struct LootPlugin;
+
+impl Plugin for LootPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<RunGold>()
+            .init_resource::<BreakableScatter>()
+            .add_systems(OnEnter(GameState::Playing), (
+                loot_assets::init_loot_assets,
+                containers::reset_loot,
+            ))
+            .add_systems(Update, (
+                containers::scatter_containers_system,
+                containers::break_containers_system,
+                debris::debris_fade_system,
+                pickups::pickup_collect_system
+                    .after(SpatialIndexSet),
+                pickups::pickup_motion_system
+                    .after(pickups::pickup_collect_system),
+            ).run_if(in_state(GameState::Playing)));
+
+        info!("🏺 LootPlugin loaded (breakable containers, debris, gold and pickups)");
+    }
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use crate::modules::shrines::BuffKind;
 
 /// Маркер для всех entity Title Screen (для bulk despawn)
 #[derive(Component)]
@@ -32,6 +33,14 @@ pub struct MenuButton;
 #[derive(Component)]
 pub struct FpsText;
 
+/// Кнопка выбора биома на титульном экране (индекс в BIOMES)
+#[derive(Component)]
+pub struct BiomeOption(pub usize);
+
+/// Кнопка выбора режима на титульном экране (индекс в GAME_MODES)
+#[derive(Component)]
+pub struct ModeOption(pub usize);
+
 /// Маркер для текста с пульсирующей прозрачностью
 #[derive(Component)]
 pub struct PulsingText;
@@ -56,6 +65,14 @@ pub struct HpBarText;
 #[derive(Component)]
 pub struct XpBarFill;
 
+/// Шкала ярости (Button: тап по полной шкале — ульта)
+#[derive(Component)]
+pub struct RageGauge;
+
+/// Маркер для заполненной части шкалы ярости
+#[derive(Component)]
+pub struct RageBarFill;
+
 /// Маркер для текста уровня
 #[derive(Component)]
 pub struct LevelText;
@@ -68,12 +85,24 @@ pub struct UpgradeBarContainer;
 #[derive(Component)]
 pub struct UpgradeIcon;
 
+/// Иконка баффа святилища (по одной на вид, скрыта, пока бафф не активен)
+#[derive(Component)]
+pub struct BuffIcon(pub BuffKind);
+
+/// Оставшееся время баффа под иконкой
+#[derive(Component)]
+pub struct BuffTimerText(pub BuffKind);
+
 /// Маркер индикатора на краю экрана (пул из 8 штук)
 #[derive(Component)]
 pub struct EdgeIndicator {
     pub index: u8,
 }
 
+/// Индикатор на краю экрана: направление к безопасной зоне, пока герой в тумане Нави
+#[derive(Component)]
+pub struct SafeZoneIndicator;
+
 /// Контейнер для kill feed записей (правый край экрана)
 #[derive(Component)]
 pub struct KillFeedContainer;
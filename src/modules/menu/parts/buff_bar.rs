@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use crate::modules::menu::components::*;
+use crate::modules::shrines::{ActiveBuffs, BuffKind};
+
+/// Иконка баффа (скрыта): цветной квадрат с меткой + секунды до конца под ним
+pub fn spawn_buff_icon(parent: &mut ChildSpawnerCommands, kind: BuffKind, font: &Handle<Font>) {
+    let color = kind.color();
+    parent.spawn((
+        HudUI,
+        BuffIcon(kind),
+        Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(2.0),
+            ..default()
+        },
+        Visibility::Hidden,
+    )).with_children(|icon| {
+        icon.spawn((
+            HudUI,
+            Node {
+                width: Val::Px(30.0),
+                height: Val::Px(30.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(1.5)),
+                border_radius: BorderRadius::all(Val::Percent(50.0)),
+                ..default()
+            },
+            BackgroundColor(color.with_alpha(0.35)),
+            BorderColor::all(color),
+        )).with_child((
+            HudUI,
+            Text::new(kind.label()),
+            TextFont {
+                font: font.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            TextShadow {
+                offset: Vec2::new(1.0, 1.0),
+                color: Color::srgba(0.0, 0.0, 0.0, 0.9),
+            },
+        ));
+        icon.spawn((
+            HudUI,
+            BuffTimerText(kind),
+            Text::new(""),
+            TextFont {
+                font: font.clone(),
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(color),
+            TextShadow {
+                offset: Vec2::new(1.0, 1.0),
+                color: Color::srgba(0.0, 0.0, 0.0, 0.9),
+            },
+        ));
+    });
+}
+
+/// Показывает иконки активных баффов и оставшиеся секунды
+pub fn update_buff_bar(
+    buffs: Res<ActiveBuffs>,
+    mut icons: Query<(&BuffIcon, &mut Visibility)>,
+    mut timers: Query<(&BuffTimerText, &mut Text)>,
+) {
+    for (icon, mut visibility) in &mut icons {
+        let target = if buffs.has(icon.0) { Visibility::Inherited } else { Visibility::Hidden };
+        visibility.set_if_neq(target);
+    }
+    for (timer_text, mut text) in &mut timers {
+        let Some(buff) = buffs.get(timer_text.0) else { continue };
+        let secs = buff.timer.remaining_secs().ceil() as u32;
+        let label = format!("{}с", secs);
+        if text.0 != label {
+            text.0 = label;
+        }
+    }
+}
@@ -3,10 +3,12 @@ use bevy::ui::UiScale;
 use crate::modules::menu::components::*;
 use crate::modules::enemies::components::{Enemy, EnemyDying};
 use crate::modules::player::components::Player;
+use crate::modules::world::SafeZone;
 
 const MAX_INDICATORS: usize = 8;
 const INDICATOR_SIZE: f32 = 16.0;
 const SCREEN_MARGIN: f32 = 8.0;
+const SAFE_ZONE_INDICATOR_SIZE: f32 = 22.0;
 
 /// Спавнит пул из MAX_INDICATORS скрытых индикаторов на краях экрана
 pub fn setup_edge_indicators(mut commands: Commands) {
@@ -118,6 +120,71 @@ pub fn update_edge_indicators(
     }
 }
 
+/// Спавнит скрытый индикатор безопасной зоны (HUD, снимается вместе с ним)
+pub fn setup_safe_zone_indicator(mut commands: Commands) {
+    commands.spawn((
+        HudUI,
+        SafeZoneIndicator,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(SAFE_ZONE_INDICATOR_SIZE),
+            height: Val::Px(SAFE_ZONE_INDICATOR_SIZE),
+            border: UiRect::all(Val::Px(2.0)),
+            border_radius: BorderRadius::all(Val::Percent(50.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.6, 0.3, 0.9, 0.5)),
+        BorderColor::all(Color::srgba(0.85, 0.65, 1.0, 0.95)),
+        Visibility::Hidden,
+        GlobalZIndex(11),
+    ));
+}
+
+/// Герой в тумане за границей зоны — индикатор на краю экрана в сторону её центра (пульсирует)
+pub fn update_safe_zone_indicator(
+    time: Res<Time>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    player_query: Query<&Transform, With<Player>>,
+    zone: Res<SafeZone>,
+    mut indicators: Query<(&mut Node, &mut Visibility, &mut BackgroundColor), With<SafeZoneIndicator>>,
+    ui_scale: Res<UiScale>,
+    windows: Query<&Window>,
+) {
+    let Ok((mut node, mut visibility, mut background)) = indicators.single_mut() else { return };
+    let Ok(player_tf) = player_query.single() else { return };
+    let player_pos = player_tf.translation;
+    if !zone.is_bounded() || zone.contains(player_pos) {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+    let Ok((camera, cam_transform)) = camera_query.single() else { return };
+    let Ok(window) = windows.single() else { return };
+    let scale = ui_scale.0;
+    let scale = if scale < 0.01 { 1.0 } else { scale };
+    let screen_w = window.width() / scale;
+    let screen_h = window.height() / scale;
+    let center = Vec2::new(screen_w / 2.0, screen_h / 2.0);
+
+    // Направление к центру зоны через проекцию близкой точки — как у индикаторов врагов
+    let dir = (Vec3::new(zone.center.x, player_pos.y, zone.center.y) - player_pos).normalize_or_zero();
+    let Ok(screen_pos) = camera.world_to_viewport(cam_transform, player_pos + dir * 2.0) else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+    let dir_2d = (screen_pos / scale - center).normalize_or_zero();
+    if dir_2d.length() < 0.001 {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+
+    let (x, y) = clamp_to_screen_edge(center, dir_2d, screen_w, screen_h, SCREEN_MARGIN);
+    node.left = Val::Px(x - SAFE_ZONE_INDICATOR_SIZE / 2.0);
+    node.top = Val::Px(y - SAFE_ZONE_INDICATOR_SIZE / 2.0);
+    let alpha = 0.35 + 0.3 * (time.elapsed_secs() * 6.0).sin().abs();
+    background.0 = Color::srgba(0.6, 0.3, 0.9, alpha);
+    *visibility = Visibility::Inherited;
+}
+
 /// Clamp точки в направлении dir от center на край экрана с margin
 fn clamp_to_screen_edge(
     center: Vec2,
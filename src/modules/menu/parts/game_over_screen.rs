@@ -2,11 +2,13 @@ use bevy::prelude::*;
 use crate::shared::GameState;
 use crate::modules::menu::components::*;
 use crate::modules::menu::parts::fade_transition::FadeState;
-use crate::modules::combat::parts::game_over::KillCount;
+use crate::modules::combat::parts::game_over::{KillCount, RunOutcome};
 use crate::modules::combat::parts::game_timer::GameTimer;
 use crate::modules::enemies::components::WaveState;
 use crate::modules::progression::components::{PlayerXp, UpgradeInventory, UpgradeCategory};
 use crate::modules::progression::parts::upgrades;
+use crate::modules::shrines::{BuffKind, ShrineStats};
+use crate::modules::loot::RunGold;
 use crate::toolkit::asset_paths;
 
 /// Создаёт Game Over оверлей — кровавая виньетка поверх замёрзшей сцены
@@ -18,6 +20,9 @@ pub fn setup_game_over(
     game_timer: Res<GameTimer>,
     player_xp: Res<PlayerXp>,
     inventory: Res<UpgradeInventory>,
+    shrine_stats: Res<ShrineStats>,
+    outcome: Res<RunOutcome>,
+    gold: Res<RunGold>,
 ) {
     let font_title = asset_server.load(asset_paths::FONT_TITLE);
     let font_ui = asset_server.load(asset_paths::FONT_UI);
@@ -73,16 +78,21 @@ pub fn setup_game_over(
             }),
         ));
 
-        // "ВЫ ПАЛИ В БОЮ"
+        // "ВЫ ПАЛИ В БОЮ" / победа в Походе — "ЧЕРТОГ ОЧИЩЕН"
+        let (title, title_color) = if outcome.victory {
+            ("ЧЕРТОГ ОЧИЩЕН", Color::srgb(0.95, 0.78, 0.3))
+        } else {
+            ("ВЫ ПАЛИ В БОЮ", Color::srgb(0.85, 0.12, 0.08))
+        };
         parent.spawn((
             GameOverUI,
-            Text::new("ВЫ ПАЛИ В БОЮ"),
+            Text::new(title),
             TextFont {
                 font: font_title,
                 font_size: 64.0,
                 ..default()
             },
-            TextColor(Color::srgb(0.85, 0.12, 0.08)),
+            TextColor(title_color),
             TextShadow {
                 offset: Vec2::new(4.0, 4.0),
                 color: Color::srgba(0.0, 0.0, 0.0, 0.8),
@@ -143,6 +153,7 @@ pub fn setup_game_over(
                     ("Упыри", kill_count.upyr),
                     ("Лешие", kill_count.leshiy),
                     ("Волколаки", kill_count.volkolak),
+                    ("Колдуны", kill_count.koldun),
                 ];
                 for (name, count) in stats {
                     if count > 0 {
@@ -158,6 +169,32 @@ pub fn setup_game_over(
             });
         }
 
+        // Святилища: сколько раз и какие баффы (только если были)
+        if shrine_stats.activations > 0 {
+            let breakdown: Vec<String> = BuffKind::ALL.iter()
+                .filter(|kind| shrine_stats.by_kind[**kind as usize] > 0)
+                .map(|kind| format!("{} ×{}", kind.name(), shrine_stats.by_kind[*kind as usize]))
+                .collect();
+            parent.spawn((
+                GameOverUI,
+                Text::new(format!("Святилища: {} ({})", shrine_stats.activations, breakdown.join(", "))),
+                TextFont { font: font_ui.clone(), font_size: 20.0, ..default() },
+                TextColor(Color::srgb(0.6, 0.55, 0.5)),
+                TextShadow { offset: Vec2::new(1.0, 1.0), color: Color::srgba(0.0, 0.0, 0.0, 0.7) },
+            ));
+        }
+
+        // Золото из разбитых контейнеров (только если собрано)
+        if gold.amount > 0 {
+            parent.spawn((
+                GameOverUI,
+                Text::new(format!("Золото: {}", gold.amount)),
+                TextFont { font: font_ui.clone(), font_size: 20.0, ..default() },
+                TextColor(Color::srgb(0.95, 0.78, 0.3)),
+                TextShadow { offset: Vec2::new(1.0, 1.0), color: Color::srgba(0.0, 0.0, 0.0, 0.7) },
+            ));
+        }
+
         // Разделитель
         spawn_separator(parent);
 
@@ -238,8 +275,8 @@ pub fn setup_game_over(
         });
     });
 
-    info!("Game Over screen: wave {}, kills {} (upyr {}, leshiy {}, volkolak {}), level {}, time {}",
-        wave, kills, kill_count.upyr, kill_count.leshiy, kill_count.volkolak, player_xp.level, time_str);
+    info!("Game Over screen: wave {}, kills {} (upyr {}, leshiy {}, volkolak {}, koldun {}), level {}, time {}",
+        wave, kills, kill_count.upyr, kill_count.leshiy, kill_count.volkolak, kill_count.koldun, player_xp.level, time_str);
 }
 
 /// Золотой разделитель (transparent → gold → transparent)
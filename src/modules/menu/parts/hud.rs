@@ -2,13 +2,16 @@ use bevy::prelude::*;
 use crate::modules::menu::components::*;
 use crate::modules::combat::parts::game_over::KillCount;
 use crate::modules::combat::parts::game_timer::GameTimer;
-use crate::modules::combat::components::PlayerHealth;
+use crate::modules::combat::components::{PlayerHealth, Rage};
+use crate::modules::input::InputState;
 use crate::modules::enemies::components::WaveState;
 use crate::modules::player::components::Player;
 use crate::modules::progression::components::PlayerXp;
+use crate::modules::shrines::BuffKind;
 use crate::toolkit::asset_paths;
+use super::buff_bar::spawn_buff_icon;
 
-/// Создаёт HUD: волна (лево) + таймер/убийства (право) + HP bar + XP bar
+/// Создаёт HUD: волна (лево) + таймер/убийства (право) + HP bar + шкала ярости + XP bar
 pub fn setup_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
     let font_ui_bold = asset_server.load(asset_paths::FONT_UI_BOLD);
     let font_ui = asset_server.load(asset_paths::FONT_UI);
@@ -120,6 +123,35 @@ pub fn setup_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
                 },
             ));
 
+            // Rage gauge — шкала ярости; полная → тап запускает ульту
+            left.spawn((
+                HudUI,
+                RageGauge,
+                Button,
+                Node {
+                    width: Val::Px(176.0),
+                    height: Val::Px(10.0),
+                    margin: UiRect::left(Val::Px(20.0)),
+                    border: UiRect::all(Val::Px(1.0)),
+                    border_radius: BorderRadius::all(Val::Px(3.0)),
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.7)),
+                BorderColor::all(Color::srgba(0.5, 0.6, 0.9, 0.6)),
+            )).with_children(|gauge| {
+                gauge.spawn((
+                    HudUI,
+                    RageBarFill,
+                    Node {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.3, 0.45, 0.9)),
+                ));
+            });
+
             // Upgrade bar — иконки активных апгрейдов (заполняется при получении)
             left.spawn((
                 HudUI,
@@ -133,6 +165,21 @@ pub fn setup_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
                     ..default()
                 },
             ));
+
+            // Баффы святилищ — иконка + таймер, видны только активные
+            left.spawn((
+                HudUI,
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(6.0),
+                    margin: UiRect::top(Val::Px(6.0)),
+                    ..default()
+                },
+            )).with_children(|bar| {
+                for kind in BuffKind::ALL {
+                    spawn_buff_icon(bar, kind, &font_ui_bold);
+                }
+            });
         });
 
         // Right column: Таймер + Убийства (ниже миникарты)
@@ -314,6 +361,42 @@ pub fn update_hp_bar(
     }
 }
 
+/// Обновляет шкалу ярости: заливка по Changed<Rage>, полная — мерцает золотом
+pub fn update_rage_bar(
+    time: Res<Time>,
+    player_query: Query<Ref<Rage>, With<Player>>,
+    mut rage_fill: Query<(&mut Node, &mut BackgroundColor), With<RageBarFill>>,
+) {
+    let Ok(rage) = player_query.single() else { return };
+    let full = rage.is_full();
+    if !rage.is_changed() && !full {
+        return;
+    }
+
+    let color = if full {
+        let flicker = 0.75 + 0.25 * (time.elapsed_secs() * 8.0).sin();
+        Color::srgb(1.0 * flicker, 0.85 * flicker, 0.3)
+    } else {
+        Color::srgb(0.3, 0.45, 0.9)
+    };
+    for (mut node, mut bg) in &mut rage_fill {
+        node.width = Val::Percent(rage.fraction() * 100.0);
+        bg.0 = color;
+    }
+}
+
+/// Тап/клик по шкале ярости → запрос ульты (проверку полноты делает combat)
+pub fn rage_gauge_interaction(
+    mut input_state: ResMut<InputState>,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<RageGauge>)>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            input_state.ultimate_requested = true;
+        }
+    }
+}
+
 /// Обновляет XP bar (ширина fill + текст уровня) — только при изменении XP ресурса
 pub fn update_xp_bar(
     player_xp: Res<PlayerXp>,
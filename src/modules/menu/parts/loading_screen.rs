@@ -1,8 +1,11 @@
 use bevy::prelude::*;
-use bevy::asset::LoadState;
+use bevy::asset::{LoadState, RecursiveDependencyLoadState};
+use bevy::gltf::Gltf;
 use crate::shared::GameState;
 use crate::toolkit::asset_paths;
 use crate::modules::menu::parts::fade_transition::FadeState;
+use crate::modules::anim_clips::ALL_CLIP_TABLES;
+use crate::modules::world::{ActiveArena, ArenaScatter};
 
 /// Маркер для UI элементов экрана загрузки
 #[derive(Component)]
@@ -41,23 +44,20 @@ pub fn setup_loading_screen(
         asset_paths::UPYR_MODEL,
         asset_paths::LESHIY_MODEL,
         asset_paths::VOLKOLAK_MODEL,
-        asset_paths::CLIFF_WALL_A,
-        asset_paths::CLIFF_WALL_B,
-        asset_paths::CLIFF_WALL_C,
-        asset_paths::RUNE_STONE,
-        asset_paths::ROCK_LARGE,
-        asset_paths::DEAD_TREE,
-        asset_paths::BONE_PILE,
     ];
     for path in glb_paths {
         let h: Handle<Scene> = asset_server.load(*path);
         handles.push(h.untyped());
     }
 
+    // Корневые Gltf моделей с анимациями — named_animations нужны для поиска клипов по имени
+    for table in ALL_CLIP_TABLES {
+        let h: Handle<Gltf> = asset_server.load(table.glb);
+        handles.push(h.untyped());
+    }
+
     // Текстуры
     let texture_paths: &[&str] = &[
-        asset_paths::FLOOR_DIFF,
-        asset_paths::FLOOR_NORMAL,
         asset_paths::GAMEOVER_BG,
         asset_paths::PORTRAIT_BOGATYR,
         asset_paths::PORTRAIT_UPYR,
@@ -69,7 +69,8 @@ pub fn setup_loading_screen(
         handles.push(h.untyped());
     }
 
-    let total = handles.len();
+    // +1: арена (раскладка со всеми моделями и текстурами — см. update_loading_progress)
+    let total = handles.len() + 1;
     info!("📦 Предзагрузка {} ассетов", total);
 
     commands.insert_resource(AssetPreloader {
@@ -154,6 +155,8 @@ pub fn update_loading_progress(
     mut bar_query: Query<&mut Node, With<LoadingBarFill>>,
     mut text_query: Query<&mut Text, With<LoadingPercentText>>,
     mut fade: ResMut<FadeState>,
+    arena: Res<ActiveArena>,
+    scatter: Res<ArenaScatter>,
 ) {
     if preloader.done {
         return;
@@ -171,6 +174,15 @@ pub fn update_loading_progress(
         }
     }
 
+    // Арена готова, когда заспавнена, декор расставлен по seed и модели/текстуры догружены
+    if arena.instantiated && scatter.ready {
+        match asset_server.recursive_dependency_load_state(&arena.handle) {
+            RecursiveDependencyLoadState::Loaded => done_count += 1,
+            RecursiveDependencyLoadState::Failed(_) => { done_count += 1; failed_count += 1; },
+            _ => {},
+        }
+    }
+
     let progress = if preloader.total > 0 {
         done_count as f32 / preloader.total as f32
     } else {
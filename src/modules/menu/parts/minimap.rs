@@ -1,14 +1,19 @@
 use bevy::prelude::*;
 use crate::modules::menu::components::HudUI;
 use crate::modules::player::Player;
-use crate::modules::enemies::components::{Enemy, EnemyDying, SpawnPortal};
+use crate::modules::enemies::components::{SpawnPortal, SealedPortal, TemporaryRift};
+use crate::modules::spatial::{SpatialIndex, SpatialLayer};
+use crate::modules::world::{CurrentArena, SafeZone};
 use crate::toolkit::asset_paths;
 
 const MAP_SIZE: f32 = 100.0;
-const ARENA_EXTENT: f32 = 25.0; // Арена 50×50м, от -25 до +25
+/// Без раскладки арены — масштаб под 50×50м
+const DEFAULT_ARENA_EXTENT: f32 = 25.0;
 const PLAYER_DOT_SIZE: f32 = 6.0;
 const ENEMY_DOT_SIZE: f32 = 4.0;
 const PORTAL_DOT_SIZE: f32 = 4.0;
+/// Пул точек разломов: стартовые, открывшиеся по ходу забега и временные
+const MAX_PORTAL_DOTS: usize = 8;
 const DOT_POOL_SIZE: usize = 30;
 
 /// Маркер корневого контейнера миникарты
@@ -19,6 +24,10 @@ pub struct MinimapUI;
 #[derive(Component)]
 pub struct MinimapField;
 
+/// Граница безопасной зоны (туман Нави) — кольцо под точками
+#[derive(Component)]
+pub struct MinimapSafeZone;
+
 /// Точка на миникарте
 #[derive(Component)]
 pub struct MinimapDot {
@@ -46,6 +55,22 @@ pub fn setup_minimap(mut commands: Commands, asset_server: Res<AssetServer>) {
         },
     )).id();
 
+    // Граница безопасной зоны (child of field, под точками) — видна в режимах со сжатием
+    let safe_zone = commands.spawn((
+        HudUI,
+        MinimapSafeZone,
+        Node {
+            position_type: PositionType::Absolute,
+            border: UiRect::all(Val::Px(1.5)),
+            border_radius: BorderRadius::all(Val::Percent(50.0)),
+            ..default()
+        },
+        BorderColor::all(Color::srgba(0.7, 0.4, 1.0, 0.9)),
+        Visibility::Hidden,
+        GlobalZIndex(51),
+    )).id();
+    commands.entity(field).add_child(safe_zone);
+
     // Точка игрока (child of field)
     let player_dot = commands.spawn((
         HudUI,
@@ -87,7 +112,7 @@ pub fn setup_minimap(mut commands: Commands, asset_server: Res<AssetServer>) {
     }
 
     // Точки порталов (children of field)
-    for _ in 0..2 {
+    for _ in 0..MAX_PORTAL_DOTS {
         let dot = commands.spawn((
             HudUI,
             MinimapDot { dot_type: MinimapDotType::Portal },
@@ -143,21 +168,29 @@ pub fn setup_minimap(mut commands: Commands, asset_server: Res<AssetServer>) {
 }
 
 /// Проецирует world positions на миникарту. Игрок в центре, мир двигается.
+/// Враги берутся из SpatialIndex только в видимом круге — пул точек не тратится на дальних.
 pub fn update_minimap(
     player_query: Query<&Transform, With<Player>>,
-    enemies: Query<&Transform, (With<Enemy>, Without<EnemyDying>, Without<Player>)>,
-    portals: Query<&Transform, (With<SpawnPortal>, Without<Player>, Without<Enemy>)>,
+    index: Res<SpatialIndex>,
+    portals: Query<&Transform, (Or<(With<SpawnPortal>, With<TemporaryRift>)>, Without<SealedPortal>, Without<Player>)>,
     minimap_field: Query<&ComputedNode, With<MinimapField>>,
     mut dots: Query<(&MinimapDot, &mut Node, &mut Visibility)>,
+    mut enemy_positions: Local<Vec<Vec3>>,
+    arena: Option<Res<CurrentArena>>,
 ) {
     let Ok(player_tf) = player_query.single() else { return };
     let Ok(_field_node) = minimap_field.single() else { return };
 
     let player_pos = player_tf.translation;
     let half = MAP_SIZE / 2.0;
+    // Миникарта вмещает арену целиком: масштаб из границ раскладки
+    let extent = arena.map_or(DEFAULT_ARENA_EXTENT, |arena| arena.bounds.half_extent());
 
     // Собираем позиции (для пула)
-    let enemy_positions: Vec<Vec3> = enemies.iter().map(|t| t.translation).collect();
+    enemy_positions.clear();
+    index.for_each_in_radius(SpatialLayer::Enemy, player_pos, extent, |_, pos| {
+        enemy_positions.push(pos);
+    });
     let portal_positions: Vec<Vec3> = portals.iter().map(|t| t.translation).collect();
 
     let mut enemy_idx = 0usize;
@@ -174,7 +207,7 @@ pub fn update_minimap(
             MinimapDotType::Enemy => {
                 if enemy_idx < enemy_positions.len() {
                     let pos = enemy_positions[enemy_idx];
-                    let (mx, my) = world_to_minimap(pos, player_pos);
+                    let (mx, my) = world_to_minimap(pos, player_pos, extent);
                     let dx = mx - half;
                     let dy = my - half;
                     if dx * dx + dy * dy <= half * half {
@@ -192,7 +225,7 @@ pub fn update_minimap(
             MinimapDotType::Portal => {
                 if portal_idx < portal_positions.len() {
                     let pos = portal_positions[portal_idx];
-                    let (mx, my) = world_to_minimap(pos, player_pos);
+                    let (mx, my) = world_to_minimap(pos, player_pos, extent);
                     let dx = mx - half;
                     let dy = my - half;
                     if dx * dx + dy * dy <= half * half {
@@ -211,10 +244,35 @@ pub fn update_minimap(
     }
 }
 
+/// Кольцо безопасной зоны: центр и радиус в масштабе миникарты, за границей — туман
+pub fn update_minimap_safe_zone(
+    player_query: Query<&Transform, With<Player>>,
+    zone: Res<SafeZone>,
+    arena: Option<Res<CurrentArena>>,
+    mut rings: Query<(&mut Node, &mut Visibility), With<MinimapSafeZone>>,
+) {
+    let Ok((mut node, mut visibility)) = rings.single_mut() else { return };
+    let Ok(player_tf) = player_query.single() else { return };
+    if !zone.is_bounded() {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+
+    let extent = arena.map_or(DEFAULT_ARENA_EXTENT, |arena| arena.bounds.half_extent());
+    let center = Vec3::new(zone.center.x, 0.0, zone.center.y);
+    let (mx, my) = world_to_minimap(center, player_tf.translation, extent);
+    let size = zone.radius * 2.0 * MAP_SIZE / (extent * 2.0);
+    node.left = Val::Px(mx - size / 2.0);
+    node.top = Val::Px(my - size / 2.0);
+    node.width = Val::Px(size);
+    node.height = Val::Px(size);
+    *visibility = Visibility::Inherited;
+}
+
 /// Мировые координаты → координаты на миникарте (player-centered)
-fn world_to_minimap(world_pos: Vec3, player_pos: Vec3) -> (f32, f32) {
+fn world_to_minimap(world_pos: Vec3, player_pos: Vec3, extent: f32) -> (f32, f32) {
     let half = MAP_SIZE / 2.0;
-    let scale = MAP_SIZE / (ARENA_EXTENT * 2.0); // 100 / 50 = 2.0 px/m
+    let scale = MAP_SIZE / (extent * 2.0); // 100 / 50 = 2.0 px/m
 
     let dx = world_pos.x - player_pos.x;
     let dz = world_pos.z - player_pos.z;
@@ -228,7 +286,7 @@ fn world_to_minimap(world_pos: Vec3, player_pos: Vec3) -> (f32, f32) {
 /// Despawn миникарты при выходе из Playing
 pub fn cleanup_minimap(
     mut commands: Commands,
-    query: Query<Entity, Or<(With<MinimapUI>, With<MinimapDot>, With<MinimapField>)>>,
+    query: Query<Entity, Or<(With<MinimapUI>, With<MinimapDot>, With<MinimapField>, With<MinimapSafeZone>)>>,
 ) {
     for entity in &query {
         commands.entity(entity).despawn();
@@ -16,6 +16,7 @@ pub mod font_diagnostics;
 pub mod adaptive_scale;
 pub mod loading_screen;
 pub mod upgrade_bar;
+pub mod buff_bar;
 pub mod edge_indicators;
 pub mod kill_feed;
 pub mod wave_banner;
@@ -2,13 +2,26 @@ use bevy::prelude::*;
 use crate::shared::GameState;
 use crate::modules::menu::components::*;
 use crate::modules::menu::parts::fade_transition::FadeState;
+use crate::modules::progression::MetaProgress;
+use crate::modules::world::{BIOMES, SelectedBiome, GAME_MODES, SelectedMode};
 use crate::toolkit::asset_paths;
 
 /// Создаёт Title Screen с фоновым изображением, виньеткой и анимациями
 pub fn setup_title_screen(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    meta: Res<MetaProgress>,
+    mut selected: ResMut<SelectedBiome>,
+    mut selected_mode: ResMut<SelectedMode>,
 ) {
+    // Выбранный ранее биом/режим мог оказаться закрыт (сброс сохранения) — откатываемся на первый
+    if !BIOMES[selected.0].is_unlocked(meta.best_wave) {
+        selected.0 = 0;
+    }
+    if !GAME_MODES[selected_mode.0].is_unlocked(meta.best_wave) {
+        selected_mode.0 = 0;
+    }
+
     let font_title = asset_server.load(asset_paths::FONT_TITLE);
     let font_ui = asset_server.load(asset_paths::FONT_UI);
     let font_ui_bold = asset_server.load(asset_paths::FONT_UI_BOLD);
@@ -115,6 +128,101 @@ pub fn setup_title_screen(
             TextLayout::new_with_justify(Justify::Center),
         ));
 
+        // Выбор биома: закрытые — тусклые, с волной открытия
+        parent.spawn((
+            TitleScreenUI,
+            Node {
+                margin: UiRect::top(Val::Px(20.0)),
+                column_gap: Val::Px(10.0),
+                flex_wrap: FlexWrap::Wrap,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+        )).with_children(|row| {
+            for (index, biome) in BIOMES.iter().enumerate() {
+                let unlocked = biome.is_unlocked(meta.best_wave);
+                let (border, text_color) = biome_option_colors(index == selected.0, unlocked);
+                let label = if unlocked {
+                    biome.name.to_string()
+                } else {
+                    format!("{}\nволна {}", biome.name, biome.unlock_wave)
+                };
+                row.spawn((
+                    TitleScreenUI,
+                    BiomeOption(index),
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(14.0), Val::Px(8.0)),
+                        border: UiRect::all(Val::Px(1.5)),
+                        border_radius: BorderRadius::all(Val::Px(5.0)),
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.35)),
+                    BorderColor::all(border),
+                )).with_children(|btn| {
+                    btn.spawn((
+                        TitleScreenUI,
+                        Text::new(label),
+                        TextFont {
+                            font: font_ui.clone(),
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(text_color),
+                        TextLayout::new_with_justify(Justify::Center),
+                    ));
+                });
+            }
+        });
+
+        // Выбор режима: под биомами, та же подача
+        parent.spawn((
+            TitleScreenUI,
+            Node {
+                column_gap: Val::Px(10.0),
+                flex_wrap: FlexWrap::Wrap,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+        )).with_children(|row| {
+            for (index, mode) in GAME_MODES.iter().enumerate() {
+                let unlocked = mode.is_unlocked(meta.best_wave);
+                let (border, text_color) = biome_option_colors(index == selected_mode.0, unlocked);
+                let label = if unlocked {
+                    mode.name.to_string()
+                } else {
+                    format!("{}\nволна {}", mode.name, mode.unlock_wave)
+                };
+                row.spawn((
+                    TitleScreenUI,
+                    ModeOption(index),
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(14.0), Val::Px(6.0)),
+                        border: UiRect::all(Val::Px(1.5)),
+                        border_radius: BorderRadius::all(Val::Px(5.0)),
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.35)),
+                    BorderColor::all(border),
+                )).with_children(|btn| {
+                    btn.spawn((
+                        TitleScreenUI,
+                        Text::new(label),
+                        TextFont {
+                            font: font_ui.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(text_color),
+                        TextLayout::new_with_justify(Justify::Center),
+                    ));
+                });
+            }
+        });
+
         // Кнопка "НАЧАТЬ" — золотой BorderGradient + BoxShadow glow
         parent.spawn((
             TitleScreenUI,
@@ -191,17 +299,133 @@ pub fn pulsing_text_system(
     }
 }
 
-/// Любой клик или клавиша → начать игру (через fade)
+/// Цвета кнопки биома: (рамка, текст)
+fn biome_option_colors(selected: bool, unlocked: bool) -> (Color, Color) {
+    match (selected, unlocked) {
+        (true, _) => (Color::srgba(0.95, 0.7, 0.2, 0.9), Color::srgb(0.95, 0.75, 0.3)),
+        (false, true) => (Color::srgba(0.65, 0.6, 0.5, 0.35), Color::srgb(0.65, 0.6, 0.5)),
+        (false, false) => (Color::srgba(0.3, 0.3, 0.3, 0.3), Color::srgba(0.5, 0.5, 0.5, 0.5)),
+    }
+}
+
+/// Клик по открытому биому (или ←/→) — выбор; закрытые не реагируют
+pub fn biome_select_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    meta: Res<MetaProgress>,
+    mut selected: ResMut<SelectedBiome>,
+    options: Query<(&Interaction, &BiomeOption), Changed<Interaction>>,
+) {
+    for (interaction, option) in &options {
+        if *interaction == Interaction::Pressed && BIOMES[option.0].is_unlocked(meta.best_wave) {
+            selected.0 = option.0;
+        }
+    }
+
+    let step = if keyboard.just_pressed(KeyCode::ArrowRight) {
+        1
+    } else if keyboard.just_pressed(KeyCode::ArrowLeft) {
+        BIOMES.len() - 1
+    } else {
+        return;
+    };
+    // Следующий открытый по кругу (первый открыт всегда)
+    let mut index = selected.0;
+    loop {
+        index = (index + step) % BIOMES.len();
+        if BIOMES[index].is_unlocked(meta.best_wave) {
+            break;
+        }
+    }
+    selected.0 = index;
+}
+
+/// Подсветка выбранного биома
+pub fn biome_option_style_system(
+    selected: Res<SelectedBiome>,
+    meta: Res<MetaProgress>,
+    mut options: Query<(&BiomeOption, &mut BorderColor, &Children)>,
+    mut texts: Query<&mut TextColor>,
+) {
+    if !selected.is_changed() {
+        return;
+    }
+    for (option, mut border, children) in &mut options {
+        let unlocked = BIOMES[option.0].is_unlocked(meta.best_wave);
+        let (border_color, text_color) = biome_option_colors(option.0 == selected.0, unlocked);
+        *border = BorderColor::all(border_color);
+        for &child in children {
+            if let Ok(mut color) = texts.get_mut(child) {
+                color.0 = text_color;
+            }
+        }
+    }
+    info!("🗺️ Biome selected: {}", BIOMES[selected.0].name);
+}
+
+/// Клик по открытому режиму (или Tab) — выбор; закрытые не реагируют
+pub fn mode_select_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    meta: Res<MetaProgress>,
+    mut selected: ResMut<SelectedMode>,
+    options: Query<(&Interaction, &ModeOption), Changed<Interaction>>,
+) {
+    for (interaction, option) in &options {
+        if *interaction == Interaction::Pressed && GAME_MODES[option.0].is_unlocked(meta.best_wave) {
+            selected.0 = option.0;
+        }
+    }
+
+    if !keyboard.just_pressed(KeyCode::Tab) {
+        return;
+    }
+    // Следующий открытый по кругу (первый открыт всегда)
+    let mut index = selected.0;
+    loop {
+        index = (index + 1) % GAME_MODES.len();
+        if GAME_MODES[index].is_unlocked(meta.best_wave) {
+            break;
+        }
+    }
+    selected.0 = index;
+}
+
+/// Подсветка выбранного режима
+pub fn mode_option_style_system(
+    selected: Res<SelectedMode>,
+    meta: Res<MetaProgress>,
+    mut options: Query<(&ModeOption, &mut BorderColor, &Children)>,
+    mut texts: Query<&mut TextColor>,
+) {
+    if !selected.is_changed() {
+        return;
+    }
+    for (option, mut border, children) in &mut options {
+        let unlocked = GAME_MODES[option.0].is_unlocked(meta.best_wave);
+        let (border_color, text_color) = biome_option_colors(option.0 == selected.0, unlocked);
+        *border = BorderColor::all(border_color);
+        for &child in children {
+            if let Ok(mut color) = texts.get_mut(child) {
+                color.0 = text_color;
+            }
+        }
+    }
+    info!("🌫️ Mode selected: {}", GAME_MODES[selected.0].name);
+}
+
+/// Любой клик или клавиша → начать игру (через fade). Клик по кнопке биома/режима — только выбор.
 pub fn title_screen_interaction(
     keyboard: Res<ButtonInput<KeyCode>>,
     mouse: Res<ButtonInput<MouseButton>>,
     touches: Res<Touches>,
-    button_query: Query<&Interaction, (Changed<Interaction>, With<Button>)>,
+    button_query: Query<&Interaction, (Changed<Interaction>, With<Button>, Without<BiomeOption>, Without<ModeOption>)>,
+    options: Query<&Interaction, Or<(With<BiomeOption>, With<ModeOption>)>>,
     mut fade: ResMut<FadeState>,
 ) {
     if fade.is_active() {
         return;
     }
+    // Курсор/палец на кнопке биома или режима — клик идёт в выбор, не в старт
+    let over_option = options.iter().any(|interaction| *interaction != Interaction::None);
 
     let mut start = false;
 
@@ -218,11 +442,11 @@ pub fn title_screen_interaction(
         start = true;
     }
 
-    if mouse.just_pressed(MouseButton::Left) {
+    if mouse.just_pressed(MouseButton::Left) && !over_option {
         start = true;
     }
 
-    if touches.any_just_pressed() {
+    if touches.any_just_pressed() && !over_option {
         start = true;
     }
 
@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 use crate::shared::GameState;
 use crate::modules::enemies::EnemyCoreSet;
-use super::parts::{title_screen, game_over_screen, hud, fps_counter, button_hover, fade_transition, font_diagnostics, adaptive_scale, loading_screen, upgrade_bar, kill_feed, wave_banner, minimap};
+use super::parts::{title_screen, game_over_screen, hud, fps_counter, button_hover, fade_transition, font_diagnostics, adaptive_scale, loading_screen, upgrade_bar, kill_feed, wave_banner, minimap, edge_indicators, buff_bar};
 use super::components;
 
 pub struct MenuPlugin;
@@ -21,6 +21,10 @@ impl Plugin for MenuPlugin {
             // Title Screen
             .add_systems(OnEnter(GameState::TitleScreen), title_screen::setup_title_screen)
             .add_systems(Update, (
+                title_screen::biome_select_system,
+                title_screen::biome_option_style_system,
+                title_screen::mode_select_system,
+                title_screen::mode_option_style_system,
                 title_screen::title_screen_interaction,
                 title_screen::pulsing_text_system,
                 title_screen::remove_loading_overlay,
@@ -36,6 +40,7 @@ impl Plugin for MenuPlugin {
                 hud::setup_hud,
                 fps_counter::setup_fps,
                 minimap::setup_minimap,
+                edge_indicators::setup_safe_zone_indicator,
                 kill_feed::setup_kill_feed,
             ))
             .add_systems(Update, (
@@ -43,9 +48,14 @@ impl Plugin for MenuPlugin {
                 hud::update_timer_text,
                 hud::update_hp_bar,
                 hud::update_xp_bar,
+                hud::update_rage_bar,
+                hud::rage_gauge_interaction,
                 fps_counter::update_fps,
                 upgrade_bar::update_upgrade_bar,
+                buff_bar::update_buff_bar,
                 minimap::update_minimap,
+                minimap::update_minimap_safe_zone,
+                edge_indicators::update_safe_zone_indicator,
                 kill_feed::consume_kill_feed_messages,
                 kill_feed::update_kill_feed,
                 wave_banner::spawn_wave_banner,
@@ -7,6 +7,12 @@ pub mod combat;
 pub mod menu;
 pub mod selection;
 pub mod progression;
+pub mod spatial;
+pub mod anim_clips;
+pub mod hazards;
+pub mod shrines;
+pub mod loot;
+pub mod sound;
 
 // Реэкспорт публичных API
 pub use world::WorldPlugin;
@@ -18,3 +24,9 @@ pub use combat::{CombatPlugin, PlayerHealth};
 pub use menu::MenuPlugin;
 pub use selection::SelectionPlugin;
 pub use progression::ProgressionPlugin;
+pub use spatial::SpatialPlugin;
+pub use anim_clips::AnimClipsPlugin;
+pub use hazards::HazardsPlugin;
+pub use shrines::ShrinesPlugin;
+pub use loot::LootPlugin;
+pub use sound::SoundPlugin;
@@ -11,27 +11,40 @@ pub struct Player;
 #[reflect(Component)]
 pub struct PlayerAnimState {
     pub current: AnimationState,
+    /// Удар комбо для Attacking (индекс в COMBO_SWINGS)
+    pub swing: usize,
     /// Последняя применённая анимация — защита от self-transitions (Bevy #13910)
     #[reflect(ignore)]
     previous: AnimationState,
+    #[reflect(ignore)]
+    previous_swing: usize,
 }
 
 impl PlayerAnimState {
     pub fn new() -> Self {
         Self {
             current: AnimationState::Idle,
+            swing: 0,
             previous: AnimationState::Idle,
+            previous_swing: 0,
         }
     }
 
-    /// Есть ли новый переход для применения
+    /// Есть ли новый переход для применения (смена удара комбо — тоже переход)
     pub fn needs_transition(&self) -> bool {
         self.current != self.previous
+            || (self.current == AnimationState::Attacking && self.swing != self.previous_swing)
     }
 
     /// Отметить текущий переход как применённый
     pub fn mark_applied(&mut self) {
         self.previous = self.current;
+        self.previous_swing = self.swing;
+    }
+
+    /// Последнее применённое состояние (до `mark_applied` — то, из которого уходим)
+    pub fn previous(&self) -> AnimationState {
+        self.previous
     }
 }
 
@@ -45,17 +58,41 @@ pub enum AnimationState {
     HitReaction,
 }
 
+/// Mask-группы костей: ноги+таз и корпус от позвоночника вверх
+pub const LOWER_BODY_GROUP: u32 = 0;
+pub const UPPER_BODY_GROUP: u32 = 1;
+
 /// Компонент для хранения индексов нод анимаций в графе
-/// (подобно ID элементов в DOM дереве)
+/// (подобно ID элементов в DOM дереве).
+/// Локомоция продублирована на оба слоя (одинаковая фаза) — верх отдаёт её вес атаке/удару.
 #[derive(Component, Clone, Copy)]
 pub struct PlayerAnimations {
+    // Нижний слой (ноги): blend space idle/walk/run
     pub idle: AnimationNodeIndex,
     pub walk: AnimationNodeIndex,
     pub run: AnimationNodeIndex,
-    pub attack: AnimationNodeIndex,
+    // Верхний слой: та же локомоция + действия
+    pub idle_upper: AnimationNodeIndex,
+    pub walk_upper: AnimationNodeIndex,
+    pub run_upper: AnimationNodeIndex,
+    /// Удары комбо по порядку (см. COMBO_SWINGS)
+    pub attacks: [AnimationNodeIndex; 3],
     pub hit: AnimationNodeIndex,
 }
 
+/// Веса слоёв на AnimationPlayer игрока (плавные — без AnimationTransitions)
+#[derive(Component, Default)]
+pub struct PlayerAnimLayers {
+    /// Сглаженный параметр blend space: 0 = idle, 1 = walk, 2 = run
+    pub locomotion: f32,
+    /// Текущее действие верхнего слоя (attack/hit); остаётся до конца fade-out
+    pub action: Option<AnimationNodeIndex>,
+    /// Вес действия на верхнем слое (0..1)
+    pub action_weight: f32,
+    /// Длительность fade действия (с) — своя у атаки и удара
+    pub action_blend: f32,
+}
+
 /// Маркер для визуальной модели игрока (child entity)
 #[derive(Component, Reflect)]
 #[reflect(Component)]
@@ -85,21 +122,91 @@ pub struct StaggerCooldown {
     pub timer: Timer,
 }
 
+/// Перезарядка рывка (кольцо CooldownRing с источником Dash)
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct DashCooldown {
+    pub timer: Timer,
+}
+
+impl DashCooldown {
+    pub fn new(cooldown: f32) -> Self {
+        let mut timer = Timer::from_seconds(cooldown, TimerMode::Once);
+        timer.finish(); // Рывок доступен сразу
+        Self { timer }
+    }
+}
+
+/// Игрок в рывке: скорость фиксирована, ввод движения игнорируется
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Dashing {
+    pub timer: Timer,
+    pub direction: Vec3,
+    /// Таймер спавна послеобразов
+    pub afterimage_timer: Timer,
+}
+
 /// Модифицируемые характеристики игрока (базовые + бонусы от апгрейдов)
 #[derive(Component, Reflect)]
 #[reflect(Component)]
 pub struct PlayerStats {
     pub move_speed_multiplier: f32,
+    /// Запас выносливости (спринт тратит, пауза восстанавливает)
+    pub max_stamina: f32,
+    /// Восстановление выносливости (ед/с) после задержки
+    pub stamina_regen: f32,
 }
 
 impl Default for PlayerStats {
     fn default() -> Self {
         Self {
             move_speed_multiplier: 1.0,
+            max_stamina: 100.0,
+            stamina_regen: 20.0,
         }
     }
 }
 
+/// Выносливость: спринт тратит, после задержки восстанавливается.
+/// Опустошённый запас блокирует спринт до частичного восстановления (без "дёрганого" бега).
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Stamina {
+    pub current: f32,
+    /// Задержка перед восстановлением после последнего спринта
+    pub regen_delay: Timer,
+    pub exhausted: bool,
+}
+
+impl Stamina {
+    pub fn new(max: f32) -> Self {
+        let mut regen_delay = Timer::from_seconds(0.8, TimerMode::Once);
+        regen_delay.finish();
+        Self { current: max, regen_delay, exhausted: false }
+    }
+
+    pub fn can_sprint(&self) -> bool {
+        !self.exhausted && self.current > 0.0
+    }
+}
+
+/// Кинематика движения: курс (yaw, рад) поворачивается с ограниченной скоростью,
+/// скорость разгоняется/тормозит — вместо мгновенного переключения walk/run
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct PlayerMotion {
+    pub heading: f32,
+    pub speed: f32,
+}
+
+impl Default for PlayerMotion {
+    fn default() -> Self {
+        // Лицом от камеры — как модель при спавне
+        Self { heading: std::f32::consts::PI, speed: 0.0 }
+    }
+}
+
 /// Маркер для entity оружия (child кости RightHand)
 #[derive(Component, Reflect)]
 #[reflect(Component)]
@@ -3,4 +3,4 @@ pub mod components;
 pub(crate) mod parts;
 
 pub use plugin::PlayerPlugin;
-pub use components::{Player, PlayerAnimState, AnimationState, PlayerAnimations, PlayerModel, AnimationSetupComplete, PlayerHitStagger, StaggerCooldown, WeaponModel, PlayerStats};
+pub use components::{Player, PlayerAnimState, AnimationState, PlayerAnimations, PlayerAnimLayers, PlayerModel, AnimationSetupComplete, PlayerHitStagger, StaggerCooldown, WeaponModel, PlayerStats, Stamina, PlayerMotion, DashCooldown, Dashing};
@@ -1,14 +1,23 @@
 use bevy::prelude::*;
-use std::time::Duration;
+use bevy::animation::graph::AnimationNodeType;
+use avian3d::prelude::*;
 use crate::modules::{Player, PlayerAnimState, InputState};
-use crate::modules::player::components::{AnimationState, PlayerAnimations, PlayerHitStagger, PlayerModel, StaggerCooldown};
+use crate::modules::player::components::{AnimationState, PlayerAnimations, PlayerAnimLayers, PlayerHitStagger, PlayerModel, StaggerCooldown};
 use crate::modules::player::AnimationSetupComplete;
 use crate::modules::combat::components::AttackCooldown;
+use crate::modules::combat::parts::combo::COMBO_SWINGS;
+use crate::modules::anim_clips::{AnimClipLibrary, BOGATYR_CLIPS};
+use crate::shared::constants::{WALK_SPEED, RUN_SPEED};
 
 // Пороги для предотвращения мерцания (hysteresis)
 const MOVEMENT_START_THRESHOLD: f32 = 0.05;  // Начать движение
 const MOVEMENT_STOP_THRESHOLD: f32 = 0.02;   // Остановиться
 
+// Слои анимации
+const ATTACK_BLEND: f32 = 0.2;              // Fade верхнего слоя атаки (с)
+const HIT_BLEND: f32 = 0.1;                 // Fade hit reaction (с)
+const LOCOMOTION_SMOOTHING: f32 = 10.0;     // Сглаживание blend space (1/с)
+
 /// Вычисляет желаемое состояние анимации по вводу.
 /// Только меняет `current` — центральная система применит переход.
 pub fn animation_state_system(
@@ -44,37 +53,122 @@ pub fn animation_state_system(
     }
 }
 
-/// Центральная система переходов анимации игрока.
-/// Единственное место где вызывается `transitions.play()`.
-/// Аналог `enemy_animation_state_system` для врагов.
+/// Длительность клипа в узле графа (для запуска с конца при обратном проигрывании)
+fn clip_duration(
+    graph: &AnimationGraphHandle,
+    index: AnimationNodeIndex,
+    graphs: &Assets<AnimationGraph>,
+    clips: &Assets<AnimationClip>,
+) -> Option<f32> {
+    let node = graphs.get(&graph.0)?.get(index)?;
+    match &node.node_type {
+        AnimationNodeType::Clip(handle) => clips.get(handle).map(|clip| clip.duration()),
+        _ => None,
+    }
+}
+
+/// Центральная система слоёв анимации игрока.
+/// Единственное место, где запускаются клипы после старта (аналог `enemy_animation_state_system`).
+/// - Низ тела: blend space idle/walk/run по фактической скорости — ноги не замирают при ударе.
+/// - Верх тела: та же локомоция, которая по переходу в Attacking/HitReaction плавно
+///   отдаёт вес клипу действия и забирает обратно, когда `current` вернулся в локомоцию.
 pub fn player_animation_transition_system(
-    mut player: Query<&mut PlayerAnimState, With<Player>>,
+    time: Res<Time>,
+    mut player: Query<(&mut PlayerAnimState, &LinearVelocity), With<Player>>,
     mut animation_query: Query<
-        (&PlayerAnimations, &mut AnimationPlayer, &mut AnimationTransitions),
+        (&PlayerAnimations, &mut AnimationPlayer, &mut PlayerAnimLayers, &AnimationGraphHandle),
         With<AnimationSetupComplete>
     >,
+    graphs: Res<Assets<AnimationGraph>>,
+    clips: Res<Assets<AnimationClip>>,
+    library: Res<AnimClipLibrary>,
 ) {
-    let Ok(mut state) = player.single_mut() else { return };
-    if !state.needs_transition() { return; }
-    let Ok((animations, mut anim_player, mut transitions)) = animation_query.single_mut() else { return };
-
-    let (index, should_loop, speed, blend_ms) = match state.current {
-        AnimationState::Idle       => (animations.idle,   true,  1.0, 200),
-        AnimationState::Walking    => (animations.walk,   true,  1.0, 200),
-        AnimationState::Running    => (animations.run,    true,  1.0, 200),
-        AnimationState::Attacking  => (animations.attack, false, 2.5, 200),
-        AnimationState::HitReaction => (animations.hit,   false, 1.0, 100),
-    };
-
-    let transition = transitions.play(&mut anim_player, index, Duration::from_millis(blend_ms));
-    if should_loop {
-        transition.repeat();
+    let Ok((mut state, velocity)) = player.single_mut() else { return };
+    let Ok((animations, mut anim_player, mut layers, graph)) = animation_query.single_mut() else { return };
+    let dt = time.delta_secs();
+
+    // Переход состояния → смена действия верхнего слоя
+    if state.needs_transition() {
+        let action = match state.current {
+            AnimationState::Attacking   => {
+                let swing = state.swing.min(COMBO_SWINGS.len() - 1);
+                let def = &COMBO_SWINGS[swing];
+                // Обратный ход — только если вместо своего клипа играет базовый замах
+                let reversed = def.fallback_reversed && library.uses_fallback(&BOGATYR_CLIPS, def.slot);
+                Some((animations.attacks[swing], def.anim_speed, ATTACK_BLEND, reversed))
+            }
+            AnimationState::HitReaction => Some((animations.hit, 1.0, HIT_BLEND, false)),
+            _ => None,
+        };
+        match action {
+            Some((index, speed, blend, reversed)) => {
+                // Прежнее действие обрывается — новое перехватывает верх с его текущим весом
+                if let Some(previous) = layers.action.filter(|&previous| previous != index) {
+                    anim_player.stop(previous);
+                }
+                anim_player.play(index).replay();
+                // Обратный замах стартует с конца клипа (без длительности — обычный)
+                let reverse_from = reversed.then(|| clip_duration(graph, index, &graphs, &clips)).flatten();
+                if let Some(active) = anim_player.animation_mut(index) {
+                    active.set_speed(speed).set_weight(layers.action_weight);
+                    if let Some(duration) = reverse_from {
+                        active.set_speed(-speed).seek_to(duration);
+                    }
+                }
+                layers.action = Some(index);
+                layers.action_blend = blend;
+            }
+            None => layers.action_blend = match state.previous() {
+                AnimationState::HitReaction => HIT_BLEND,
+                _ => ATTACK_BLEND,
+            },
+        }
+        state.mark_applied();
     }
-    if let Some(active) = anim_player.animation_mut(index) {
-        active.set_speed(speed);
+
+    // Вес действия: к 1 пока оно идёт, к 0 после; на нуле клип останавливается
+    let acting = matches!(state.current, AnimationState::Attacking | AnimationState::HitReaction);
+    let step = dt / layers.action_blend.max(0.01);
+    layers.action_weight = if acting {
+        (layers.action_weight + step).min(1.0)
+    } else {
+        (layers.action_weight - step).max(0.0)
+    };
+    if let Some(index) = layers.action {
+        if layers.action_weight <= 0.0 {
+            anim_player.stop(index);
+            layers.action = None;
+        } else if let Some(active) = anim_player.animation_mut(index) {
+            active.set_weight(layers.action_weight);
+        }
     }
 
-    state.mark_applied();
+    // Blend space по скорости: 0 → idle, WALK_SPEED → walk, RUN_SPEED → run
+    let speed = Vec2::new(velocity.x, velocity.z).length();
+    let target = if speed <= WALK_SPEED {
+        speed / WALK_SPEED
+    } else {
+        1.0 + ((speed - WALK_SPEED) / (RUN_SPEED - WALK_SPEED)).min(1.0)
+    };
+    layers.locomotion += (target - layers.locomotion) * (1.0 - (-LOCOMOTION_SMOOTHING * dt).exp());
+    let blend = layers.locomotion;
+    let weights = [
+        (1.0 - blend).clamp(0.0, 1.0),
+        1.0 - (blend - 1.0).abs().min(1.0),
+        (blend - 1.0).clamp(0.0, 1.0),
+    ];
+
+    let upper_share = 1.0 - layers.action_weight;
+    let lower = [animations.idle, animations.walk, animations.run];
+    let upper = [animations.idle_upper, animations.walk_upper, animations.run_upper];
+    for ((lower, upper), weight) in lower.into_iter().zip(upper).zip(weights) {
+        if let Some(active) = anim_player.animation_mut(lower) {
+            active.set_weight(weight);
+        }
+        if let Some(active) = anim_player.animation_mut(upper) {
+            active.set_weight(weight * upper_share);
+        }
+    }
 }
 
 /// Diablo 4 Hit Recovery:
@@ -0,0 +1,154 @@
+use bevy::prelude::*;
+use avian3d::prelude::*;
+use crate::modules::input::InputState;
+use crate::modules::player::components::{Player, PlayerModel, PlayerAnimState, AnimationState, PlayerMotion, DashCooldown, Dashing};
+use crate::shared::constants::WALK_SPEED;
+use crate::modules::combat::components::Invulnerable;
+
+const DASH_SPEED: f32 = 22.0;          // м/с (≈4м за рывок)
+const DASH_DURATION: f32 = 0.18;       // с
+const DASH_IFRAMES: f32 = 0.3;         // с — чуть дольше самого рывка (выход из толпы)
+pub const DASH_COOLDOWN: f32 = 1.5;    // с
+const AFTERIMAGE_INTERVAL: f32 = 0.04; // с между послеобразами
+const AFTERIMAGE_LIFETIME: f32 = 0.25; // с
+const AFTERIMAGE_POOL_SIZE: usize = 6; // одновременно живут ~5 (0.25 / 0.04)
+
+/// Кэшированные ассеты послеобразов: силуэт-капсула + пул материалов (round-robin, как у slash)
+#[derive(Resource)]
+pub struct DashAssets {
+    pub mesh: Handle<Mesh>,
+    pub material_pool: [Handle<StandardMaterial>; AFTERIMAGE_POOL_SIZE],
+    pub next_index: usize,
+}
+
+/// Послеобраз рывка: гаснет и исчезает
+#[derive(Component)]
+pub struct DashAfterimage {
+    pub timer: Timer,
+    pub material: Handle<StandardMaterial>,
+}
+
+pub fn init_dash_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let mesh = meshes.add(Capsule3d::new(0.35, 1.1));
+    let material_pool = std::array::from_fn(|_| materials.add(StandardMaterial {
+        base_color: Color::srgba(0.4, 0.7, 1.0, 0.5),
+        emissive: LinearRgba::new(0.6, 1.2, 3.0, 1.0),
+        alpha_mode: AlphaMode::Add,
+        unlit: true,
+        ..default()
+    }));
+    commands.insert_resource(DashAssets { mesh, material_pool, next_index: 0 });
+}
+
+/// Старт рывка по запросу (Space / double-tap / кнопка): направление ввода,
+/// без ввода — куда смотрит модель. В стаггере рывок недоступен (action lock).
+pub fn start_dash_system(
+    time: Res<Time>,
+    mut input_state: ResMut<InputState>,
+    mut player: Query<(Entity, &mut DashCooldown, &PlayerAnimState, &Children), (With<Player>, Without<Dashing>)>,
+    model_query: Query<&Transform, With<PlayerModel>>,
+    mut commands: Commands,
+) {
+    let requested = std::mem::take(&mut input_state.dash_requested);
+    let Ok((entity, mut cooldown, state, children)) = player.single_mut() else { return };
+    cooldown.timer.tick(time.delta());
+
+    if !requested || !cooldown.timer.is_finished() || state.current == AnimationState::HitReaction {
+        return;
+    }
+
+    let direction = if input_state.movement.length() > 0.02 {
+        Vec3::new(input_state.movement.x, 0.0, input_state.movement.z).normalize()
+    } else {
+        children.iter()
+            .find_map(|child| model_query.get(child).ok())
+            .map(|model| (model.rotation * Vec3::Z).with_y(0.0).normalize_or_zero())
+            .unwrap_or(Vec3::NEG_Z)
+    };
+
+    cooldown.timer.reset();
+    commands.entity(entity).insert((
+        Dashing {
+            timer: Timer::from_seconds(DASH_DURATION, TimerMode::Once),
+            direction,
+            afterimage_timer: Timer::from_seconds(AFTERIMAGE_INTERVAL, TimerMode::Repeating),
+        },
+        Invulnerable::new(DASH_IFRAMES),
+    ));
+    debug!("💨 Dash {:?}", direction);
+}
+
+/// Рывок: фиксированная скорость + послеобразы; по окончании — выход на шаг по направлению
+/// рывка (дальше разгоном/торможением управляет movement)
+pub fn dashing_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut player: Query<(Entity, &Transform, &mut Dashing, &mut LinearVelocity, &mut PlayerMotion), With<Player>>,
+    mut assets: ResMut<DashAssets>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Ok((entity, transform, mut dash, mut velocity, mut motion)) = player.single_mut() else { return };
+    dash.timer.tick(time.delta());
+
+    if dash.timer.is_finished() {
+        motion.heading = dash.direction.x.atan2(dash.direction.z);
+        motion.speed = WALK_SPEED;
+        velocity.0 = dash.direction * WALK_SPEED;
+        commands.entity(entity).remove::<Dashing>();
+        return;
+    }
+    velocity.0 = dash.direction * DASH_SPEED;
+
+    // Послеобраз — силуэт на месте игрока, повёрнутый по рывку
+    let spawned = dash.afterimage_timer.tick(time.delta()).times_finished_this_tick();
+    for _ in 0..spawned {
+        let material = assets.material_pool[assets.next_index % AFTERIMAGE_POOL_SIZE].clone();
+        assets.next_index = assets.next_index.wrapping_add(1);
+        if let Some(mat) = materials.get_mut(&material) {
+            mat.base_color = mat.base_color.with_alpha(0.5);
+        }
+        commands.spawn((
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_translation(transform.translation)
+                .with_rotation(Quat::from_rotation_y(dash.direction.x.atan2(dash.direction.z))),
+            DashAfterimage {
+                timer: Timer::from_seconds(AFTERIMAGE_LIFETIME, TimerMode::Once),
+                material,
+            },
+        ));
+    }
+}
+
+/// Затухание послеобразов + despawn
+pub fn afterimage_fade_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut DashAfterimage)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (entity, mut afterimage) in &mut query {
+        afterimage.timer.tick(time.delta());
+        if afterimage.timer.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        if let Some(mat) = materials.get_mut(&afterimage.material) {
+            mat.base_color = mat.base_color.with_alpha(0.5 * afterimage.timer.fraction_remaining());
+        }
+    }
+}
+
+/// Despawn послеобразов при выходе из Playing
+pub fn cleanup_afterimages(
+    mut commands: Commands,
+    query: Query<Entity, With<DashAfterimage>>,
+) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
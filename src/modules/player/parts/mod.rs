@@ -1,7 +1,8 @@
 //! Реализация игрока:
 //! - spawner: спавн с AnimationGraph и Avian3D физикой, привязка AnimationPlayer
-//! - movement: перемещение через LinearVelocity по InputState, поворот модели
-//! - animation: переключение idle/walk/run с гистерезисом и плавным блендингом (200мс)
+//! - movement: разгон/торможение и ограниченный разворот по InputState, спринт за выносливость
+//! - animation: слои анимации — blend space idle/walk/run на ногах, атака/удар на корпусе
+//! - dash: рывок с i-frames, кулдауном и послеобразами
 //! - weapon_attachment: поиск кости "RightHand" в скелете, спавн меча как дочерней сущности
 //! - cleanup: деспавн игрока, сброс InputState
 
@@ -10,3 +11,4 @@ pub(crate) mod movement;
 pub(super) mod animation;
 pub(super) mod cleanup;
 pub(super) mod weapon_attachment;
+pub(super) mod dash;
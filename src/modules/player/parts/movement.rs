@@ -1,43 +1,109 @@
 use bevy::prelude::*;
 use avian3d::prelude::*;
+use std::f32::consts::{PI, TAU};
 use crate::modules::{Player, PlayerModel, InputState};
-use crate::modules::player::components::PlayerStats;
-use crate::shared::constants::{WALK_SPEED, RUN_SPEED};
+use crate::modules::player::components::{PlayerStats, Stamina, PlayerMotion, Dashing};
+use crate::modules::hazards::HazardSlow;
+use crate::modules::shrines::ActiveBuffs;
+use crate::shared::constants::{
+    WALK_SPEED, RUN_SPEED, PLAYER_ACCELERATION, PLAYER_DECELERATION, PLAYER_TURN_RATE,
+    SPRINT_STAMINA_DRAIN, STAMINA_RECOVER_FRACTION,
+};
 
-/// Движение через LinearVelocity с учётом модификатора скорости от апгрейдов
+/// Движение через LinearVelocity: разгон/торможение, ограниченная скорость разворота
+/// и спринт за выносливость. Модификаторы скорости и выносливости — из PlayerStats (апгрейды),
+/// трясина (HazardSlow) режет скорость поверх них, Быстрота святилища — добавляет.
+/// Во время рывка скоростью управляет dash (игрок не подхватывается этой системой).
 pub fn player_movement_system(
     input_state: Res<InputState>,
     time: Res<Time>,
-    mut player_query: Query<(&Children, &mut LinearVelocity, &mut Transform, &PlayerStats), With<Player>>,
+    buffs: Res<ActiveBuffs>,
+    mut player_query: Query<
+        (&Children, &mut LinearVelocity, &mut Transform, &PlayerStats, &mut Stamina, &mut PlayerMotion, Option<&HazardSlow>),
+        (With<Player>, Without<Dashing>)
+    >,
     mut model_query: Query<&mut Transform, (With<PlayerModel>, Without<Player>)>,
 ) {
-    if let Ok((children, mut velocity, mut player_transform, stats)) = player_query.single_mut() {
-        // Страховка: сбрасываем rotation parent entity (physics body не должен вращаться)
-        player_transform.rotation = Quat::IDENTITY;
-        if input_state.movement.length() > 0.02 {
-            let speed = if input_state.is_running {
-                RUN_SPEED
-            } else {
-                WALK_SPEED
-            } * stats.move_speed_multiplier;
-
-            velocity.0 = input_state.movement.normalize() * speed;
-
-            // Поворачиваем ТОЛЬКО визуальную модель (PlayerModel child)
-            let target_rotation = Quat::from_rotation_y(
-                input_state.movement.x.atan2(input_state.movement.z)
-            );
-
-            // Frame-rate independent slerp: экспоненциальное сглаживание
-            let t = 1.0 - (-10.0 * time.delta_secs()).exp();
-
-            for &child in children {
-                if let Ok(mut model_transform) = model_query.get_mut(child) {
-                    model_transform.rotation = model_transform.rotation.slerp(target_rotation, t);
-                }
-            }
-        } else {
-            velocity.0 = Vec3::ZERO;
+    let Ok((children, mut velocity, mut player_transform, stats, mut stamina, mut motion, slow)) =
+        player_query.single_mut() else { return };
+    // Страховка: сбрасываем rotation parent entity (physics body не должен вращаться)
+    player_transform.rotation = Quat::IDENTITY;
+
+    let dt = time.delta_secs();
+    let moving = input_state.movement.length() > 0.02;
+    let sprinting = moving && input_state.is_running && stamina.can_sprint();
+    update_stamina(&mut stamina, stats, sprinting, time.delta());
+
+    let mut target_speed = if !moving {
+        0.0
+    } else if sprinting {
+        RUN_SPEED
+    } else {
+        WALK_SPEED
+    } * stats.move_speed_multiplier * slow.map_or(1.0, |s| s.multiplier) * buffs.speed_multiplier();
+
+    if moving {
+        // Курс догоняет ввод не быстрее PLAYER_TURN_RATE
+        let wanted = input_state.movement.x.atan2(input_state.movement.z);
+        let delta = wrap_angle(wanted - motion.heading);
+        let max_step = PLAYER_TURN_RATE * dt;
+        motion.heading = wrap_angle(motion.heading + delta.clamp(-max_step, max_step));
+
+        // Резкий разворот — сначала торможение: скорость ограничена косинусом рассогласования
+        target_speed *= delta.cos().max(0.0);
+    }
+
+    let rate = if target_speed > motion.speed { PLAYER_ACCELERATION } else { PLAYER_DECELERATION };
+    motion.speed = move_towards(motion.speed, target_speed, rate * dt);
+
+    let (sin, cos) = motion.heading.sin_cos();
+    velocity.0 = Vec3::new(sin, 0.0, cos) * motion.speed;
+
+    if !moving {
+        return;
+    }
+
+    // Поворачиваем ТОЛЬКО визуальную модель (PlayerModel child) — вслед за курсом
+    let target_rotation = Quat::from_rotation_y(motion.heading);
+
+    // Frame-rate independent slerp: экспоненциальное сглаживание
+    let t = 1.0 - (-10.0 * dt).exp();
+
+    for &child in children {
+        if let Ok(mut model_transform) = model_query.get_mut(child) {
+            model_transform.rotation = model_transform.rotation.slerp(target_rotation, t);
         }
     }
 }
+
+/// Спринт тратит выносливость; без спринта — восстановление после задержки.
+/// Опустошение блокирует спринт до STAMINA_RECOVER_FRACTION запаса.
+fn update_stamina(stamina: &mut Stamina, stats: &PlayerStats, sprinting: bool, delta: std::time::Duration) {
+    let dt = delta.as_secs_f32();
+    if sprinting {
+        stamina.current = (stamina.current - SPRINT_STAMINA_DRAIN * dt).max(0.0);
+        stamina.regen_delay.reset();
+        if stamina.current <= 0.0 {
+            stamina.exhausted = true;
+            debug!("😮‍💨 Stamina exhausted");
+        }
+        return;
+    }
+
+    if !stamina.regen_delay.tick(delta).is_finished() {
+        return;
+    }
+    stamina.current = (stamina.current + stats.stamina_regen * dt).min(stats.max_stamina);
+    if stamina.exhausted && stamina.current >= stats.max_stamina * STAMINA_RECOVER_FRACTION {
+        stamina.exhausted = false;
+    }
+}
+
+/// Угол в диапазон [-PI, PI)
+fn wrap_angle(angle: f32) -> f32 {
+    (angle + PI).rem_euclid(TAU) - PI
+}
+
+fn move_towards(current: f32, target: f32, max_delta: f32) -> f32 {
+    current + (target - current).clamp(-max_delta, max_delta)
+}
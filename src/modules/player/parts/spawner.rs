@@ -1,21 +1,20 @@
 use bevy::prelude::*;
-use std::time::Duration;
+use bevy::animation::AnimationTargetId;
 use avian3d::prelude::*;  // ✅ Добавляем импорт физики (RigidBody, Collider)
-use crate::modules::player::components::{Player, PlayerAnimState, PlayerAnimations, PlayerModel, AnimationSetupComplete, PlayerStats};
-use crate::modules::combat::components::{Weapon, AttackCooldown, PlayerHealth};
-use crate::modules::world::{GroundCircle, CooldownRing};
+use crate::modules::player::components::{Player, PlayerAnimState, PlayerAnimations, PlayerAnimLayers, PlayerModel, AnimationSetupComplete, PlayerStats, Stamina, PlayerMotion, DashCooldown, LOWER_BODY_GROUP, UPPER_BODY_GROUP};
+use crate::modules::combat::components::{Weapon, AttackCooldown, PlayerHealth, Rage};
+use crate::modules::combat::parts::combo::{ComboState, COMBO_SWINGS};
+use crate::modules::combat::parts::ultimate::{HeroUltimate, RAGE_MAX};
+use crate::modules::world::{GroundCircle, CooldownRing, CooldownSource, CurrentArena};
+use super::dash::DASH_COOLDOWN;
+use bevy::gltf::Gltf;
 use crate::toolkit::asset_paths;
+use crate::modules::anim_clips::{AnimClipLibrary, AnimSlot, ClipGraph, BOGATYR_CLIPS};
 
 /// Индексы анимаций в AnimationGraph — хранится на PlayerModel перманентно.
 /// setup_scene_animation перезапускается если Bevy пересоздаст сцену из SceneRoot.
 #[derive(Component, Clone, Copy)]
-pub struct AnimationIndices {
-    idle: AnimationNodeIndex,
-    walk: AnimationNodeIndex,
-    run: AnimationNodeIndex,
-    attack: AnimationNodeIndex,
-    hit: AnimationNodeIndex,
-}
+pub struct AnimationIndices(PlayerAnimations);
 
 pub fn spawn_player(
     mut commands: Commands,
@@ -23,42 +22,45 @@ pub fn spawn_player(
     mut graphs: ResMut<Assets<AnimationGraph>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    mut library: ResMut<AnimClipLibrary>,
+    gltfs: Res<Assets<Gltf>>,
+    arena: Option<Res<CurrentArena>>,
 ) {
     info!("🎮 Spawning player with animations from {}", asset_paths::BOGATYR_MODEL);
 
     // Загружаем модель богатыря
     let scene = asset_server.load(asset_paths::BOGATYR_MODEL);
 
-    // Создаем AnimationGraph
-    let mut animation_graph = AnimationGraph::new();
-
-    // Загружаем анимации (каждая из отдельного GLB)
-    let idle_handle = asset_server.load(asset_paths::ANIM_IDLE);
-    let walk_handle = asset_server.load(asset_paths::ANIM_WALK);
-    let run_handle = asset_server.load(asset_paths::ANIM_RUN);
-    let attack_handle = asset_server.load(asset_paths::ANIM_ATTACK);
-    let hit_handle = asset_server.load(asset_paths::ANIM_HIT);
-
-    // Добавляем каждую анимацию в граф и получаем индексы
-    let idle_index = animation_graph.add_clip(idle_handle, 1.0, animation_graph.root);
-    let walk_index = animation_graph.add_clip(walk_handle, 1.0, animation_graph.root);
-    let run_index = animation_graph.add_clip(run_handle, 1.0, animation_graph.root);
-    let attack_index = animation_graph.add_clip(attack_handle, 1.0, animation_graph.root);
-    let hit_index = animation_graph.add_clip(hit_handle, 1.0, animation_graph.root);
+    // AnimationGraph со слотами — клипы подставляются по имени из BOGATYR_CLIPS.
+    // Два слоя: нижний не трогает корпус, верхний не трогает ноги (кости раскладываются
+    // по группам в setup_scene_animation, когда иерархия GLB уже заспавнена)
+    let mut animation_graph = ClipGraph::new(&BOGATYR_CLIPS);
+    let lower = 1 << UPPER_BODY_GROUP;
+    let upper = 1 << LOWER_BODY_GROUP;
+    let animations = PlayerAnimations {
+        idle: animation_graph.add_masked(AnimSlot::Idle, lower, 1.0),
+        walk: animation_graph.add_masked(AnimSlot::Walk, lower, 1.0),
+        run: animation_graph.add_masked(AnimSlot::Run, lower, 1.0),
+        idle_upper: animation_graph.add_masked(AnimSlot::Idle, upper, 1.0),
+        walk_upper: animation_graph.add_masked(AnimSlot::Walk, upper, 1.0),
+        run_upper: animation_graph.add_masked(AnimSlot::Run, upper, 1.0),
+        // Удары комбо — по слотам из COMBO_SWINGS (порядок = номер удара)
+        attacks: COMBO_SWINGS.each_ref().map(|swing| animation_graph.add_masked(swing.slot, upper, 1.0)),
+        hit: animation_graph.add_masked(AnimSlot::Hit, upper, 1.0),
+    };
 
     // Сохраняем граф
-    let graph_handle = graphs.add(animation_graph);
+    let graph_handle = library.build(animation_graph, &asset_server, &gltfs, &mut graphs);
+
+    info!("📊 AnimationGraph created with 10 animation nodes, 2 body layers ({})", asset_paths::BOGATYR_GLB);
 
-    info!("📊 AnimationGraph created with 5 animation nodes");
-    info!("  - Idle: {}", asset_paths::ANIM_IDLE);
-    info!("  - Walk: {}", asset_paths::ANIM_WALK);
-    info!("  - Run: {}", asset_paths::ANIM_RUN);
-    info!("  - Attack: {}", asset_paths::ANIM_ATTACK);
-    info!("  - Hit: {}", asset_paths::ANIM_HIT);
+    let stats = PlayerStats::default();
+    let max_stamina = stats.max_stamina;
 
     // Создаем ЛОГИЧЕСКИЙ Player entity (без mesh) + ФИЗИКА
     let player_entity = commands.spawn((
-        Transform::from_xyz(0.0, 0.9, 0.0),  // ✅ Y = 0.9 (половина высоты 1.8м) - стоит на полу
+        // Y = 0.9 (половина высоты 1.8м) — стоит на полу; без арены — центр
+        Transform::from_translation(arena.map_or(Vec3::new(0.0, 0.9, 0.0), |arena| arena.player_spawn)),
         Player,
         PlayerAnimState::new(),
         RigidBody::Dynamic,  // ✅ Dynamic = сталкивается со Static и другими Dynamic
@@ -71,11 +73,22 @@ pub fn spawn_player(
             .lock_rotation_x()
             .lock_rotation_y()   // ✅ Коллизии не вращают тело — визуальный поворот через PlayerModel child
             .lock_rotation_z(),
-        // Combat
-        Weapon::default(),
-        AttackCooldown::new(1.0),
+        // Combat (вложенный tuple — лимит 15 компонентов в bundle)
+        (
+            Weapon::default(),
+            AttackCooldown::new(1.0),
+            ComboState::default(),
+            DashCooldown::new(DASH_COOLDOWN),
+            Rage::new(RAGE_MAX),
+            HeroUltimate::default(),
+        ),
         PlayerHealth::new(100.0),
-        PlayerStats::default(),
+        // Движение: характеристики, выносливость, разгон/курс
+        (
+            stats,
+            Stamina::new(max_stamina),
+            PlayerMotion::default(),
+        ),
     )).id();
 
     // Создаем ВИЗУАЛЬНЫЙ child с SceneRoot
@@ -86,13 +99,7 @@ pub fn spawn_player(
         Visibility::Hidden,  // Скрыт до загрузки GLB (убирает "поп-ин")
         PlayerModel,
         // Временный компонент для передачи индексов в setup_scene_animation
-        AnimationIndices {
-            idle: idle_index,
-            walk: walk_index,
-            run: run_index,
-            attack: attack_index,
-            hit: hit_index,
-        },
+        AnimationIndices(animations),
         AnimationGraphHandle(graph_handle),
     )).id();
 
@@ -137,6 +144,7 @@ pub fn spawn_player(
         Transform::from_xyz(0.0, -0.885, 0.0)
             .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
         CooldownRing {
+            source: CooldownSource::Attack,
             inner_radius: 0.50,
             outer_radius: 0.62,
             material_handle: cd_material,
@@ -146,6 +154,56 @@ pub fn spawn_player(
         },
     )).id();
 
+    // Dash ring — белёсо-синяя дуга перезарядки рывка (внутри кольца оружия)
+    let dash_mesh = meshes.add(Annulus::new(0.40, 0.47)); // Будет заменён на arc в первом кадре
+    let dash_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.8, 0.9, 1.0, 0.5),
+        emissive: LinearRgba::new(0.6, 0.8, 1.0, 0.0) * 2.0,
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    });
+    let dash_ring = commands.spawn((
+        Mesh3d(dash_mesh),
+        MeshMaterial3d(dash_material.clone()),
+        Transform::from_xyz(0.0, -0.882, 0.0)
+            .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+        CooldownRing {
+            source: CooldownSource::Dash,
+            inner_radius: 0.40,
+            outer_radius: 0.47,
+            material_handle: dash_material,
+            last_fraction: -1.0,
+            last_facing: 0.0,
+            last_alpha: 0.0,
+        },
+    )).id();
+
+    // Stamina arc — зелёная дуга выносливости снаружи HP ring (видна пока запас не полон)
+    let stamina_mesh = meshes.add(Annulus::new(0.84, 0.9)); // Будет заменён на arc в первом кадре
+    let stamina_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.5, 0.95, 0.4, 0.0),
+        emissive: LinearRgba::new(0.4, 1.0, 0.3, 0.0) * 2.0,
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    });
+    let stamina_ring = commands.spawn((
+        Mesh3d(stamina_mesh),
+        MeshMaterial3d(stamina_material.clone()),
+        Transform::from_xyz(0.0, -0.888, 0.0)
+            .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+        CooldownRing {
+            source: CooldownSource::Stamina,
+            inner_radius: 0.84,
+            outer_radius: 0.9,
+            material_handle: stamina_material,
+            last_fraction: -1.0,
+            last_facing: 0.0,
+            last_alpha: 0.0,
+        },
+    )).id();
+
     // Point light — тёплый свет вокруг игрока для контраста с тёмным полом
     let player_light = commands.spawn((
         PointLight {
@@ -162,9 +220,11 @@ pub fn spawn_player(
     commands.entity(player_entity).add_child(model_child);
     commands.entity(player_entity).add_child(ground_circle);
     commands.entity(player_entity).add_child(cooldown_ring);
+    commands.entity(player_entity).add_child(dash_ring);
+    commands.entity(player_entity).add_child(stamina_ring);
     commands.entity(player_entity).add_child(player_light);
 
-    info!("✅ Created Player entity with PlayerModel child + ground circle + cooldown ring + light");
+    info!("✅ Created Player entity with PlayerModel child + ground circle + cooldown/dash/stamina rings + light");
 }
 
 /// Настройка AnimationPlayer после загрузки GLB.
@@ -179,6 +239,8 @@ pub fn setup_scene_animation(
         (With<AnimationPlayer>, Without<AnimationSetupComplete>, Without<PlayerModel>)
     >,
     children: Query<&Children>,
+    bones: Query<(&Name, Option<&AnimationTargetId>)>,
+    mut graphs: ResMut<Assets<AnimationGraph>>,
     mut commands: Commands,
 ) {
     for player_children in &player {
@@ -187,12 +249,14 @@ pub fn setup_scene_animation(
                 // Ищем AnimationPlayer на глубине 1-2 от PlayerModel
                 for &child in model_children {
                     if let Ok(entity) = animation_players.get(child) {
+                        assign_body_mask_groups(entity, graph_handle, &children, &bones, &mut graphs);
                         setup_anim_components(entity, anim_indices, graph_handle, model_child, &mut commands);
                         return;
                     }
                     if let Ok(grandchildren) = children.get(child) {
                         for &grandchild in grandchildren {
                             if let Ok(entity) = animation_players.get(grandchild) {
+                                assign_body_mask_groups(entity, graph_handle, &children, &bones, &mut graphs);
                                 setup_anim_components(entity, anim_indices, graph_handle, model_child, &mut commands);
                                 return;
                             }
@@ -204,6 +268,39 @@ pub fn setup_scene_animation(
     }
 }
 
+/// Раскладывает кости по mask-группам графа: всё от первой кости "Spine*" вниз по иерархии —
+/// верх тела, остальное (Hips, ноги) — низ. Каждая кость обязана попасть в группу:
+/// кость без группы анимировали бы оба слоя сразу.
+fn assign_body_mask_groups(
+    animation_player: Entity,
+    graph_handle: &AnimationGraphHandle,
+    children: &Query<&Children>,
+    bones: &Query<(&Name, Option<&AnimationTargetId>)>,
+    graphs: &mut Assets<AnimationGraph>,
+) {
+    let Some(graph) = graphs.get_mut(&graph_handle.0) else { return };
+    let mut stack = vec![(animation_player, false)];
+    let (mut upper_count, mut lower_count) = (0, 0);
+    while let Some((entity, parent_upper)) = stack.pop() {
+        let mut upper = parent_upper;
+        if let Ok((name, target)) = bones.get(entity) {
+            upper |= name.as_str().to_lowercase().starts_with("spine");
+            if let Some(&target) = target {
+                let group = if upper { UPPER_BODY_GROUP } else { LOWER_BODY_GROUP };
+                graph.add_target_to_mask_group(target, group);
+                if upper { upper_count += 1 } else { lower_count += 1 }
+            }
+        }
+        if let Ok(kids) = children.get(entity) {
+            stack.extend(kids.iter().map(|kid| (kid, upper)));
+        }
+    }
+    if upper_count == 0 {
+        warn!("⚠️ Кость Spine не найдена — верхний слой анимаций не отделён от ног");
+    }
+    info!("🦴 Body mask: {} upper bones, {} lower bones", upper_count, lower_count);
+}
+
 /// Вставляет компоненты анимации на AnimationPlayer entity через deferred commands.
 /// НЕ вызывает transitions.play() — это делает play_initial_animation на следующем кадре,
 /// когда AnimationGraphHandle уже на entity.
@@ -214,18 +311,10 @@ fn setup_anim_components(
     model_child: Entity,
     commands: &mut Commands,
 ) {
-    let animations = PlayerAnimations {
-        idle: anim_indices.idle,
-        walk: anim_indices.walk,
-        run: anim_indices.run,
-        attack: anim_indices.attack,
-        hit: anim_indices.hit,
-    };
-
     commands.entity(entity).insert((
-        animations,
+        anim_indices.0,
         graph_handle.clone(),
-        AnimationTransitions::new(),
+        PlayerAnimLayers::default(),
         AnimationSetupComplete,
     ));
 
@@ -234,16 +323,19 @@ fn setup_anim_components(
     info!("🎬 Animation setup queued on {:?}", entity);
 }
 
-/// Запускает idle анимацию на кадре ПОСЛЕ setup — когда AnimationGraphHandle
-/// и AnimationTransitions уже на entity (deferred commands применены).
+/// Запускает локомоцию обоих слоёв на кадре ПОСЛЕ setup — когда AnimationGraphHandle
+/// уже на entity (deferred commands применены). Все 6 циклов крутятся всегда и стартуют
+/// одновременно (фаза верха и низа совпадает), blend space меняет только веса.
 pub fn play_initial_animation(
-    mut query: Query<
-        (&PlayerAnimations, &mut AnimationPlayer, &mut AnimationTransitions),
-        Added<AnimationSetupComplete>,
-    >,
+    mut query: Query<(&PlayerAnimations, &mut AnimationPlayer), Added<AnimationSetupComplete>>,
 ) {
-    for (animations, mut player, mut transitions) in &mut query {
-        transitions.play(&mut player, animations.idle, Duration::ZERO).repeat();
-        info!("🎬 Player idle animation started");
+    for (animations, mut player) in &mut query {
+        for (index, weight) in [
+            (animations.idle, 1.0), (animations.walk, 0.0), (animations.run, 0.0),
+            (animations.idle_upper, 1.0), (animations.walk_upper, 0.0), (animations.run_upper, 0.0),
+        ] {
+            player.play(index).repeat().set_weight(weight);
+        }
+        info!("🎬 Player locomotion layers started");
     }
 }
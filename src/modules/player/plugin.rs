@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use crate::shared::GameState;
-use super::parts::{spawner, movement, animation, cleanup, weapon_attachment};
+use super::parts::{spawner, movement, animation, cleanup, weapon_attachment, dash};
 
 pub struct PlayerPlugin;
 
@@ -11,12 +11,17 @@ impl Plugin for PlayerPlugin {
                 cleanup::despawn_player,
                 cleanup::reset_input,
                 spawner::spawn_player,
+                dash::init_dash_assets,
             ).chain())
+            .add_systems(OnExit(GameState::Playing), dash::cleanup_afterimages)
             .add_systems(Update, (
                 spawner::setup_scene_animation,
                 spawner::play_initial_animation,
                 weapon_attachment::attach_weapon_to_hand,
                 movement::player_movement_system,
+                dash::start_dash_system,
+                dash::dashing_system,
+                dash::afterimage_fade_system,
                 animation::animation_state_system,
                 animation::player_hit_stagger_system,
                 animation::stagger_cooldown_system,
@@ -74,6 +74,7 @@ pub enum UpgradeId {
     OberegSvaroga,
     SlezaLady,
     ZnakVolka,
+    DyhanieStriboga,
 }
 
 /// Категория апгрейда
@@ -4,3 +4,4 @@ pub(crate) mod parts;
 
 pub use plugin::ProgressionPlugin;
 pub use components::{PlayerXp, XpOrb, HpOrb, UpgradeInventory, LevelUpState, UpgradeId};
+pub use parts::meta::MetaProgress;
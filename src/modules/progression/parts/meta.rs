@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::modules::enemies::components::WaveState;
+
+/// Ключ localStorage (wasm) / имя файла рядом с игрой (native)
+const SAVE_KEY: &str = "chertogon_meta";
+
+/// Прогресс между забегами: открывает новые биомы
+#[derive(Resource, Serialize, Deserialize, Default, Clone, Debug)]
+#[serde(default)]
+pub struct MetaProgress {
+    /// Лучшая достигнутая волна
+    pub best_wave: u32,
+    pub runs: u32,
+}
+
+/// Читаем сохранение при сборке приложения — титульный экран (OnEnter до Startup)
+/// уже видит открытые биомы. Нет или битое — начинаем с нуля.
+pub fn load_meta_progress() -> MetaProgress {
+    let meta: MetaProgress = read_save()
+        .and_then(|text| ron::from_str(&text).ok())
+        .unwrap_or_default();
+    info!("🏆 Meta progress: best wave {}, {} runs", meta.best_wave, meta.runs);
+    meta
+}
+
+/// OnEnter(GameOver): засчитываем забег и сохраняем
+pub fn record_run_system(
+    wave: Res<WaveState>,
+    mut meta: ResMut<MetaProgress>,
+) {
+    meta.runs += 1;
+    if wave.current_wave > meta.best_wave {
+        meta.best_wave = wave.current_wave;
+        info!("🏆 New best wave: {}", meta.best_wave);
+    }
+    match ron::to_string(&*meta) {
+        Ok(text) => write_save(&text),
+        Err(err) => warn!("⚠️ Meta progress not saved: {}", err),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_save() -> Option<String> {
+    std::fs::read_to_string(format!("{SAVE_KEY}.ron")).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write_save(text: &str) {
+    if let Err(err) = std::fs::write(format!("{SAVE_KEY}.ron"), text) {
+        warn!("⚠️ Meta progress not saved: {}", err);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_save() -> Option<String> {
+    web_sys::window()?.local_storage().ok()??.get_item(SAVE_KEY).ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write_save(text: &str) {
+    let storage = web_sys::window().and_then(|window| window.local_storage().ok().flatten());
+    if storage.is_none_or(|storage| storage.set_item(SAVE_KEY, text).is_err()) {
+        warn!("⚠️ Meta progress not saved: localStorage unavailable");
+    }
+}
@@ -4,3 +4,4 @@ pub mod level_up;
 pub mod level_up_ui;
 pub mod upgrades;
 pub mod hp_regen;
+pub mod meta;
@@ -5,6 +5,9 @@ use crate::modules::combat::components::PlayerHealth;
 use crate::modules::combat::parts::vfx_assets::HitVfxAssets;
 use crate::modules::progression::components::{XpOrb, HpOrb, PlayerXp};
 use crate::shared::rand_01;
+use crate::modules::spatial::{SpatialIndex, SpatialIndexed, SpatialLayer};
+use crate::modules::world::SafeZone;
+use crate::modules::shrines::ActiveBuffs;
 use super::orb_assets::OrbAssets;
 
 // ── Спавн орбов при смерти врагов ──
@@ -23,55 +26,94 @@ pub fn spawn_orbs_on_enemy_death(
             EnemyType::Upyr => 10.0,
             EnemyType::Leshiy => 15.0,
             EnemyType::Volkolak => 12.0,
+            EnemyType::Koldun => 20.0,
         };
 
         let pos = transform.translation;
 
         // XP орб — всегда
-        let offset = Vec3::new(
-            (rand_01() - 0.5) * 1.0,
-            0.5 + rand_01() * 0.5,
-            (rand_01() - 0.5) * 1.0,
-        );
-
-        commands.spawn((
-            Mesh3d(orb_assets.xp_mesh.clone()),
-            MeshMaterial3d(orb_assets.xp_material.clone()),
-            Transform::from_translation(pos + Vec3::Y * 0.5),
-            XpOrb {
-                xp_value,
-                magnetized: false,
-                age: 0.0,
-                spawn_offset: offset,
-            },
-        ));
+        spawn_xp_orb(&mut commands, &orb_assets, pos, xp_value);
 
         // HP орб — 5% шанс
         if rand_01() < 0.05 {
-            let hp_offset = Vec3::new(
-                (rand_01() - 0.5) * 1.0,
-                0.5 + rand_01() * 0.5,
-                (rand_01() - 0.5) * 1.0,
-            );
-
-            commands.spawn((
-                Mesh3d(orb_assets.hp_mesh.clone()),
-                MeshMaterial3d(orb_assets.hp_material.clone()),
-                Transform::from_translation(pos + Vec3::Y * 0.5),
-                HpOrb {
-                    heal_amount: 10.0,
-                    magnetized: false,
-                    age: 0.0,
-                    spawn_offset: hp_offset,
-                },
-            ));
+            spawn_hp_orb(&mut commands, &orb_assets, pos, 10.0);
         }
     }
 }
 
+/// XP орб в точке: выброс со случайным разбросом, дальше bobbing и магнит
+pub fn spawn_xp_orb(commands: &mut Commands, orb_assets: &OrbAssets, pos: Vec3, xp_value: f32) {
+    commands.spawn((
+        Mesh3d(orb_assets.xp_mesh.clone()),
+        MeshMaterial3d(orb_assets.xp_material.clone()),
+        Transform::from_translation(pos + Vec3::Y * 0.5),
+        XpOrb {
+            xp_value,
+            magnetized: false,
+            age: 0.0,
+            spawn_offset: orb_spawn_offset(),
+        },
+        SpatialIndexed(SpatialLayer::XpOrb),
+    ));
+}
+
+/// HP орб в точке
+pub fn spawn_hp_orb(commands: &mut Commands, orb_assets: &OrbAssets, pos: Vec3, heal_amount: f32) {
+    commands.spawn((
+        Mesh3d(orb_assets.hp_mesh.clone()),
+        MeshMaterial3d(orb_assets.hp_material.clone()),
+        Transform::from_translation(pos + Vec3::Y * 0.5),
+        HpOrb {
+            heal_amount,
+            magnetized: false,
+            age: 0.0,
+            spawn_offset: orb_spawn_offset(),
+        },
+        SpatialIndexed(SpatialLayer::HpOrb),
+    ));
+}
+
+fn orb_spawn_offset() -> Vec3 {
+    Vec3::new(
+        (rand_01() - 0.5) * 1.0,
+        0.5 + rand_01() * 0.5,
+        (rand_01() - 0.5) * 1.0,
+    )
+}
+
+// ── Магнит ──
+
+/// Магнит: орбы в радиусе магнита (из SpatialIndex) начинают лететь к игроку.
+/// Притянутый орб выпадает из индекса — дальше его ведёт только физика полёта.
+pub fn orb_magnet_system(
+    mut commands: Commands,
+    index: Res<SpatialIndex>,
+    player_xp: Res<PlayerXp>,
+    player_query: Query<&Transform, With<Player>>,
+    mut xp_orbs: Query<&mut XpOrb>,
+    mut hp_orbs: Query<&mut HpOrb>,
+) {
+    let Ok(player_tf) = player_query.single() else { return };
+    let player_pos = player_tf.translation;
+    let magnet_radius = player_xp.magnet_radius;
+
+    index.for_each_in_radius(SpatialLayer::XpOrb, player_pos, magnet_radius, |entity, _| {
+        if let Ok(mut orb) = xp_orbs.get_mut(entity) {
+            orb.magnetized = true;
+            commands.entity(entity).remove::<SpatialIndexed>();
+        }
+    });
+    index.for_each_in_radius(SpatialLayer::HpOrb, player_pos, magnet_radius, |entity, _| {
+        if let Ok(mut orb) = hp_orbs.get_mut(entity) {
+            orb.magnetized = true;
+            commands.entity(entity).remove::<SpatialIndexed>();
+        }
+    });
+}
+
 // ── Физика и сбор XP орбов ──
 
-/// Движение XP орбов: выброс → bobbing → магнит → сбор
+/// Движение XP орбов: выброс → bobbing → (магнит: orb_magnet_system) → полёт → сбор
 pub fn xp_orb_physics_system(
     time: Res<Time>,
     player_query: Query<&Transform, With<Player>>,
@@ -79,13 +121,13 @@ pub fn xp_orb_physics_system(
     mut commands: Commands,
     mut xp_res: ResMut<PlayerXp>,
     vfx_assets: Res<HitVfxAssets>,
+    buffs: Res<ActiveBuffs>,
 ) {
     let dt = time.delta_secs();
     if dt == 0.0 { return; } // Игра на паузе
 
     let Ok(player_tf) = player_query.single() else { return };
     let player_pos = player_tf.translation;
-    let magnet_radius = xp_res.magnet_radius;
 
     for (entity, mut orb, mut transform) in &mut orbs {
         orb.age += dt;
@@ -101,12 +143,6 @@ pub fn xp_orb_physics_system(
             // Bobbing на месте
             let bob_y = 0.5 + (orb.age * 3.0).sin() * 0.15;
             transform.translation.y = transform.translation.y * 0.95 + bob_y * 0.05;
-
-            // Проверяем магнит
-            let dist = (transform.translation - player_pos).length();
-            if dist < magnet_radius {
-                orb.magnetized = true;
-            }
         } else {
             // Фаза 2: летим к игроку (ускоряющийся lerp)
             let dir = (player_pos - transform.translation).normalize_or_zero();
@@ -116,9 +152,11 @@ pub fn xp_orb_physics_system(
             // Сбор при контакте
             let dist = (transform.translation - player_pos).length();
             if dist < 0.8 {
-                xp_res.add_xp(orb.xp_value);
+                // Двойной опыт святилища — множитель на сбор
+                let xp = orb.xp_value * buffs.xp_multiplier();
+                xp_res.add_xp(xp);
                 // Зелёный "+N XP" floating text
-                spawn_xp_text(&mut commands, &vfx_assets.font, player_pos, orb.xp_value);
+                spawn_xp_text(&mut commands, &vfx_assets.font, player_pos, xp);
                 commands.entity(entity).despawn();
             }
         }
@@ -127,10 +165,9 @@ pub fn xp_orb_physics_system(
 
 // ── Физика и сбор HP орбов ──
 
-/// Движение HP орбов: выброс → bobbing → магнит → сбор
+/// Движение HP орбов: выброс → bobbing → (магнит: orb_magnet_system) → полёт → сбор
 pub fn hp_orb_physics_system(
     time: Res<Time>,
-    player_xp: Res<PlayerXp>,
     player_query: Query<&Transform, With<Player>>,
     mut player_health: Query<&mut PlayerHealth, With<Player>>,
     mut orbs: Query<(Entity, &mut HpOrb, &mut Transform), (Without<Player>, Without<XpOrb>)>,
@@ -142,7 +179,6 @@ pub fn hp_orb_physics_system(
 
     let Ok(player_tf) = player_query.single() else { return };
     let player_pos = player_tf.translation;
-    let magnet_radius = player_xp.magnet_radius;
 
     for (entity, mut orb, mut transform) in &mut orbs {
         orb.age += dt;
@@ -156,11 +192,6 @@ pub fn hp_orb_physics_system(
 
             let bob_y = 0.5 + (orb.age * 2.5).sin() * 0.15;
             transform.translation.y = transform.translation.y * 0.95 + bob_y * 0.05;
-
-            let dist = (transform.translation - player_pos).length();
-            if dist < magnet_radius {
-                orb.magnetized = true;
-            }
         } else {
             let dir = (player_pos - transform.translation).normalize_or_zero();
             let speed = 8.0 + orb.age * 10.0;
@@ -179,6 +210,41 @@ pub fn hp_orb_physics_system(
     }
 }
 
+// ── Туман Нави ──
+
+/// Скорость, с которой туман выталкивает орбы (м/с)
+const FOG_PUSH_SPEED: f32 = 4.0;
+/// Орбы оседают чуть внутри границы — их можно подобрать, не заходя в туман
+const FOG_PUSH_INSET: f32 = 1.0;
+
+/// Сжимающийся туман выталкивает лежащие орбы к центру безопасной зоны
+pub fn orb_safe_zone_push_system(
+    time: Res<Time>,
+    zone: Res<SafeZone>,
+    mut orbs: Query<(&mut Transform, AnyOf<(&XpOrb, &HpOrb)>), Without<Player>>,
+) {
+    if !zone.is_bounded() {
+        return;
+    }
+    let rest_radius = (zone.radius - FOG_PUSH_INSET).max(0.0);
+
+    for (mut transform, (xp, hp)) in &mut orbs {
+        let magnetized = xp.is_some_and(|orb| orb.magnetized) || hp.is_some_and(|orb| orb.magnetized);
+        if magnetized {
+            continue;
+        }
+        let offset = Vec2::new(transform.translation.x, transform.translation.z) - zone.center;
+        let dist = offset.length();
+        if dist <= rest_radius {
+            continue;
+        }
+        let step = (FOG_PUSH_SPEED * time.delta_secs()).min(dist - rest_radius);
+        let dir = offset / dist;
+        transform.translation.x -= dir.x * step;
+        transform.translation.z -= dir.y * step;
+    }
+}
+
 // ── Cleanup ──
 
 /// Удаляет все орбы при входе в Playing (новый ран)
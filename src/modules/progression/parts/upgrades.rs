@@ -38,10 +38,17 @@ pub fn all_upgrades() -> Vec<UpgradeDef> {
         UpgradeDef {
             id: UpgradeId::ZnakVolka,
             name: "Знак Волка",
-            description: "+5% скорость",
+            description: "+5% скорость, +10 выносливости",
             category: UpgradeCategory::Path,
             max_level: 5,
         },
+        UpgradeDef {
+            id: UpgradeId::DyhanieStriboga,
+            name: "Дыхание Стрибога",
+            description: "+20 выносливости, +25% восстановление",
+            category: UpgradeCategory::Path,
+            max_level: 3,
+        },
     ]
 }
 
@@ -97,8 +104,14 @@ pub fn apply_upgrade(
             // HP regen обрабатывается отдельной системой hp_regen_system
         }
         UpgradeId::ZnakVolka => {
-            // +5% movement speed
+            // +5% movement speed + 10 max stamina
             player_stats.move_speed_multiplier *= 1.05;
+            player_stats.max_stamina += 10.0;
+        }
+        UpgradeId::DyhanieStriboga => {
+            // +20 max stamina, +25% stamina regen
+            player_stats.max_stamina += 20.0;
+            player_stats.stamina_regen *= 1.25;
         }
     }
 }
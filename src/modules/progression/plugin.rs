@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use crate::shared::GameState;
-use super::parts::{orb_assets, orbs, level_up, level_up_ui, hp_regen};
+use crate::modules::spatial::SpatialIndexSet;
+use super::parts::{orb_assets, orbs, level_up, level_up_ui, hp_regen, meta};
 use super::components::{PlayerXp, UpgradeInventory, LevelUpState};
 
 pub struct ProgressionPlugin;
@@ -11,6 +12,9 @@ impl Plugin for ProgressionPlugin {
             .init_resource::<PlayerXp>()
             .init_resource::<UpgradeInventory>()
             .init_resource::<LevelUpState>()
+            // Прогресс между забегами (открытие биомов)
+            .insert_resource(meta::load_meta_progress())
+            .add_systems(OnEnter(GameState::GameOver), meta::record_run_system)
             // Инициализация при входе в Playing
             .add_systems(OnEnter(GameState::Playing), (
                 orb_assets::init_orb_assets,
@@ -21,11 +25,13 @@ impl Plugin for ProgressionPlugin {
             // Основные системы (работают в Playing)
             .add_systems(Update, (
                 orbs::spawn_orbs_on_enemy_death,
+                orbs::orb_magnet_system,
                 orbs::xp_orb_physics_system,
                 orbs::hp_orb_physics_system,
+                orbs::orb_safe_zone_push_system,
                 level_up::check_level_up_system,
                 hp_regen::hp_regen_system,
-            ).chain().run_if(in_state(GameState::Playing)))
+            ).chain().after(SpatialIndexSet).run_if(in_state(GameState::Playing)))
             // Level-up UI (работает даже на паузе — виртуальное время на паузе, но Update крутится)
             .add_systems(Update, (
                 level_up_ui::spawn_level_up_ui,
@@ -243,11 +243,13 @@ fn build_enemy_panel(
         EnemyType::Upyr => "Упырь",
         EnemyType::Leshiy => "Леший",
         EnemyType::Volkolak => "Волколак",
+        EnemyType::Koldun => "Колдун",
     };
     let hp_color = match enemy_type {
         EnemyType::Upyr => Color::srgb(0.8, 0.15, 0.1),
         EnemyType::Leshiy => Color::srgb(0.15, 0.7, 0.2),
         EnemyType::Volkolak => Color::srgb(0.5, 0.5, 0.65),
+        EnemyType::Koldun => Color::srgb(0.6, 0.2, 0.9),
     };
     let stats = vec![
         ("damage", format!("{:.0}", attack_cd.damage), asset_paths::ICON_DAMAGE),
@@ -298,6 +300,7 @@ pub fn update_selection_panel(
                 EnemyType::Upyr => Color::srgb(0.8, 0.15, 0.1),
                 EnemyType::Leshiy => Color::srgb(0.15, 0.7, 0.2),
                 EnemyType::Volkolak => Color::srgb(0.5, 0.5, 0.65),
+                EnemyType::Koldun => Color::srgb(0.6, 0.2, 0.9),
             };
             (health.current, health.max, color)
         } else if let Ok(health) = players.get(selected) {
@@ -7,6 +7,7 @@ pub fn portrait_for_enemy(enemy_type: &EnemyType) -> &'static str {
         EnemyType::Upyr => asset_paths::PORTRAIT_UPYR,
         EnemyType::Leshiy => asset_paths::PORTRAIT_LESHIY,
         EnemyType::Volkolak => asset_paths::PORTRAIT_VOLKOLAK,
+        EnemyType::Koldun => asset_paths::PORTRAIT_UPYR, // Отдельного портрета пока нет — модель Упыря
     }
 }
 
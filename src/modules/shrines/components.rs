@@ -0,0 +1,131 @@
+use bevy::prelude::*;
+
+/// Рунное святилище: копит заряд (свет разгорается), заряженное — даёт бафф
+/// герою, простоявшему рядом SHRINE_DWELL секунд, затем остывает
+#[derive(Component)]
+pub struct RuneShrine {
+    pub state: ShrineState,
+    pub timer: Timer,
+    /// Сколько герой уже стоит рядом с заряженным святилищем (с)
+    pub dwell: f32,
+    /// Свет рун (child декора) и его полная яркость из раскладки
+    pub light: Option<Entity>,
+    pub base_intensity: f32,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ShrineState {
+    /// Остывает после использования — руны почти погасли
+    Cooldown,
+    /// Копит силу — свет плавно разгорается
+    Charging,
+    /// Готово — руны пульсируют, ждёт героя
+    Charged,
+}
+
+/// Временный бафф святилища
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BuffKind {
+    /// Быстрота: скорость бега выше
+    Haste,
+    /// Двойной опыт с орбов
+    DoubleXp,
+    /// Аура урона: жжёт врагов вокруг героя
+    DamageAura,
+    /// Магнит: все орбы арены летят к герою
+    MagnetPulse,
+}
+
+impl BuffKind {
+    pub const ALL: [BuffKind; 4] = [BuffKind::Haste, BuffKind::DoubleXp, BuffKind::DamageAura, BuffKind::MagnetPulse];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BuffKind::Haste => "Быстрота",
+            BuffKind::DoubleXp => "Двойной опыт",
+            BuffKind::DamageAura => "Аура урона",
+            BuffKind::MagnetPulse => "Магнит",
+        }
+    }
+
+    /// Короткая метка для иконки в HUD
+    pub fn label(&self) -> &'static str {
+        match self {
+            BuffKind::Haste => "Б",
+            BuffKind::DoubleXp => "×2",
+            BuffKind::DamageAura => "А",
+            BuffKind::MagnetPulse => "М",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            BuffKind::Haste => Color::srgb(0.3, 0.8, 1.0),
+            BuffKind::DoubleXp => Color::srgb(0.2, 1.0, 0.5),
+            BuffKind::DamageAura => Color::srgb(1.0, 0.4, 0.2),
+            BuffKind::MagnetPulse => Color::srgb(0.8, 0.5, 1.0),
+        }
+    }
+
+    /// Длительность (с)
+    pub fn duration(&self) -> f32 {
+        match self {
+            BuffKind::Haste => 12.0,
+            BuffKind::DoubleXp => 20.0,
+            BuffKind::DamageAura => 10.0,
+            BuffKind::MagnetPulse => 4.0,
+        }
+    }
+}
+
+/// Бафф с таймером
+pub struct ActiveBuff {
+    pub kind: BuffKind,
+    pub timer: Timer,
+}
+
+/// Активные баффы героя (повторный бафф того же вида обновляет таймер)
+#[derive(Resource, Default)]
+pub struct ActiveBuffs {
+    pub buffs: Vec<ActiveBuff>,
+}
+
+impl ActiveBuffs {
+    pub fn has(&self, kind: BuffKind) -> bool {
+        self.buffs.iter().any(|buff| buff.kind == kind)
+    }
+
+    pub fn get(&self, kind: BuffKind) -> Option<&ActiveBuff> {
+        self.buffs.iter().find(|buff| buff.kind == kind)
+    }
+
+    pub fn grant(&mut self, kind: BuffKind) {
+        let timer = Timer::from_seconds(kind.duration(), TimerMode::Once);
+        match self.buffs.iter_mut().find(|buff| buff.kind == kind) {
+            Some(buff) => buff.timer = timer,
+            None => self.buffs.push(ActiveBuff { kind, timer }),
+        }
+    }
+
+    /// Множитель скорости героя (Быстрота)
+    pub fn speed_multiplier(&self) -> f32 {
+        if self.has(BuffKind::Haste) { 1.35 } else { 1.0 }
+    }
+
+    /// Множитель опыта с орбов (Двойной опыт)
+    pub fn xp_multiplier(&self) -> f32 {
+        if self.has(BuffKind::DoubleXp) { 2.0 } else { 1.0 }
+    }
+}
+
+/// Статистика святилищ за забег (экран Game Over)
+#[derive(Resource, Default)]
+pub struct ShrineStats {
+    pub activations: u32,
+    /// По видам баффа — индекс как в BuffKind::ALL
+    pub by_kind: [u32; 4],
+}
+
+/// Видимый круг ауры урона под героем
+#[derive(Component)]
+pub struct DamageAuraVfx;
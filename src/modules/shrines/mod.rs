@@ -0,0 +1,6 @@
+pub mod components;
+pub mod plugin;
+pub(crate) mod parts;
+
+pub use plugin::ShrinesPlugin;
+pub use components::{ActiveBuffs, BuffKind, ShrineStats};
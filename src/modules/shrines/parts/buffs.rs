@@ -0,0 +1,132 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use bevy::light::NotShadowCaster;
+use std::f32::consts::FRAC_PI_2;
+use crate::modules::player::components::Player;
+use crate::modules::enemies::components::{Enemy, Health, EnemyDying};
+use crate::modules::combat::parts::damage_numbers;
+use crate::modules::combat::parts::vfx_assets::HitVfxAssets;
+use crate::modules::progression::components::{XpOrb, HpOrb};
+use crate::modules::spatial::{SpatialIndex, SpatialLayer};
+use crate::modules::shrines::components::{ActiveBuffs, BuffKind, DamageAuraVfx};
+
+const AURA_RADIUS: f32 = 3.5;
+const AURA_TICK: f32 = 0.5;
+const AURA_DAMAGE: f32 = 8.0;
+
+/// Тикает таймеры баффов, истёкшие снимает
+pub fn buff_timer_system(time: Res<Time>, mut buffs: ResMut<ActiveBuffs>) {
+    if buffs.buffs.is_empty() {
+        return;
+    }
+    for buff in &mut buffs.buffs {
+        buff.timer.tick(time.delta());
+    }
+    buffs.buffs.retain(|buff| !buff.timer.is_finished());
+}
+
+/// Кэшированный круг ауры под героем (создаётся один раз на Startup)
+#[derive(Resource)]
+pub struct AuraAssets {
+    pub disc_mesh: Handle<Mesh>,
+    pub disc_material: Handle<StandardMaterial>,
+}
+
+/// Инициализация ассетов ауры
+pub fn init_aura_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(AuraAssets {
+        disc_mesh: meshes.add(Circle::new(AURA_RADIUS)),
+        disc_material: materials.add(StandardMaterial {
+            base_color: Color::srgba(1.0, 0.35, 0.1, 0.18),
+            emissive: LinearRgba::new(1.2, 0.35, 0.05, 1.0),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        }),
+    });
+}
+
+/// Цели ауры: соседи героя из SpatialIndex и их здоровье
+#[derive(SystemParam)]
+pub struct AuraTargets<'w, 's> {
+    index: Res<'w, SpatialIndex>,
+    enemies: Query<'w, 's, &'static mut Health, (With<Enemy>, Without<EnemyDying>)>,
+}
+
+/// Светящийся круг под героем, пока аура урона активна
+pub fn damage_aura_vfx_system(
+    mut commands: Commands,
+    buffs: Res<ActiveBuffs>,
+    assets: Res<AuraAssets>,
+    player: Query<Entity, With<Player>>,
+    vfx: Query<Entity, With<DamageAuraVfx>>,
+) {
+    if !buffs.has(BuffKind::DamageAura) {
+        for entity in &vfx {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    let Ok(player_entity) = player.single() else { return };
+    if vfx.is_empty() {
+        let vfx_entity = commands.spawn((
+            Mesh3d(assets.disc_mesh.clone()),
+            MeshMaterial3d(assets.disc_material.clone()),
+            Transform::from_xyz(0.0, 0.04, 0.0).with_rotation(Quat::from_rotation_x(-FRAC_PI_2)),
+            NotShadowCaster,
+            DamageAuraVfx,
+        )).id();
+        commands.entity(player_entity).add_child(vfx_entity);
+    }
+}
+
+/// Аура урона: раз в AURA_TICK жжёт всех врагов в радиусе вокруг героя
+pub fn damage_aura_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    buffs: Res<ActiveBuffs>,
+    player: Query<&Transform, With<Player>>,
+    targets: AuraTargets,
+    vfx_assets: Res<HitVfxAssets>,
+    mut since_tick: Local<f32>,
+) {
+    let AuraTargets { index, mut enemies } = targets;
+    let Ok(player_tf) = player.single() else { return };
+    if !buffs.has(BuffKind::DamageAura) {
+        *since_tick = 0.0;
+        return;
+    }
+
+    *since_tick += time.delta_secs();
+    if *since_tick < AURA_TICK {
+        return;
+    }
+    *since_tick -= AURA_TICK;
+
+    index.for_each_in_radius(SpatialLayer::Enemy, player_tf.translation, AURA_RADIUS, |enemy, pos| {
+        let Ok(mut health) = enemies.get_mut(enemy) else { return };
+        health.take_damage(AURA_DAMAGE);
+        damage_numbers::spawn_damage_number(&mut commands, &vfx_assets.font, pos, AURA_DAMAGE);
+    });
+}
+
+/// Магнит: пока действует — все орбы арены летят к герою
+pub fn magnet_pulse_system(
+    buffs: Res<ActiveBuffs>,
+    mut xp_orbs: Query<&mut XpOrb>,
+    mut hp_orbs: Query<&mut HpOrb>,
+) {
+    if !buffs.has(BuffKind::MagnetPulse) {
+        return;
+    }
+    for mut orb in &mut xp_orbs {
+        orb.magnetized = true;
+    }
+    for mut orb in &mut hp_orbs {
+        orb.magnetized = true;
+    }
+}
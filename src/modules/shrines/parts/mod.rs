@@ -0,0 +1,6 @@
+//! Реализация святилищ:
+//! - shrine: цикл рунного камня (остывание → заряд → готово), бафф за стояние рядом, статистика
+//! - buffs: таймеры баффов, аура урона вокруг героя (круг под ним — из кэша AuraAssets), магнит для орбов
+
+pub mod shrine;
+pub mod buffs;
@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+use crate::modules::player::components::Player;
+use crate::modules::combat::parts::impact_flash;
+use crate::modules::combat::parts::vfx_assets::HitVfxAssets;
+use crate::modules::world::ShrineProp;
+use crate::modules::shrines::components::{RuneShrine, ShrineState, BuffKind, ActiveBuffs, ShrineStats};
+use crate::shared::rand_01;
+
+/// Первый заряд в начале забега (с) — у каждого святилища свой, вразнобой
+const FIRST_CHARGE_MIN: f32 = 20.0;
+const FIRST_CHARGE_SPREAD: f32 = 20.0;
+/// Накопление силы после остывания (с)
+const CHARGE_TIME: f32 = 20.0;
+/// Остывание после баффа (с)
+const COOLDOWN_TIME: f32 = 25.0;
+/// Сколько стоять рядом с заряженным святилищем, чтобы получить бафф (с)
+const SHRINE_DWELL: f32 = 1.0;
+const SHRINE_RADIUS: f32 = 2.2;
+/// Яркость рун в долях от полной: остывшие / начало заряда
+const COOLDOWN_LIGHT: f32 = 0.08;
+const CHARGE_START_LIGHT: f32 = 0.15;
+
+/// Навешивает поведение на святилища, расставленные scatter (свет — child декора)
+pub fn init_shrines_system(
+    mut commands: Commands,
+    new_shrines: Query<(Entity, &Children), Added<ShrineProp>>,
+    lights: Query<&PointLight>,
+) {
+    for (entity, children) in &new_shrines {
+        let light = children.iter().find(|&child| lights.contains(child));
+        let base_intensity = light
+            .and_then(|light| lights.get(light).ok())
+            .map_or(0.0, |light| light.intensity);
+        commands.entity(entity).insert(RuneShrine {
+            state: ShrineState::Charging,
+            timer: Timer::from_seconds(first_charge_time(), TimerMode::Once),
+            dwell: 0.0,
+            light,
+            base_intensity,
+        });
+    }
+}
+
+fn first_charge_time() -> f32 {
+    FIRST_CHARGE_MIN + rand_01() * FIRST_CHARGE_SPREAD
+}
+
+/// Новый забег: баффы и статистика с нуля, святилища снова копят первый заряд
+pub fn reset_shrines(
+    mut buffs: ResMut<ActiveBuffs>,
+    mut stats: ResMut<ShrineStats>,
+    mut shrines: Query<&mut RuneShrine>,
+) {
+    buffs.buffs.clear();
+    *stats = ShrineStats::default();
+    for mut shrine in &mut shrines {
+        shrine.state = ShrineState::Charging;
+        shrine.timer = Timer::from_seconds(first_charge_time(), TimerMode::Once);
+        shrine.dwell = 0.0;
+    }
+}
+
+/// Цикл святилища: остывание → заряд (свет разгорается) → заряжено (пульсирует).
+/// Пока герой стоит у заряженного — руны вспыхивают сильнее.
+pub fn shrine_charge_system(
+    time: Res<Time>,
+    mut shrines: Query<&mut RuneShrine>,
+    mut lights: Query<&mut PointLight>,
+) {
+    let t = time.elapsed_secs();
+    for mut shrine in &mut shrines {
+        shrine.timer.tick(time.delta());
+        if shrine.timer.just_finished() {
+            match shrine.state {
+                ShrineState::Cooldown => {
+                    shrine.state = ShrineState::Charging;
+                    shrine.timer = Timer::from_seconds(CHARGE_TIME, TimerMode::Once);
+                }
+                ShrineState::Charging => shrine.state = ShrineState::Charged,
+                ShrineState::Charged => {}
+            }
+        }
+
+        let level = match shrine.state {
+            ShrineState::Cooldown => COOLDOWN_LIGHT,
+            ShrineState::Charging => {
+                CHARGE_START_LIGHT + (1.0 - CHARGE_START_LIGHT) * shrine.timer.fraction().powi(2)
+            }
+            ShrineState::Charged => {
+                1.0 + 0.25 * (t * 4.0).sin() + shrine.dwell / SHRINE_DWELL
+            }
+        };
+        let Some(light) = shrine.light else { continue };
+        if let Ok(mut light) = lights.get_mut(light) {
+            light.intensity = shrine.base_intensity * level;
+        }
+    }
+}
+
+/// Герой SHRINE_DWELL секунд у заряженного святилища — случайный бафф, святилище остывает
+pub fn shrine_activation_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    player: Query<&Transform, With<Player>>,
+    mut shrines: Query<(&GlobalTransform, &mut RuneShrine)>,
+    mut buffs: ResMut<ActiveBuffs>,
+    mut stats: ResMut<ShrineStats>,
+    vfx_assets: Res<HitVfxAssets>,
+) {
+    let Ok(player_tf) = player.single() else { return };
+    let player_pos = player_tf.translation;
+
+    for (transform, mut shrine) in &mut shrines {
+        if shrine.state != ShrineState::Charged {
+            continue;
+        }
+        let offset = transform.translation() - player_pos;
+        if Vec2::new(offset.x, offset.z).length() > SHRINE_RADIUS {
+            shrine.dwell = 0.0;
+            continue;
+        }
+        shrine.dwell += time.delta_secs();
+        if shrine.dwell < SHRINE_DWELL {
+            continue;
+        }
+
+        let kind = BuffKind::ALL[((rand_01() * BuffKind::ALL.len() as f32) as usize).min(BuffKind::ALL.len() - 1)];
+        buffs.grant(kind);
+        stats.activations += 1;
+        stats.by_kind[kind as usize] += 1;
+
+        shrine.state = ShrineState::Cooldown;
+        shrine.timer = Timer::from_seconds(COOLDOWN_TIME, TimerMode::Once);
+        shrine.dwell = 0.0;
+        impact_flash::spawn_impact_flash(&mut commands, &vfx_assets, transform.translation() + Vec3::Y);
+        info!("🪬 Shrine activated: {} for {:.0}s", kind.name(), kind.duration());
+    }
+}
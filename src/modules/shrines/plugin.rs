@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use crate::shared::GameState;
+use crate::modules::spatial::SpatialIndexSet;
+use super::components::{ActiveBuffs, ShrineStats};
+use super::parts::{shrine, buffs};
+
+pub struct ShrinesPlugin;
+
+impl Plugin for ShrinesPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ActiveBuffs>()
+            .init_resource::<ShrineStats>()
+            .add_systems(Startup, buffs::init_aura_assets)
+            // Святилища расставляет scatter ещё на экране загрузки
+            .add_systems(Update, shrine::init_shrines_system)
+            .add_systems(OnEnter(GameState::Playing), shrine::reset_shrines)
+            .add_systems(Update, (
+                shrine::shrine_charge_system,
+                shrine::shrine_activation_system
+                    .after(shrine::shrine_charge_system),
+                buffs::buff_timer_system
+                    .after(shrine::shrine_activation_system),
+                buffs::damage_aura_system
+                    .after(buffs::buff_timer_system)
+                    .after(SpatialIndexSet),
+                buffs::damage_aura_vfx_system
+                    .after(buffs::buff_timer_system),
+                buffs::magnet_pulse_system
+                    .after(buffs::buff_timer_system),
+            ).run_if(in_state(GameState::Playing)));
+
+        info!("🪬 ShrinesPlugin loaded (rune shrines, timed buffs)");
+    }
+}
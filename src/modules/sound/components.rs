@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+/// Процедурный звук: синтезируется на лету при воспроизведении, файлов в assets/ нет
+#[derive(Asset, TypePath, Clone, Copy, Debug)]
+pub enum SynthAudio {
+    /// Фоновая тема стадии печатей — индекс в MUSIC_STAGES, играет без конца
+    Music(usize),
+    /// Телеграф временного разлома: нарастающий шорох и гул, обрывается к раскрытию
+    RiftTelegraph,
+}
+
+/// Музыка забега: текущая стадия (сколько разломов запечатано, с потолком по числу тем)
+#[derive(Resource, Default)]
+pub struct MusicState {
+    pub stage: usize,
+}
+
+/// Играющая тема; при смене стадии старая затихает и исчезает, новая нарастает
+#[derive(Component)]
+pub struct MusicTrack {
+    pub fading_out: bool,
+}
+
+/// Кэшированные звуки (создаются один раз на Startup)
+#[derive(Resource)]
+pub struct SoundAssets {
+    /// Темы по порядку MUSIC_STAGES
+    pub music: Vec<Handle<SynthAudio>>,
+    pub rift_telegraph: Handle<SynthAudio>,
+}
@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+use bevy::audio::Volume;
+use crate::modules::enemies::TemporaryRift;
+use crate::modules::sound::components::SoundAssets;
+
+/// Громкость телеграфа разлома — поверх музыки, но не громче боя
+const RIFT_TELEGRAPH_VOLUME: f32 = 0.8;
+
+/// Новый временный разлом — звук раскрытия на всё время телеграфа (разлом за кадром, слышно раньше, чем видно)
+pub fn rift_telegraph_sound_system(
+    mut commands: Commands,
+    assets: Res<SoundAssets>,
+    rifts: Query<(), Added<TemporaryRift>>,
+) {
+    for _ in &rifts {
+        commands.spawn((
+            AudioPlayer(assets.rift_telegraph.clone()),
+            PlaybackSettings { volume: Volume::Linear(RIFT_TELEGRAPH_VOLUME), ..PlaybackSettings::DESPAWN },
+        ));
+    }
+}
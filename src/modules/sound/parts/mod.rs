@@ -0,0 +1,8 @@
+//! Реализация звука:
+//! - synth: синтез на лету (Decodable для SynthAudio) — темы стадий печатей и телеграф разлома
+//! - music: смена темы по печатям разломов с перекрёстным затуханием
+//! - effects: разовые звуки событий боя (раскрытие флангового разлома)
+
+pub mod synth;
+pub mod music;
+pub mod effects;
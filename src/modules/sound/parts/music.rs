@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+use bevy::audio::{AudioSinkPlayback, Volume};
+use crate::modules::enemies::PortalSealed;
+use crate::modules::sound::components::{MusicState, MusicTrack, SoundAssets};
+use super::synth::MUSIC_STAGES;
+
+/// Громкость темы после нарастания
+const MUSIC_VOLUME: f32 = 0.6;
+/// Перекрёстное затухание при смене темы (с)
+const MUSIC_FADE_SECS: f32 = 2.5;
+
+/// Новый забег — с первой темы: разломы снова открыты
+pub fn start_run_music(
+    mut commands: Commands,
+    mut state: ResMut<MusicState>,
+    assets: Res<SoundAssets>,
+    mut tracks: Query<&mut MusicTrack>,
+) {
+    state.stage = 0;
+    switch_track(&mut commands, &assets, &mut tracks, state.stage);
+}
+
+/// Печать разлома переключает тему на следующую стадию (последняя держится до конца забега)
+pub fn portal_sealed_music_system(
+    mut commands: Commands,
+    mut sealed: MessageReader<PortalSealed>,
+    mut state: ResMut<MusicState>,
+    assets: Res<SoundAssets>,
+    mut tracks: Query<&mut MusicTrack>,
+) {
+    let seals = sealed.read().count();
+    if seals == 0 {
+        return;
+    }
+    let stage = (state.stage + seals).min(MUSIC_STAGES.len() - 1);
+    if stage == state.stage {
+        return;
+    }
+    state.stage = stage;
+    switch_track(&mut commands, &assets, &mut tracks, stage);
+    info!("🎵 Music stage {} after portal seal", stage);
+}
+
+/// Конец забега — тема затихает
+pub fn fade_out_music(mut tracks: Query<&mut MusicTrack>) {
+    for mut track in &mut tracks {
+        track.fading_out = true;
+    }
+}
+
+/// Плавно ведёт громкость тем к цели по реальному времени (пауза мира музыку не замораживает).
+/// Затихшая тема удаляется.
+pub fn music_fade_system(
+    mut commands: Commands,
+    real_time: Res<Time<Real>>,
+    mut tracks: Query<(Entity, &MusicTrack, &mut AudioSink)>,
+) {
+    let step = real_time.delta_secs() / MUSIC_FADE_SECS * MUSIC_VOLUME;
+    for (entity, track, mut sink) in &mut tracks {
+        let volume = sink.volume().to_linear();
+        if track.fading_out {
+            let next = (volume - step).max(0.0);
+            sink.set_volume(Volume::Linear(next));
+            if next <= 0.0 {
+                commands.entity(entity).despawn();
+            }
+        } else if volume < MUSIC_VOLUME {
+            sink.set_volume(Volume::Linear((volume + step).min(MUSIC_VOLUME)));
+        }
+    }
+}
+
+/// Гасит играющие темы и запускает тему стадии с нуля громкости
+fn switch_track(commands: &mut Commands, assets: &SoundAssets, tracks: &mut Query<&mut MusicTrack>, stage: usize) {
+    for mut track in tracks.iter_mut() {
+        track.fading_out = true;
+    }
+    // Поток бесконечный — Once, а не Loop: повтор буферизовал бы всю тему в памяти
+    commands.spawn((
+        AudioPlayer(assets.music[stage].clone()),
+        PlaybackSettings { volume: Volume::SILENT, ..PlaybackSettings::ONCE },
+        MusicTrack { fading_out: false },
+    ));
+}
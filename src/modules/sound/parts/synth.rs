@@ -0,0 +1,155 @@
+use std::f64::consts::TAU;
+use std::time::Duration;
+use bevy::prelude::*;
+use bevy::audio::{Decodable, Source};
+use crate::modules::sound::components::{SoundAssets, SynthAudio};
+use crate::shared::SeededRng;
+
+const SAMPLE_RATE: u32 = 44_100;
+/// Общая громкость синтеза; выше — мягкое ограничение (tanh), без щелчков перегруза
+const MASTER_GAIN: f32 = 0.3;
+/// Период медленного «дыхания» пэда (с)
+const SWELL_PERIOD: f64 = 8.0;
+/// Расстройка второго осциллятора — хорус вместо голого синуса
+const DETUNE: f64 = 1.003;
+/// Телеграф разлома (с): раскрытие вихря + короткий хвост
+const TELEGRAPH_SECS: f64 = 1.9;
+const TELEGRAPH_RISE_SECS: f64 = 1.6;
+
+/// Тема стадии печатей: чем больше разломов закрыто, тем светлее аккорд и реже барабан
+#[derive(Clone, Copy, Debug)]
+pub struct MusicStage {
+    /// Основной тон гула (Гц)
+    pub root: f64,
+    /// Ступени аккорда — множители к root
+    pub chord: [f64; 3],
+    /// Темп боевого барабана (удары в минуту) и его громкость
+    pub drum_bpm: f64,
+    pub drum_gain: f32,
+    /// Высокий мерцающий обертон — «свет» над гулом
+    pub shimmer: f32,
+}
+
+/// Темы по числу печатей: 0 — все разломы открыты, дальше — по одной на каждую печать
+pub const MUSIC_STAGES: [MusicStage; 3] = [
+    // Разломы открыты: ре-минорный гул с малой секундой, тяжёлый частый барабан
+    MusicStage { root: 73.42, chord: [1.0, 1.5, 16.0 / 15.0], drum_bpm: 96.0, drum_gain: 0.9, shimmer: 0.0 },
+    // Первая печать: чистое минорное трезвучие, барабан тише
+    MusicStage { root: 73.42, chord: [1.0, 1.5, 1.2], drum_bpm: 80.0, drum_gain: 0.6, shimmer: 0.12 },
+    // Разломы слабеют: мажор, редкий удар, светлый верх
+    MusicStage { root: 73.42, chord: [1.0, 1.5, 1.25], drum_bpm: 64.0, drum_gain: 0.35, shimmer: 0.25 },
+];
+
+/// Создаёт хэндлы звуков один раз — синтез идёт при воспроизведении
+pub fn init_sound_assets(mut commands: Commands, mut synths: ResMut<Assets<SynthAudio>>) {
+    let music = (0..MUSIC_STAGES.len())
+        .map(|stage| synths.add(SynthAudio::Music(stage)))
+        .collect();
+    commands.insert_resource(SoundAssets {
+        music,
+        rift_telegraph: synths.add(SynthAudio::RiftTelegraph),
+    });
+}
+
+/// Поток сэмплов одного воспроизведения (моно, 44.1 кГц)
+pub struct SynthDecoder {
+    audio: SynthAudio,
+    sample: u64,
+    /// Шум телеграфа и состояние его фильтра
+    noise: SeededRng,
+    lowpass: f64,
+}
+
+impl Iterator for SynthDecoder {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        let t = self.sample as f64 / SAMPLE_RATE as f64;
+        self.sample += 1;
+        let value = match self.audio {
+            SynthAudio::Music(stage) => music_sample(&MUSIC_STAGES[stage.min(MUSIC_STAGES.len() - 1)], t),
+            SynthAudio::RiftTelegraph => {
+                if t >= TELEGRAPH_SECS {
+                    return None;
+                }
+                self.telegraph_sample(t)
+            }
+        };
+        Some((value * MASTER_GAIN).tanh())
+    }
+}
+
+impl Source for SynthDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        match self.audio {
+            SynthAudio::Music(_) => None,
+            SynthAudio::RiftTelegraph => Some(Duration::from_secs_f64(TELEGRAPH_SECS)),
+        }
+    }
+}
+
+impl Decodable for SynthAudio {
+    type DecoderItem = f32;
+    type Decoder = SynthDecoder;
+
+    fn decoder(&self) -> SynthDecoder {
+        SynthDecoder { audio: *self, sample: 0, noise: SeededRng::new(0x5EA1), lowpass: 0.0 }
+    }
+}
+
+impl SynthDecoder {
+    /// Шорох (шум с открывающимся фильтром) и рваный гул вверх по тону — как мерцание света вихря.
+    /// К раскрытию громче всего, затем короткий спад.
+    fn telegraph_sample(&mut self, t: f64) -> f32 {
+        let rise = (t / TELEGRAPH_RISE_SECS).min(1.0);
+        let release = ((TELEGRAPH_SECS - t) / (TELEGRAPH_SECS - TELEGRAPH_RISE_SECS)).min(1.0);
+        let envelope = rise * rise * release;
+
+        // Однополюсный фильтр: срез от 200 Гц до 3 кГц
+        let cutoff = 200.0 + 2800.0 * rise * rise;
+        let alpha = 1.0 - (-TAU * cutoff / SAMPLE_RATE as f64).exp();
+        let white = self.noise.next_f32() as f64 * 2.0 - 1.0;
+        self.lowpass += (white - self.lowpass) * alpha;
+
+        // Гул 45 → 90 Гц, рваный на 12 Гц
+        let growl = (TAU * (45.0 * t + 22.5 * t * t / TELEGRAPH_RISE_SECS)).sin();
+        let flutter = 0.6 + 0.4 * (TAU * 12.0 * t).sin();
+
+        ((self.lowpass * 2.5 + growl * flutter * 1.5) * envelope) as f32
+    }
+}
+
+/// Гул аккорда (каждая ступень дышит в своей фазе) + барабан с акцентом на первую долю + обертон
+fn music_sample(stage: &MusicStage, t: f64) -> f32 {
+    let mut pad = 0.0;
+    for (i, ratio) in stage.chord.iter().enumerate() {
+        let freq = stage.root * ratio;
+        let swell = 0.6 + 0.4 * (TAU * t / SWELL_PERIOD + i as f64 * 2.1).sin();
+        let voice = (TAU * freq * t).sin() + (TAU * freq * DETUNE * t).sin() + 0.4 * (TAU * 2.0 * freq * t).sin();
+        pad += voice * swell;
+    }
+
+    // Удар: тон падает с 110 до 50 Гц за первые десятки мс, хвост гаснет экспонентой
+    let beat = 60.0 / stage.drum_bpm;
+    let since = t % beat;
+    let accent = if ((t / beat) as u64).is_multiple_of(4) { 1.0 } else { 0.6 };
+    let sweep = 50.0 * since + 2.0 * (1.0 - (-30.0 * since).exp());
+    let drum = (TAU * sweep).sin() * (-since * 7.0).exp() * accent;
+
+    let high = stage.root * stage.chord[2] * 4.0;
+    let shimmer = (TAU * high * t).sin() * (0.5 + 0.5 * (TAU * t / 3.0).sin());
+
+    (pad * 0.18 + drum * 1.2 * stage.drum_gain as f64 + shimmer * stage.shimmer as f64) as f32
+}
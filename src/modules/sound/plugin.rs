@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+use bevy::audio::AddAudioSource;
+use crate::shared::GameState;
+use super::components::{MusicState, SynthAudio};
+use super::parts::{synth, music, effects};
+
+pub struct SoundPlugin;
+
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_audio_source::<SynthAudio>()
+            .init_resource::<MusicState>()
+            .add_systems(Startup, synth::init_sound_assets)
+            .add_systems(OnEnter(GameState::Playing), music::start_run_music)
+            .add_systems(Update, (
+                music::portal_sealed_music_system,
+                effects::rift_telegraph_sound_system,
+            ).run_if(in_state(GameState::Playing)))
+            // Затухание идёт и после забега — тема гаснет уже на экране GameOver
+            .add_systems(Update, music::music_fade_system)
+            .add_systems(OnExit(GameState::Playing), music::fade_out_music);
+
+        info!("🎵 SoundPlugin loaded (procedural music per portal seal stage, rift telegraph)");
+    }
+}
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+/// SystemSet перестройки индекса — запросы к SpatialIndex ставятся `.after(SpatialIndexSet)`
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SpatialIndexSet;
+
+/// Слой индекса: запросы идут по одному слою (враги не смешиваются с орбами)
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+pub enum SpatialLayer {
+    Enemy,
+    XpOrb,
+    HpOrb,
+    Pickup,
+}
+
+impl SpatialLayer {
+    pub const COUNT: usize = 4;
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Entity попадает в SpatialIndex (позиция из Transform каждый кадр).
+/// Снимается при смерти/подборе — мёртвые и исчезающие объекты не участвуют в запросах.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct SpatialIndexed(pub SpatialLayer);
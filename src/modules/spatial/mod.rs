@@ -0,0 +1,8 @@
+pub mod components;
+pub mod plugin;
+mod parts;
+
+// Публичный API
+pub use plugin::SpatialPlugin;
+pub use components::{SpatialIndexed, SpatialLayer, SpatialIndexSet};
+pub use parts::index::SpatialIndex;
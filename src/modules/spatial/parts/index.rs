@@ -0,0 +1,238 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::modules::spatial::components::SpatialLayer;
+
+/// Сетка одного слоя: ячейки + границы занятой области (запрос любого радиуса
+/// обходит только пересечение с ней — радиус 30м на арене 50×50 не перебирает пустоту)
+struct LayerGrid {
+    cells: HashMap<(i32, i32), Vec<(Entity, Vec3)>>,
+    min: (i32, i32),
+    max: (i32, i32),
+    len: usize,
+}
+
+impl Default for LayerGrid {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            min: (i32::MAX, i32::MAX),
+            max: (i32::MIN, i32::MIN),
+            len: 0,
+        }
+    }
+}
+
+/// Общий пространственный индекс (hash grid по XZ, ячейка 3×3м, слой на тип объекта).
+/// Перестраивается каждый кадр в SpatialIndexSet; все запросы — callback без аллокаций.
+/// Высота (Y) игнорируется: арена плоская, дистанции считаются в плоскости пола.
+#[derive(Resource)]
+pub struct SpatialIndex {
+    cell_size: f32,
+    layers: [LayerGrid; SpatialLayer::COUNT],
+}
+
+impl Default for SpatialIndex {
+    fn default() -> Self {
+        Self {
+            cell_size: 3.0,
+            layers: Default::default(),
+        }
+    }
+}
+
+fn flat_dist_sq(a: Vec3, b: Vec3) -> f32 {
+    let dx = a.x - b.x;
+    let dz = a.z - b.z;
+    dx * dx + dz * dz
+}
+
+impl SpatialIndex {
+    fn cell_key(&self, pos: Vec3) -> (i32, i32) {
+        (
+            (pos.x / self.cell_size).floor() as i32,
+            (pos.z / self.cell_size).floor() as i32,
+        )
+    }
+
+    /// Очищает Vecs без деаллокации (capacity сохраняется между кадрами)
+    pub(crate) fn clear(&mut self) {
+        for layer in &mut self.layers {
+            for cell in layer.cells.values_mut() {
+                cell.clear();
+            }
+            layer.min = (i32::MAX, i32::MAX);
+            layer.max = (i32::MIN, i32::MIN);
+            layer.len = 0;
+        }
+    }
+
+    pub(crate) fn insert(&mut self, layer: SpatialLayer, entity: Entity, pos: Vec3) {
+        let key = self.cell_key(pos);
+        let grid = &mut self.layers[layer.index()];
+        grid.cells.entry(key).or_default().push((entity, pos));
+        grid.min = (grid.min.0.min(key.0), grid.min.1.min(key.1));
+        grid.max = (grid.max.0.max(key.0), grid.max.1.max(key.1));
+        grid.len += 1;
+    }
+
+    /// Сколько объектов в слое
+    pub fn len(&self, layer: SpatialLayer) -> usize {
+        self.layers[layer.index()].len
+    }
+
+    pub fn is_empty(&self, layer: SpatialLayer) -> bool {
+        self.len(layer) == 0
+    }
+
+    /// Обход ячеек, пересекающих прямоугольник XZ [min, max] (обрезан границами слоя)
+    fn for_each_in_rect(&self, layer: SpatialLayer, min: Vec3, max: Vec3, mut f: impl FnMut(Entity, Vec3)) {
+        let grid = &self.layers[layer.index()];
+        if grid.len == 0 {
+            return;
+        }
+        let (min_x, min_z) = self.cell_key(min);
+        let (max_x, max_z) = self.cell_key(max);
+        for cx in min_x.max(grid.min.0)..=max_x.min(grid.max.0) {
+            for cz in min_z.max(grid.min.1)..=max_z.min(grid.max.1) {
+                if let Some(cell) = grid.cells.get(&(cx, cz)) {
+                    for &(entity, pos) in cell {
+                        f(entity, pos);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Все объекты слоя в радиусе (включая стоящий в самом центре — self отсекает вызывающий).
+    /// Радиус любой: обходятся все покрытые ячейки.
+    pub fn for_each_in_radius(&self, layer: SpatialLayer, center: Vec3, radius: f32, mut f: impl FnMut(Entity, Vec3)) {
+        let r_sq = radius * radius;
+        let extent = Vec3::new(radius, 0.0, radius);
+        self.for_each_in_rect(layer, center - extent, center + extent, |entity, pos| {
+            if flat_dist_sq(center, pos) <= r_sq {
+                f(entity, pos);
+            }
+        });
+    }
+
+    /// Объекты в секторе: вершина origin, направление forward (XZ), полуугол half_angle (рад), дальность range
+    pub fn for_each_in_cone(
+        &self,
+        layer: SpatialLayer,
+        origin: Vec3,
+        forward: Vec3,
+        half_angle: f32,
+        range: f32,
+        mut f: impl FnMut(Entity, Vec3),
+    ) {
+        let forward = Vec3::new(forward.x, 0.0, forward.z).normalize_or_zero();
+        let min_cos = half_angle.cos();
+        self.for_each_in_radius(layer, origin, range, |entity, pos| {
+            let to = Vec3::new(pos.x - origin.x, 0.0, pos.z - origin.z);
+            let dist = to.length();
+            // В самой вершине конуса — считаем попавшим
+            if dist < 0.0001 || to.dot(forward) >= dist * min_cos {
+                f(entity, pos);
+            }
+        });
+    }
+
+    /// Объекты в "капсуле" вдоль отрезка start → end толщиной radius.
+    /// Callback получает t ∈ [0, 1] — проекцию на отрезок (снаряду — первое попадание по min t).
+    pub fn for_each_on_segment(
+        &self,
+        layer: SpatialLayer,
+        start: Vec3,
+        end: Vec3,
+        radius: f32,
+        mut f: impl FnMut(Entity, Vec3, f32),
+    ) {
+        let seg = Vec2::new(end.x - start.x, end.z - start.z);
+        let len_sq = seg.length_squared();
+        let r_sq = radius * radius;
+        let extent = Vec3::new(radius, 0.0, radius);
+        self.for_each_in_rect(layer, start.min(end) - extent, start.max(end) + extent, |entity, pos| {
+            let rel = Vec2::new(pos.x - start.x, pos.z - start.z);
+            let t = if len_sq > 0.0 { (rel.dot(seg) / len_sq).clamp(0.0, 1.0) } else { 0.0 };
+            if (rel - seg * t).length_squared() <= r_sq {
+                f(entity, pos, t);
+            }
+        });
+    }
+
+    /// K ближайших объектов в пределах max_radius → out (по возрастанию дистанции, out очищается).
+    /// Кольца ячеек расширяются от центра, пока k-й найденный ближе следующего кольца.
+    /// filter отсекает кандидатов (например, себя или уже выбранные цели).
+    pub fn k_nearest(
+        &self,
+        layer: SpatialLayer,
+        center: Vec3,
+        k: usize,
+        max_radius: f32,
+        mut filter: impl FnMut(Entity) -> bool,
+        out: &mut Vec<(Entity, Vec3, f32)>,
+    ) {
+        out.clear();
+        let grid = &self.layers[layer.index()];
+        if k == 0 || grid.len == 0 {
+            return;
+        }
+        let max_sq = max_radius * max_radius;
+        let (cx, cz) = self.cell_key(center);
+        // Колец хватает до края занятой области слоя (и не больше радиуса)
+        let cover = (cx - grid.min.0).abs().max((cx - grid.max.0).abs())
+            .max((cz - grid.min.1).abs()).max((cz - grid.max.1).abs());
+        let max_rings = ((max_radius / self.cell_size).ceil() as i32).min(cover);
+
+        let mut visit = |key: (i32, i32), out: &mut Vec<(Entity, Vec3, f32)>| {
+            let Some(cell) = grid.cells.get(&key) else { return };
+            for &(entity, pos) in cell {
+                let dist_sq = flat_dist_sq(center, pos);
+                if dist_sq > max_sq || (out.len() == k && dist_sq >= out[k - 1].2) || !filter(entity) {
+                    continue;
+                }
+                let at = out.partition_point(|c| c.2 <= dist_sq);
+                out.insert(at, (entity, pos, dist_sq));
+                out.truncate(k);
+            }
+        };
+
+        for ring in 0..=max_rings.max(0) {
+            // Только клетки на границе кольца (внутренние обошли раньше)
+            if ring == 0 {
+                visit((cx, cz), out);
+            } else {
+                for d in -ring..=ring {
+                    visit((cx + d, cz - ring), out);
+                    visit((cx + d, cz + ring), out);
+                }
+                for d in (1 - ring)..ring {
+                    visit((cx - ring, cz + d), out);
+                    visit((cx + ring, cz + d), out);
+                }
+            }
+            // Всё за следующим кольцом не ближе ring × cell_size
+            let ring_dist = ring as f32 * self.cell_size;
+            if out.len() == k && out[k - 1].2 <= ring_dist * ring_dist {
+                break;
+            }
+        }
+
+        for c in out.iter_mut() {
+            c.2 = c.2.sqrt();
+        }
+    }
+
+    /// Ближайший объект в радиусе, прошедший filter: (entity, pos, дистанция)
+    pub fn nearest(
+        &self,
+        layer: SpatialLayer,
+        center: Vec3,
+        max_radius: f32,
+        filter: impl FnMut(Entity) -> bool,
+    ) -> Option<(Entity, Vec3, f32)> {
+        let mut out = Vec::with_capacity(1);
+        self.k_nearest(layer, center, 1, max_radius, filter, &mut out);
+        out.pop()
+    }
+}
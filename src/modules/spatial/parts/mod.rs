@@ -0,0 +1,6 @@
+//! Реализация пространственного индекса:
+//! - index: SpatialIndex (hash grid по слоям) — радиус, конус, отрезок, k ближайших
+//! - rebuild: перестройка индекса из SpatialIndexed каждый кадр
+
+pub mod index;
+pub mod rebuild;
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use crate::modules::spatial::components::SpatialIndexed;
+use super::index::SpatialIndex;
+
+/// Перестраивает индекс каждый кадр (O(n), 0 аллокаций при стабильном кол-ве объектов)
+pub fn rebuild_spatial_index_system(
+    mut index: ResMut<SpatialIndex>,
+    indexed: Query<(Entity, &Transform, &SpatialIndexed)>,
+) {
+    index.clear();
+    for (entity, transform, indexed) in &indexed {
+        index.insert(indexed.0, entity, transform.translation);
+    }
+}
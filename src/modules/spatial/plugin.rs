@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use crate::shared::GameState;
+use super::components::SpatialIndexSet;
+use super::parts::{index::SpatialIndex, rebuild};
+
+pub struct SpatialPlugin;
+
+impl Plugin for SpatialPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<SpatialIndex>()
+            // Перестройка в начале кадра: AI, автоатака, орбы и миникарта читают актуальный индекс
+            .add_systems(Update, rebuild::rebuild_spatial_index_system
+                .in_set(SpatialIndexSet)
+                .run_if(in_state(GameState::Playing)));
+
+        info!("🗺️ SpatialPlugin loaded (enemies + orbs + pickups index)");
+    }
+}
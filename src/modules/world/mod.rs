@@ -2,4 +2,12 @@ pub mod plugin;
 pub(crate) mod parts;
 
 pub use plugin::WorldPlugin;
-pub use parts::ground_circle::{GroundCircle, CooldownRing};
+pub use parts::arena_layout::{ArenaLayout, ArenaBounds, BreakablesDef, HazardDef, HazardKind, PortalDef, PortalStyle, EnemyWeights, ActiveArena, ArenaEntity, CurrentArena, ShrineProp};
+pub use parts::scatter::{ArenaScatter, ScatterProp};
+pub use parts::biomes::{BiomeDef, BIOMES, SelectedBiome};
+pub use parts::game_modes::{GameModeDef, ShrinkSchedule, ShrinkStage, GAME_MODES, SelectedMode};
+pub use parts::safe_zone::SafeZone;
+pub use parts::portal_setup::{spawn_portal_site, portal_vortex, portal_light_color, PortalFill, PORTAL_LIGHT_INTENSITY};
+pub use parts::portal_fill::{PortalVortexMaterial, PortalVortexSettings};
+pub use parts::ground_circle::{GroundCircle, CooldownRing, CooldownSource};
+pub use parts::stylized_material::{RimLightSettings, character_rim_light};
@@ -1,87 +1,81 @@
 use bevy::prelude::*;
-use bevy_hanabi::prelude::*;
+use bevy::light::NotShadowCaster;
+use crate::shared::rand_01;
+use super::arena_layout::{ArenaBounds, ArenaEntity, CurrentArena, ParticlesDef};
 
-/// Спавнит парящие угольки/пылинки по всей арене для атмосферы
-pub fn setup_ambient_particles(
-    mut commands: Commands,
-    mut effects: ResMut<Assets<EffectAsset>>,
-) {
-    let mut module = Module::default();
-
-    // Позиция: сфера 20м (покрывает арену)
-    let init_pos = SetPositionSphereModifier {
-        center: module.lit(Vec3::ZERO),
-        radius: module.lit(20.0),
-        dimension: ShapeDimension::Volume,
-    };
-
-    // Lifetime: 3–8 сек
-    let lt_min = module.lit(3.0);
-    let lt_max = module.lit(8.0);
-    let init_lifetime = SetAttributeModifier::new(
-        Attribute::LIFETIME,
-        module.uniform(lt_min, lt_max),
-    );
-
-    // Начальный размер: 0.01–0.04
-    let sz_min = module.lit(Vec3::splat(0.005));
-    let sz_max = module.lit(Vec3::splat(0.015));
-    let init_size = SetAttributeModifier::new(
-        Attribute::SIZE3,
-        module.uniform(sz_min, sz_max),
-    );
-
-    // Скорость: медленная, направление вверх
-    let spd_min = module.lit(0.05);
-    let spd_max = module.lit(0.15);
-    let init_vel = SetVelocitySphereModifier {
-        center: module.lit(Vec3::ZERO),
-        speed: module.uniform(spd_min, spd_max),
-    };
+/// Частицы живут в слое от земли до этой высоты и перетекают через края
+const PARTICLE_CEILING: f32 = 6.0;
 
-    // Медленный подъём
-    let update_accel = AccelModifier::constant(&mut module, Vec3::new(0., 0.15, 0.));
-    let update_drag = LinearDragModifier::constant(&mut module, 0.5);
+/// Парящая частица биома (уголёк, светлячок, снежинка)
+#[derive(Component)]
+pub struct AmbientMote {
+    velocity: Vec3,
+    /// Фаза покачивания — частицы не колышутся в такт
+    phase: f32,
+}
 
-    // Круглая форма + биллборд
-    let round = RoundModifier::ellipse(&mut module);
+/// Спавнит частицы атмосферы по всей арене (один меш + один материал на всех)
+pub fn spawn_ambient_particles(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    def: &ParticlesDef,
+    bounds: &ArenaBounds,
+) {
+    let [r, g, b] = def.color;
+    let mesh = meshes.add(Sphere::new(def.size).mesh().ico(1).unwrap());
+    let material = materials.add(StandardMaterial {
+        base_color: Color::linear_rgba(r, g, b, 0.6),
+        emissive: LinearRgba::rgb(r, g, b),
+        unlit: true,
+        alpha_mode: AlphaMode::Add,
+        ..default()
+    });
 
-    // Градиент цвета: тёплый янтарь → затухание
-    let mut color_grad = bevy_hanabi::Gradient::new();
-    color_grad.add_key(0.0, Vec4::new(8.0, 4.0, 1.0, 0.0));    // fade-in: горячий янтарь HDR
-    color_grad.add_key(0.1, Vec4::new(6.0, 3.0, 0.5, 0.6));    // пик яркости
-    color_grad.add_key(0.5, Vec4::new(3.0, 1.0, 0.2, 0.4));    // тлеющий
-    color_grad.add_key(1.0, Vec4::new(1.0, 0.3, 0.05, 0.0));   // затухание
+    for _ in 0..def.count {
+        let position = random_point(bounds, rand_01() * PARTICLE_CEILING);
+        commands.spawn((
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_translation(position),
+            NotShadowCaster,
+            AmbientMote {
+                velocity: Vec3::Y * def.rise_speed * (0.6 + rand_01() * 0.8),
+                phase: rand_01() * std::f32::consts::TAU,
+            },
+            ArenaEntity,
+        ));
+    }
+}
 
-    // Кривая размера: fade in → sustain → fade out
-    let mut size_grad = bevy_hanabi::Gradient::new();
-    size_grad.add_key(0.0, Vec3::ZERO);
-    size_grad.add_key(0.1, Vec3::ONE);
-    size_grad.add_key(0.9, Vec3::ONE);
-    size_grad.add_key(1.0, Vec3::ZERO);
+/// Подъём/падение + покачивание; вышедшая за слой частица возвращается с другого края
+pub fn ambient_particles_system(
+    time: Res<Time>,
+    arena: Option<Res<CurrentArena>>,
+    mut motes: Query<(&AmbientMote, &mut Transform)>,
+) {
+    let Some(arena) = arena else { return };
+    let Some(def) = &arena.atmosphere.particles else { return };
+    let dt = time.delta_secs();
+    let t = time.elapsed_secs();
 
-    // 20 частиц/сек × 8 сек ≈ 160 частиц макс
-    let effect = EffectAsset::new(1024, SpawnerSettings::rate(20.0_f32.into()), module)
-        .with_name("ambient_embers")
-        .with_alpha_mode(bevy_hanabi::AlphaMode::Add)
-        .init(init_pos)
-        .init(init_lifetime)
-        .init(init_size)
-        .init(init_vel)
-        .update(update_accel)
-        .update(update_drag)
-        .render(ColorOverLifetimeModifier::new(color_grad))
-        .render(SizeOverLifetimeModifier {
-            gradient: size_grad,
-            screen_space_size: false,
-        })
-        .render(OrientModifier::new(OrientMode::ParallelCameraDepthPlane))
-        .render(round);
+    for (mote, mut transform) in &mut motes {
+        let sway = Vec3::new((t + mote.phase).sin(), 0.0, (t * 0.7 + mote.phase).cos()) * def.sway;
+        transform.translation += (mote.velocity + sway) * dt;
 
-    let handle = effects.add(effect);
+        let y = transform.translation.y;
+        if y > PARTICLE_CEILING {
+            transform.translation = random_point(&arena.bounds, 0.0);
+        } else if y < 0.0 {
+            transform.translation = random_point(&arena.bounds, PARTICLE_CEILING);
+        }
+    }
+}
 
-    commands.spawn((
-        ParticleEffect::new(handle),
-        Transform::from_xyz(0.0, 0.5, 0.0),
-    ));
+fn random_point(bounds: &ArenaBounds, y: f32) -> Vec3 {
+    Vec3::new(
+        bounds.min.x + rand_01() * (bounds.max.x - bounds.min.x),
+        y,
+        bounds.min.y + rand_01() * (bounds.max.y - bounds.min.y),
+    )
 }
@@ -0,0 +1,416 @@
+use bevy::prelude::*;
+use bevy::asset::{AssetLoader, LoadContext, io::Reader};
+use bevy::image::{ImageAddressMode, ImageLoaderSettings, ImageSampler, ImageSamplerDescriptor};
+use serde::Deserialize;
+use crate::toolkit::asset_paths;
+use super::biomes::{BIOMES, SelectedBiome};
+
+/// Раскладка арены (assets/arenas/*.arena.ron): пол, освещение, стены, декор,
+/// порталы (и места будущих разломов) и точка появления героя. Новая арена — новый файл, без правок в коде.
+#[derive(Asset, TypePath, Deserialize, Clone, Debug)]
+pub struct ArenaLayout {
+    pub name: String,
+    /// Игровая область (XZ) — внутренняя кромка стен
+    pub bounds: ArenaBounds,
+    pub floor: FloorDef,
+    pub ambient: AmbientDef,
+    pub sun: SunDef,
+    pub atmosphere: AtmosphereDef,
+    pub walls: WallsDef,
+    #[serde(default)]
+    pub props: Vec<PropDef>,
+    /// Процедурная расстановка по seed забега (препятствия, рунные камни, декор)
+    #[serde(default)]
+    pub scatter: Option<ScatterDef>,
+    /// Отдельные источники света (не привязанные к декору)
+    #[serde(default)]
+    pub lights: Vec<LightDef>,
+    /// Статичные опасные зоны (жаровни, трясина) — живут вместе с ареной
+    #[serde(default)]
+    pub hazards: Vec<HazardDef>,
+    /// Разбиваемые урны, бочки и груды костей — новая россыпь к каждой волне
+    #[serde(default)]
+    pub breakables: Option<BreakablesDef>,
+    pub portals: Vec<PortalDef>,
+    /// Места, где по ходу забега открываются новые разломы взамен запечатанных
+    #[serde(default)]
+    pub rift_sites: Vec<PortalDef>,
+    pub player_spawn: Vec3,
+    /// Множители шансов архетипов врагов (биом: на болоте больше леших и т.п.)
+    #[serde(default)]
+    pub enemy_weights: EnemyWeights,
+    /// Модели и текстуры раскладки — держат зависимости загруженными до спавна арены
+    #[serde(skip)]
+    pub dependencies: Vec<UntypedHandle>,
+}
+
+/// Прямоугольник игровой области на плоскости XZ
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub struct ArenaBounds {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+impl ArenaBounds {
+    /// Половина стороны квадрата с центром в начале координат, накрывающего арену
+    pub fn half_extent(&self) -> f32 {
+        self.min.abs().max(self.max.abs()).max_element()
+    }
+
+    pub fn contains(&self, pos: Vec3) -> bool {
+        pos.x >= self.min.x && pos.x <= self.max.x && pos.z >= self.min.y && pos.z <= self.max.y
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct FloorDef {
+    pub diffuse: String,
+    pub normal: String,
+    pub tint: [f32; 3],
+    /// Сторона меша пола (м) — больше арены, чтобы за скалами не было пустоты
+    pub size: f32,
+    /// Повторы текстуры на сторону меша
+    pub tile_repeat: f32,
+    /// Половина стороны коллайдера пола
+    pub collider_half_extent: f32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct AmbientDef {
+    pub color: [f32; 3],
+    pub brightness: f32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct SunDef {
+    pub color: [f32; 3],
+    pub illuminance: f32,
+    /// Солнце смотрит из этой точки в начало координат
+    pub position: Vec3,
+    pub shadow_map_size: usize,
+}
+
+/// Воздух биома: фон за ареной, стелющийся туман, парящие частицы
+#[derive(Deserialize, Clone, Debug)]
+pub struct AtmosphereDef {
+    /// ClearColor — тьма за ареной (в тон туману)
+    pub clear_color: [f32; 3],
+    /// Цвет тумана у земли (linear); None — без тумана
+    #[serde(default)]
+    pub fog_color: Option<[f32; 3]>,
+    #[serde(default)]
+    pub particles: Option<ParticlesDef>,
+}
+
+/// Парящие частицы (угольки, светлячки, снег): rise_speed < 0 — падают
+#[derive(Deserialize, Clone, Debug)]
+pub struct ParticlesDef {
+    /// HDR цвет (linear, >1 — светится через bloom)
+    pub color: [f32; 3],
+    pub count: usize,
+    pub size: f32,
+    pub rise_speed: f32,
+    /// Амплитуда бокового покачивания (м/с)
+    #[serde(default)]
+    pub sway: f32,
+}
+
+/// Множители базовых шансов по волне (1.0 — как в лесу)
+#[derive(Deserialize, Clone, Copy, Debug)]
+#[serde(default)]
+pub struct EnemyWeights {
+    pub upyr: f32,
+    pub leshiy: f32,
+    pub volkolak: f32,
+    pub koldun: f32,
+}
+
+impl Default for EnemyWeights {
+    fn default() -> Self {
+        Self { upyr: 1.0, leshiy: 1.0, volkolak: 1.0, koldun: 1.0 }
+    }
+}
+
+/// Стены по периметру: набор моделей-вариантов и секции
+#[derive(Deserialize, Clone, Debug)]
+pub struct WallsDef {
+    pub models: Vec<String>,
+    /// Коллайдер секции в локальных единицах модели (масштабируется вместе с ней)
+    pub collider_half_extents: Vec3,
+    pub sections: Vec<WallSection>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct WallSection {
+    pub position: Vec3,
+    pub rotation: f32,
+    pub scale: Vec3,
+    /// Индекс в WallsDef::models
+    pub variant: usize,
+}
+
+/// Декор: модель + опционально коллайдер и свет
+#[derive(Deserialize, Clone, Debug)]
+pub struct PropDef {
+    pub model: String,
+    pub position: Vec3,
+    #[serde(default)]
+    pub rotation: f32,
+    #[serde(default = "default_scale")]
+    pub scale: f32,
+    /// Коллайдер в локальных единицах модели (масштабируется вместе с ней)
+    #[serde(default)]
+    pub collider: Option<ColliderDef>,
+    /// Свет — позиция относительно декора, в локальных единицах модели
+    #[serde(default)]
+    pub light: Option<LightDef>,
+    /// Святилище: копит силу и даёт бафф стоящему рядом герою (свет — индикатор заряда)
+    #[serde(default)]
+    pub shrine: bool,
+}
+
+/// Маркер декора-святилища (PropDef::shrine) — поведение навешивает модуль shrines
+#[derive(Component)]
+pub struct ShrineProp;
+
+fn default_scale() -> f32 {
+    1.0
+}
+
+fn default_weight() -> f32 {
+    1.0
+}
+
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub enum ColliderDef {
+    Cylinder { radius: f32, height: f32 },
+    Cuboid { half_extents: Vec3 },
+}
+
+impl ColliderDef {
+    /// Радиус описанной окружности в плане (локальные единицы)
+    pub fn footprint_radius(&self) -> f32 {
+        match *self {
+            ColliderDef::Cylinder { radius, .. } => radius,
+            ColliderDef::Cuboid { half_extents } => half_extents.xz().length(),
+        }
+    }
+}
+
+/// Процедурная расстановка: пулы раскидываются Poisson-disc внутри арены
+/// с отступом от стен, чистой зоной у героя и коридорами от порталов
+#[derive(Deserialize, Clone, Debug)]
+pub struct ScatterDef {
+    /// Отступ от границы арены (стены и декор у стен)
+    pub edge_margin: f32,
+    /// Радиус свободной зоны вокруг точки появления героя
+    pub spawn_clearance: f32,
+    /// Ширина коридора от выхода каждого портала к герою
+    pub lane_width: f32,
+    /// Пулы по порядку: ранние (крупные) занимают место первыми
+    pub pools: Vec<ScatterPool>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ScatterPool {
+    pub count: usize,
+    /// Минимальное расстояние между точками пула и до уже расставленного
+    pub min_distance: f32,
+    pub entries: Vec<ScatterEntry>,
+}
+
+/// Вариант декора в пуле: выбирается по весу, масштаб — случайный в [min, max]
+#[derive(Deserialize, Clone, Debug)]
+pub struct ScatterEntry {
+    #[serde(default = "default_weight")]
+    pub weight: f32,
+    pub model: String,
+    pub scale: (f32, f32),
+    #[serde(default)]
+    pub collider: Option<ColliderDef>,
+    #[serde(default)]
+    pub light: Option<LightDef>,
+    #[serde(default)]
+    pub shrine: bool,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct LightDef {
+    pub position: Vec3,
+    pub color: [f32; 3],
+    pub intensity: f32,
+    pub range: f32,
+}
+
+/// Россыпь разбиваемых контейнеров: сколько и как часто какого вида
+#[derive(Deserialize, Clone, Debug)]
+pub struct BreakablesDef {
+    pub count: usize,
+    /// Минимальное расстояние между контейнерами (м)
+    pub min_distance: f32,
+    /// Веса видов
+    pub urn: f32,
+    pub barrel: f32,
+    pub bones: f32,
+}
+
+/// Опасная зона: круг на полу, действует на всех внутри — и героя, и врагов
+#[derive(Deserialize, Clone, Debug)]
+pub struct HazardDef {
+    pub kind: HazardKind,
+    pub position: Vec3,
+    pub radius: f32,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum HazardKind {
+    /// Жаровня: огонь жжёт всех внутри
+    FirePit { damage_per_second: f32 },
+    /// Трясина: замедляет (множитель скорости); ядовитая ещё и травит
+    Bog {
+        slow: f32,
+        #[serde(default)]
+        damage_per_second: f32,
+    },
+}
+
+impl HazardKind {
+    pub fn damage_per_second(self) -> f32 {
+        match self {
+            HazardKind::FirePit { damage_per_second } | HazardKind::Bog { damage_per_second, .. } => damage_per_second,
+        }
+    }
+
+    /// Множитель скорости внутри зоны (None — не замедляет)
+    pub fn slow(self) -> Option<f32> {
+        match self {
+            HazardKind::FirePit { .. } => None,
+            HazardKind::Bog { slow, .. } => Some(slow),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PortalStyle {
+    /// "Разлом Огня": каменная арка с рунами
+    Fire,
+    /// "Разлом Тьмы": арка из корней и костей
+    Dark,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct PortalDef {
+    pub style: PortalStyle,
+    /// Центр арки
+    pub position: Vec3,
+    /// Точка выхода врагов (внутри арены)
+    pub spawn: Vec3,
+    /// Поворот арки вокруг Y (рад): 0 — смотрит в +Z (северная стена)
+    #[serde(default)]
+    pub rotation: f32,
+}
+
+impl ArenaLayout {
+    /// Все места разломов: стартовые порталы, затем rift_sites (индекс — «место» портала)
+    pub fn portal_sites(&self) -> impl Iterator<Item = &PortalDef> {
+        self.portals.iter().chain(&self.rift_sites)
+    }
+}
+
+/// Загрузчик *.arena.ron
+#[derive(Default, TypePath)]
+pub struct ArenaLayoutLoader;
+
+impl AssetLoader for ArenaLayoutLoader {
+    type Asset = ArenaLayout;
+    type Settings = ();
+    type Error = BevyError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<ArenaLayout, BevyError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let mut layout: ArenaLayout = ron::de::from_bytes(&bytes)?;
+
+        // Модели и текстуры — зависимости ассета: арена готова, когда загружено всё
+        let mut dependencies = Vec::new();
+        let scatter_models = layout.scatter.iter()
+            .flat_map(|scatter| &scatter.pools)
+            .flat_map(|pool| &pool.entries)
+            .map(|entry| &entry.model);
+        let scenes = layout.walls.models.iter()
+            .chain(layout.props.iter().map(|prop| &prop.model))
+            .chain(scatter_models);
+        for path in scenes {
+            dependencies.push(load_context.load::<Scene>(path.clone()).untyped());
+        }
+        for path in [&layout.floor.diffuse, &layout.floor.normal] {
+            let texture = load_context.loader()
+                .with_settings(repeat_sampler)
+                .load::<Image>(path.clone());
+            dependencies.push(texture.untyped());
+        }
+        for portal in layout.portal_sites() {
+            dependencies.push(load_context.load::<Scene>(portal.style.model()).untyped());
+        }
+        layout.dependencies = dependencies;
+
+        Ok(layout)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["arena.ron"]
+    }
+}
+
+impl PortalStyle {
+    pub fn model(self) -> &'static str {
+        match self {
+            PortalStyle::Fire => asset_paths::PORTAL_FIRE,
+            PortalStyle::Dark => asset_paths::PORTAL_DARK,
+        }
+    }
+}
+
+/// Тайлящаяся текстура (пол)
+pub fn repeat_sampler(settings: &mut ImageLoaderSettings) {
+    settings.sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
+        address_mode_u: ImageAddressMode::Repeat,
+        address_mode_v: ImageAddressMode::Repeat,
+        ..default()
+    });
+}
+
+/// Загружаемая раскладка арены; instantiated — сцена уже заспавнена (или загрузка упала)
+#[derive(Resource)]
+pub struct ActiveArena {
+    /// Индекс в BIOMES
+    pub biome: usize,
+    pub handle: Handle<ArenaLayout>,
+    pub instantiated: bool,
+}
+
+/// Маркер entity арены — всё, что снимается при смене биома
+#[derive(Component)]
+pub struct ArenaEntity;
+
+/// Раскладка заспавненной арены — границы, порталы, точка появления героя
+#[derive(Resource, Clone, Deref)]
+pub struct CurrentArena(pub ArenaLayout);
+
+/// Startup: начинаем загрузку раскладки выбранного биома (фоном, пока открыт титульный экран)
+pub fn load_arena_layout(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    selected: Res<SelectedBiome>,
+) {
+    commands.insert_resource(ActiveArena {
+        biome: selected.0,
+        handle: asset_server.load(BIOMES[selected.0].layout),
+        instantiated: false,
+    });
+}
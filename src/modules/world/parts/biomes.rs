@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+use crate::toolkit::asset_paths;
+use super::arena_layout::{ActiveArena, ArenaEntity, CurrentArena};
+
+/// Биом на выбор перед забегом: раскладка арены + порог открытия
+pub struct BiomeDef {
+    pub name: &'static str,
+    pub layout: &'static str,
+    /// Открывается, когда лучший забег дошёл до этой волны (0 — доступен сразу)
+    pub unlock_wave: u32,
+}
+
+pub const BIOMES: &[BiomeDef] = &[
+    BiomeDef { name: "Лес", layout: asset_paths::ARENA_FOREST, unlock_wave: 0 },
+    BiomeDef { name: "Болото", layout: asset_paths::ARENA_SWAMP, unlock_wave: 5 },
+    BiomeDef { name: "Зимний лес", layout: asset_paths::ARENA_WINTER, unlock_wave: 8 },
+    BiomeDef { name: "Пепелище", layout: asset_paths::ARENA_BURNT_VILLAGE, unlock_wave: 10 },
+    BiomeDef { name: "Навь", layout: asset_paths::ARENA_NAVI, unlock_wave: 12 },
+];
+
+impl BiomeDef {
+    pub fn is_unlocked(&self, best_wave: u32) -> bool {
+        best_wave >= self.unlock_wave
+    }
+}
+
+/// Биом, выбранный на титульном экране (индекс в BIOMES)
+#[derive(Resource, Default)]
+pub struct SelectedBiome(pub usize);
+
+/// OnEnter(Loading): выбран другой биом — снимаем старую арену и грузим новую раскладку.
+/// Спавн — в setup_scene, экран загрузки ждёт ActiveArena::instantiated.
+pub fn sync_active_arena(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    selected: Res<SelectedBiome>,
+    mut active: ResMut<ActiveArena>,
+    arena_entities: Query<Entity, With<ArenaEntity>>,
+) {
+    if active.biome == selected.0 {
+        return;
+    }
+    for entity in &arena_entities {
+        commands.entity(entity).despawn();
+    }
+    commands.remove_resource::<CurrentArena>();
+
+    let biome = &BIOMES[selected.0];
+    *active = ActiveArena {
+        biome: selected.0,
+        handle: asset_server.load(biome.layout),
+        instantiated: false,
+    };
+    info!("🗺️ Biome switched to '{}' ({})", biome.name, biome.layout);
+}
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+
+/// Режим забега: поверх биома задаёт особые правила (сжатие арены туманом Нави, победа печатями)
+pub struct GameModeDef {
+    pub name: &'static str,
+    /// Открывается, когда лучший забег дошёл до этой волны (0 — доступен сразу)
+    pub unlock_wave: u32,
+    /// Расписание сжатия безопасной зоны (None — арена не сжимается)
+    pub shrink: Option<ShrinkSchedule>,
+    /// Поход: разломы не открываются повторно — запечатал все места арены, победа
+    pub seal_to_win: bool,
+    /// Множитель бюджета токенов атаки: сколько врагов бьют героя одновременно
+    pub token_budget: f32,
+    /// Враги — твёрдые тела для героя (BodyBlocking): толпа может зажать
+    pub body_blocking: bool,
+}
+
+/// Расписание сжатия: стадии по волнам + урон туманом за границей зоны
+pub struct ShrinkSchedule {
+    pub stages: &'static [ShrinkStage],
+    /// Урон в секунду сразу за границей
+    pub damage_per_second: f32,
+    /// Прирост урона в секунду за каждую секунду снаружи подряд
+    pub damage_growth: f32,
+    pub max_damage_per_second: f32,
+}
+
+/// Стадия: с волны `wave` зона за `duration` секунд стягивается до `radius`
+pub struct ShrinkStage {
+    pub wave: u32,
+    pub radius: f32,
+    pub duration: f32,
+}
+
+pub const GAME_MODES: &[GameModeDef] = &[
+    GameModeDef { name: "Классика", unlock_wave: 0, shrink: None, seal_to_win: false, token_budget: 1.0, body_blocking: false },
+    GameModeDef { name: "Поход", unlock_wave: 6, shrink: None, seal_to_win: true, token_budget: 1.0, body_blocking: false },
+    GameModeDef {
+        name: "Натиск Нави",
+        unlock_wave: 10,
+        shrink: Some(ShrinkSchedule {
+            stages: &[
+                ShrinkStage { wave: 3, radius: 18.0, duration: 20.0 },
+                ShrinkStage { wave: 6, radius: 13.0, duration: 15.0 },
+                ShrinkStage { wave: 9, radius: 9.0, duration: 12.0 },
+                ShrinkStage { wave: 12, radius: 6.0, duration: 10.0 },
+            ],
+            damage_per_second: 4.0,
+            damage_growth: 3.0,
+            max_damage_per_second: 40.0,
+        }),
+        seal_to_win: false,
+        // Натиск: тесная арена, больше атакующих разом и толпа зажимает героя
+        token_budget: 1.25,
+        body_blocking: true,
+    },
+];
+
+impl GameModeDef {
+    pub fn is_unlocked(&self, best_wave: u32) -> bool {
+        best_wave >= self.unlock_wave
+    }
+}
+
+/// Режим, выбранный на титульном экране (индекс в GAME_MODES)
+#[derive(Resource, Default)]
+pub struct SelectedMode(pub usize);
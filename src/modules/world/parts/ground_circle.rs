@@ -1,8 +1,8 @@
 use bevy::prelude::*;
 use bevy::mesh::{Indices, PrimitiveTopology, VertexAttributeValues};
 use bevy::asset::RenderAssetUsages;
-use crate::modules::enemies::components::{Enemy, Health, EnemyDying, EnemyModel};
-use crate::modules::player::components::{Player, PlayerModel};
+use crate::modules::enemies::components::{Enemy, Health, EnemyDying, EnemyModel, AuraBuff};
+use crate::modules::player::components::{Player, PlayerModel, PlayerStats, Stamina, DashCooldown};
 use crate::modules::combat::components::{PlayerHealth, AttackCooldown};
 use crate::modules::selection::components::Selected;
 
@@ -24,9 +24,21 @@ pub struct GroundCircle {
     pub last_alpha: f32,
 }
 
-/// Кольцо перезарядки оружия (тонкая дуга внутри HP ring)
+/// Чей кулдаун показывает кольцо
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CooldownSource {
+    /// Автоатака (AttackCooldown)
+    Attack,
+    /// Рывок (DashCooldown)
+    Dash,
+    /// Запас выносливости (Stamina): виден только пока не полон
+    Stamina,
+}
+
+/// Кольцо-индикатор у ног игрока: перезарядка оружия/рывка или выносливость
 #[derive(Component)]
 pub struct CooldownRing {
+    pub source: CooldownSource,
     pub inner_radius: f32,
     pub outer_radius: f32,
     pub material_handle: Handle<StandardMaterial>,
@@ -118,16 +130,16 @@ fn update_ring(
     }
 }
 
-/// Обновляет кольцо перезарядки оружия игрока
+/// Обновляет кольца игрока (оружие + рывок + выносливость)
 pub fn cooldown_ring_system(
-    player: Query<(&AttackCooldown, &Children), With<Player>>,
+    player: Query<(&AttackCooldown, Option<&DashCooldown>, Option<(&Stamina, &PlayerStats)>, &Children), With<Player>>,
     mut ring_query: Query<(&mut CooldownRing, &mut Transform, &Mesh3d), Without<GroundCircle>>,
     model_query: Query<&Transform, (With<PlayerModel>, Without<CooldownRing>, Without<GroundCircle>)>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     time: Res<Time>,
 ) {
-    let Ok((cooldown, children)) = player.single() else { return };
+    let Ok((attack_cooldown, dash_cooldown, stamina, children)) = player.single() else { return };
 
     // Направление из PlayerModel
     let mut facing = 0.0_f32;
@@ -139,12 +151,25 @@ pub fn cooldown_ring_system(
         }
     }
 
-    let cd_fraction = cooldown.timer.fraction();
-
     for child in children.iter() {
         let Ok((mut ring, mut transform, mesh3d)) = ring_query.get_mut(child) else {
             continue;
         };
+        // Доля заполнения дуги + готовность (полная выносливость — дуга скрыта)
+        let (cd_fraction, ready) = match ring.source {
+            CooldownSource::Attack => (attack_cooldown.timer.fraction(), attack_cooldown.timer.is_finished()),
+            CooldownSource::Dash => match dash_cooldown {
+                Some(dash) => (dash.timer.fraction(), dash.timer.is_finished()),
+                None => continue,
+            },
+            CooldownSource::Stamina => match stamina {
+                Some((stamina, stats)) => {
+                    let fraction = (stamina.current / stats.max_stamina).clamp(0.0, 1.0);
+                    (fraction, fraction >= 1.0)
+                }
+                None => continue,
+            },
+        };
 
         ring.last_facing = facing;
 
@@ -163,8 +188,16 @@ pub fn cooldown_ring_system(
 
         // Яркость: ярче когда готов, тусклее на кулдауне
         let t = time.elapsed_secs();
-        let ready = cooldown.timer.is_finished();
-        let alpha = if ready {
+        let exhausted = stamina.is_some_and(|(stamina, _)| stamina.exhausted);
+        let alpha = if ring.source == CooldownSource::Stamina {
+            if ready {
+                0.0
+            } else if exhausted {
+                0.3 + 0.2 * (t * 10.0).sin() // Частое мерцание — спринт заблокирован
+            } else {
+                0.7
+            }
+        } else if ready {
             0.7 + 0.15 * (t * 4.0).sin() // Пульс когда готов
         } else {
             0.35 + cd_fraction * 0.25 // Растёт с прогрессом кулдауна
@@ -180,7 +213,7 @@ pub fn cooldown_ring_system(
 
 /// Обновляет позиции вершин дуги in-place (0 аллокаций, если vertex count совпадает).
 /// Если меш ещё не был инициализирован (другой vertex count) — пересоздаёт целиком.
-fn update_arc_positions_inplace(mesh: &mut Mesh, inner_r: f32, outer_r: f32, fraction: f32, segments: u32) {
+pub fn update_arc_positions_inplace(mesh: &mut Mesh, inner_r: f32, outer_r: f32, fraction: f32, segments: u32) {
     let half_angle = fraction * std::f32::consts::PI;
     let center_angle = std::f32::consts::FRAC_PI_2;
     let seg = segments.max(3);
@@ -304,3 +337,50 @@ pub fn selection_highlight_system(
         }
     }
 }
+
+/// Исходный цвет HP-кольца, сохранённый на время баффа ауры Колдуна
+#[derive(Component)]
+pub struct AuraBuffTint {
+    pub original_color: Color,
+    pub original_emissive: LinearRgba,
+}
+
+/// Баффнутые союзники Колдуна: HP-кольцо светится фиолетовым, по снятию — исходный цвет.
+/// Alpha не трогаем — пульсацию продолжает health_ring_system.
+pub fn aura_buff_ring_system(
+    mut commands: Commands,
+    buffed: Query<&Children, (With<Enemy>, Added<AuraBuff>)>,
+    mut unbuffed: RemovedComponents<AuraBuff>,
+    children_query: Query<&Children>,
+    circle_query: Query<(&GroundCircle, Option<&AuraBuffTint>)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for children in &buffed {
+        for child in children.iter() {
+            let Ok((circle, tint)) = circle_query.get(child) else { continue };
+            if tint.is_some() { continue; }
+            let Some(mat) = materials.get_mut(&circle.material_handle) else { continue };
+            commands.entity(child).insert(AuraBuffTint {
+                original_color: mat.base_color,
+                original_emissive: mat.emissive,
+            });
+            let alpha = mat.base_color.alpha();
+            mat.base_color = Color::srgba(0.75, 0.35, 1.0, alpha);
+            mat.emissive = LinearRgba::new(1.5, 0.5, 3.0, 0.0);
+        }
+    }
+
+    for entity in unbuffed.read() {
+        // Враг мог умереть/исчезнуть вместе с бафом
+        let Ok(children) = children_query.get(entity) else { continue };
+        for child in children.iter() {
+            let Ok((circle, Some(tint))) = circle_query.get(child) else { continue };
+            if let Some(mat) = materials.get_mut(&circle.material_handle) {
+                let alpha = mat.base_color.alpha();
+                mat.base_color = tint.original_color.with_alpha(alpha);
+                mat.emissive = tint.original_emissive;
+            }
+            commands.entity(child).remove::<AuraBuffTint>();
+        }
+    }
+}
@@ -2,6 +2,8 @@ use bevy::prelude::*;
 use bevy::light::NotShadowCaster;
 use bevy::render::render_resource::*;
 use bevy::shader::ShaderRef;
+use super::arena_layout::ArenaEntity;
+use super::safe_zone::{SafeZone, UNBOUNDED_RADIUS};
 
 /// Параметры тумана — передаются в шейдер (vertex + fragment)
 #[derive(Clone, Debug, Reflect, ShaderType)]
@@ -12,7 +14,12 @@ pub struct GroundFogSettings {
     pub max_height: f32,
     pub density: f32,
     pub layer_index: f32,
+    /// Радиус безопасной зоны: за ним туман Нави встаёт стеной
+    pub safe_radius: f32,
+    /// Доп. подъём слоя за границей зоны
+    pub edge_rise: f32,
     pub _pad1: f32,
+    pub safe_center: Vec2,
     pub _pad2: f32,
     pub _pad3: f32,
 }
@@ -38,36 +45,42 @@ impl Material for GroundFogMaterial {
     }
 }
 
-/// Конфиг слоёв: (высота Y, max подъём, плотность, скорость)
-const FOG_LAYERS: [(f32, f32, f32, f32); 2] = [
-    (0.05, 1.4, 0.40, 0.20),  // Нижний — плотный, высокий, медленный
-    (0.10, 0.9, 0.25, 0.35),  // Верхний — легче, ниже, быстрее
+/// Конфиг слоёв: (высота Y, max подъём, плотность, скорость, подъём за границей зоны)
+const FOG_LAYERS: [(f32, f32, f32, f32, f32); 2] = [
+    (0.05, 1.4, 0.40, 0.20, 3.0),  // Нижний — плотный, высокий, медленный
+    (0.10, 0.9, 0.25, 0.35, 2.0),  // Верхний — легче, ниже, быстрее
 ];
 
-/// Спавн двух слоёв тумана с subdivided mesh (vertex displacement)
-pub fn setup_ground_fog(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<GroundFogMaterial>>,
+/// Спавн двух слоёв тумана с subdivided mesh (vertex displacement).
+/// Цвет — из атмосферы биома, размер — арена + кромка у стен.
+pub fn spawn_ground_fog(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<GroundFogMaterial>>,
+    fog_color: LinearRgba,
+    size: f32,
 ) {
     // 64×64 вершин = ~8000 треугольников — ничтожно для GPU
     let fog_mesh = meshes.add(
         Plane3d::default()
             .mesh()
-            .size(52.0, 52.0)
+            .size(size, size)
             .subdivisions(63),
     );
 
-    for (i, &(y, max_h, density, speed)) in FOG_LAYERS.iter().enumerate() {
+    for (i, &(y, max_h, density, speed, edge_rise)) in FOG_LAYERS.iter().enumerate() {
         let mat = materials.add(GroundFogMaterial {
             settings: GroundFogSettings {
-                fog_color: LinearRgba::new(0.25, 0.20, 0.45, 1.0),
+                fog_color,
                 time: 0.0,
                 speed,
                 max_height: max_h,
                 density,
                 layer_index: i as f32,
+                safe_radius: UNBOUNDED_RADIUS,
+                edge_rise,
                 _pad1: 0.0,
+                safe_center: Vec2::ZERO,
                 _pad2: 0.0,
                 _pad3: 0.0,
             },
@@ -78,17 +91,21 @@ pub fn setup_ground_fog(
             MeshMaterial3d(mat),
             Transform::from_xyz(0.0, y, 0.0),
             NotShadowCaster,
+            ArenaEntity,
         ));
     }
 }
 
-/// Обновление time uniform для анимации displacement
+/// Обновление time uniform для анимации displacement + граница безопасной зоны
 pub fn update_ground_fog_time(
     time: Res<Time>,
+    safe_zone: Res<SafeZone>,
     mut materials: ResMut<Assets<GroundFogMaterial>>,
 ) {
     let t = time.elapsed_secs();
     for (_, material) in materials.iter_mut() {
         material.settings.time = t;
+        material.settings.safe_radius = safe_zone.radius;
+        material.settings.safe_center = safe_zone.center;
     }
 }
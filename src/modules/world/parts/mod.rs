@@ -1,7 +1,22 @@
 //! Реализация игрового мира:
-//! - setup_scene: лесная арена 50x50м (трава, скалы, рунные камни, декор)
+//! - arena_layout: ассет ArenaLayout (*.arena.ron) — пол, свет, стены, декор, опасные зоны, порталы, спавн героя
+//! - setup_scene: спавн арены из загруженной раскладки
+//! - scatter: seed забега и процедурная расстановка декора (Poisson-disc, проверка связности)
+//! - biomes: каталог биомов (BIOMES), выбор на титульном экране, смена арены перед забегом
+//! - game_modes: режимы забега (GAME_MODES) — расписание сжатия арены туманом Нави, победа печатями (Поход)
+//! - safe_zone: безопасная зона арены (центр, текущий и целевой радиус)
+//! - portal_setup: Разломы Нави (модель, вихрь, свет) по PortalStyle — стартовые и открывающиеся по ходу забега
+//! - ground_fog: стелющийся туман (шейдер, цвет из атмосферы биома, подъём за границей безопасной зоны)
+//! - ambient_particles: парящие частицы биома (угольки, светлячки, снег)
 //! - ground_circle: кольца HP под сущностями (динамический меш-арка, поворот, пульсация)
 
+pub(super) mod arena_layout;
+pub(super) mod biomes;
+pub(super) mod game_modes;
+pub(super) mod safe_zone;
+pub(super) mod scatter;
+pub(super) mod ground_fog;
+pub(super) mod ambient_particles;
 pub(super) mod setup_scene;
 pub(super) mod portal_setup;
 pub(super) mod portal_fill;
@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+
+/// Радиус «без ограничений» — зона заведомо шире любой арены (туман у краёв как обычно)
+pub const UNBOUNDED_RADIUS: f32 = 1000.0;
+
+/// Безопасная зона арены: за её границей — туман Нави, жжёт героя и выталкивает орбы.
+/// В режимах без сжатия радиус UNBOUNDED_RADIUS. Расписание и урон — в hazards::navi_fog.
+#[derive(Resource)]
+pub struct SafeZone {
+    pub center: Vec2,
+    pub radius: f32,
+    pub target_radius: f32,
+    /// Скорость сжатия текущей стадии (м/с)
+    pub shrink_speed: f32,
+    /// Следующая стадия расписания режима
+    pub next_stage: usize,
+    /// Секунд подряд герой за границей — от этого растёт урон
+    pub time_outside: f32,
+}
+
+impl Default for SafeZone {
+    fn default() -> Self {
+        Self {
+            center: Vec2::ZERO,
+            radius: UNBOUNDED_RADIUS,
+            target_radius: UNBOUNDED_RADIUS,
+            shrink_speed: 0.0,
+            next_stage: 0,
+            time_outside: 0.0,
+        }
+    }
+}
+
+impl SafeZone {
+    /// Зона реально ограничивает арену (режим со сжатием)
+    pub fn is_bounded(&self) -> bool {
+        self.radius < UNBOUNDED_RADIUS
+    }
+
+    /// Насколько точка (XZ) за границей зоны; 0 — внутри
+    pub fn distance_outside(&self, pos: Vec3) -> f32 {
+        (Vec2::new(pos.x, pos.z).distance(self.center) - self.radius).max(0.0)
+    }
+
+    pub fn contains(&self, pos: Vec3) -> bool {
+        self.distance_outside(pos) <= 0.0
+    }
+}
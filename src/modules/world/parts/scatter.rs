@@ -0,0 +1,366 @@
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use std::f32::consts::{SQRT_2, TAU};
+use crate::shared::{rand_u64, RunSeed, SeededRng};
+use super::arena_layout::{ActiveArena, ArenaBounds, ArenaLayout, CurrentArena, PropDef, ScatterDef, ScatterEntry};
+use super::setup_scene::spawn_prop;
+
+/// Попыток Bridson вокруг активной точки до её отбраковки
+const POISSON_ATTEMPTS: usize = 30;
+/// Ячейка сетки проверки связности (м)
+const COVERAGE_CELL: f32 = 1.0;
+/// Радиус агента — проход уже этого считается закрытым
+const AGENT_RADIUS: f32 = 0.5;
+/// Зазор между декором и краем опасной зоны — зона читается целиком
+const HAZARD_CLEARANCE: f32 = 1.0;
+
+/// Маркер процедурно расставленного декора (пересоздаётся каждый забег)
+#[derive(Component)]
+pub struct ScatterProp;
+
+/// Расстановка для текущего seed готова — экран загрузки ждёт её
+#[derive(Resource, Default)]
+pub struct ArenaScatter {
+    pub ready: bool,
+}
+
+/// OnEnter(Loading): новый seed забега. CHERTOGON_SEED (native) — повторить конкретную арену.
+pub fn new_run_seed(mut commands: Commands, mut scatter: ResMut<ArenaScatter>) {
+    let seed = seed_override().unwrap_or_else(rand_u64);
+    info!("🎲 Run seed: {}", seed);
+    commands.insert_resource(RunSeed(seed));
+    scatter.ready = false;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn seed_override() -> Option<u64> {
+    std::env::var("CHERTOGON_SEED").ok()?.parse().ok()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn seed_override() -> Option<u64> {
+    None
+}
+
+/// Снимает расстановку прошлого забега и раскидывает новую по seed (после спавна арены)
+pub fn scatter_props_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut scatter: ResMut<ArenaScatter>,
+    seed: Option<Res<RunSeed>>,
+    active: Res<ActiveArena>,
+    arena: Option<Res<CurrentArena>>,
+    old_props: Query<Entity, With<ScatterProp>>,
+) {
+    if scatter.ready {
+        return;
+    }
+    let Some(seed) = seed else { return };
+    let Some(arena) = arena else {
+        // Раскладка не загрузилась — расставлять не на чем, загрузку не держим
+        scatter.ready = active.instantiated;
+        return;
+    };
+
+    for entity in &old_props {
+        commands.entity(entity).despawn();
+    }
+    if let Some(def) = &arena.scatter {
+        let props = generate_scatter(def, &arena, seed.0);
+        for prop in &props {
+            let entity = spawn_prop(&mut commands, &asset_server, prop);
+            commands.entity(entity).insert(ScatterProp);
+        }
+        info!("🪨 Scattered {} props (seed {})", props.len(), seed.0);
+    }
+    scatter.ready = true;
+}
+
+/// Детерминированная расстановка: один seed и одна раскладка — всегда одна арена.
+/// Пулы идут по порядку; точка отбраковывается, если она в чистой зоне героя, в коридоре
+/// портала, в опасной зоне, слишком близко к соседям или отрезает часть арены от героя.
+pub fn generate_scatter(def: &ScatterDef, layout: &ArenaLayout, seed: u64) -> Vec<PropDef> {
+    let mut rng = SeededRng::new(seed);
+    let spawn = layout.player_spawn.xz();
+    // Коридоры — и от будущих разломов: открывшись, они не должны упираться в декор
+    let lanes: Vec<(Vec2, Vec2)> = layout.portal_sites()
+        .map(|portal| (portal.spawn.xz(), spawn))
+        .collect();
+    let area_min = layout.bounds.min + Vec2::splat(def.edge_margin);
+    let area_max = layout.bounds.max - Vec2::splat(def.edge_margin);
+
+    // Стены и ручной декор с коллайдерами уже занимают место
+    let mut coverage = Coverage::new(&layout.bounds, spawn);
+    let wall_half = layout.walls.collider_half_extents.xz();
+    for section in &layout.walls.sections {
+        coverage.stamp_box(section.position.xz(), wall_half * section.scale.xz(), section.rotation);
+    }
+    let mut placed: Vec<Vec2> = Vec::new();
+    for prop in &layout.props {
+        if let Some(collider) = prop.collider {
+            let pos = prop.position.xz();
+            coverage.stamp_circle(pos, collider.footprint_radius() * prop.scale + AGENT_RADIUS);
+            placed.push(pos);
+        }
+    }
+    coverage.seal();
+
+    let mut props = Vec::new();
+    for pool in &def.pools {
+        let total_weight: f32 = pool.entries.iter().map(|entry| entry.weight.max(0.0)).sum();
+        if pool.count == 0 || total_weight <= 0.0 {
+            continue;
+        }
+
+        let mut pool_placed = 0;
+        for point in poisson_disc(&mut rng, area_min, area_max, pool.min_distance) {
+            if pool_placed == pool.count {
+                break;
+            }
+            // Выбор варианта и масштаба — до проверок: последовательность rng не зависит от отказов
+            let entry = pick_entry(&mut rng, &pool.entries, total_weight);
+            let scale = rng.range(entry.scale.0, entry.scale.1);
+            let rotation = rng.range(0.0, TAU);
+            let footprint = entry.collider.map_or(0.0, |collider| collider.footprint_radius() * scale);
+
+            if point.distance(spawn) < def.spawn_clearance + footprint {
+                continue;
+            }
+            if lanes.iter().any(|&(a, b)| distance_to_segment(point, a, b) < def.lane_width * 0.5 + footprint) {
+                continue;
+            }
+            if placed.iter().any(|other| other.distance(point) < pool.min_distance) {
+                continue;
+            }
+            if layout.hazards.iter().any(|hazard| point.distance(hazard.position.xz()) < hazard.radius + footprint + HAZARD_CLEARANCE) {
+                continue;
+            }
+            if footprint > 0.0 && !coverage.try_block(point, footprint + AGENT_RADIUS) {
+                continue;
+            }
+
+            placed.push(point);
+            props.push(PropDef {
+                model: entry.model.clone(),
+                position: Vec3::new(point.x, 0.0, point.y),
+                rotation,
+                scale,
+                collider: entry.collider,
+                light: entry.light.clone(),
+                shrine: entry.shrine,
+            });
+            pool_placed += 1;
+        }
+        if pool_placed < pool.count {
+            debug!("🪨 Scatter pool placed {}/{} (not enough room)", pool_placed, pool.count);
+        }
+    }
+    props
+}
+
+fn pick_entry<'a>(rng: &mut SeededRng, entries: &'a [ScatterEntry], total_weight: f32) -> &'a ScatterEntry {
+    let mut roll = rng.next_f32() * total_weight;
+    for entry in entries {
+        let weight = entry.weight.max(0.0);
+        if roll < weight {
+            return entry;
+        }
+        roll -= weight;
+    }
+    &entries[entries.len() - 1]
+}
+
+/// Bridson Poisson-disc: точки не ближе radius друг к другу, равномерно по области.
+/// Результат перемешан — первые N точек тоже равномерны.
+fn poisson_disc(rng: &mut SeededRng, min: Vec2, max: Vec2, radius: f32) -> Vec<Vec2> {
+    let size = max - min;
+    if radius <= 0.0 || size.x <= 0.0 || size.y <= 0.0 {
+        return Vec::new();
+    }
+    let cell = radius / SQRT_2;
+    let width = (size.x / cell).ceil() as usize;
+    let height = (size.y / cell).ceil() as usize;
+    let mut grid: Vec<Option<usize>> = vec![None; width * height];
+    let cell_of = |p: Vec2| {
+        let x = (((p.x - min.x) / cell) as usize).min(width - 1);
+        let y = (((p.y - min.y) / cell) as usize).min(height - 1);
+        (x, y)
+    };
+
+    let mut points = Vec::new();
+    let mut active = Vec::new();
+    let first = Vec2::new(rng.range(min.x, max.x), rng.range(min.y, max.y));
+    let (fx, fy) = cell_of(first);
+    grid[fy * width + fx] = Some(0);
+    points.push(first);
+    active.push(0);
+
+    while !active.is_empty() {
+        let slot = rng.index(active.len());
+        let origin = points[active[slot]];
+        let mut found = false;
+
+        for _ in 0..POISSON_ATTEMPTS {
+            let candidate = origin + Vec2::from_angle(rng.range(0.0, TAU)) * rng.range(radius, radius * 2.0);
+            if candidate.x < min.x || candidate.y < min.y || candidate.x >= max.x || candidate.y >= max.y {
+                continue;
+            }
+            let (cx, cy) = cell_of(candidate);
+            let too_close = (cy.saturating_sub(2)..(cy + 3).min(height)).any(|y| {
+                (cx.saturating_sub(2)..(cx + 3).min(width)).any(|x| {
+                    grid[y * width + x].is_some_and(|i| points[i].distance(candidate) < radius)
+                })
+            });
+            if too_close {
+                continue;
+            }
+            grid[cy * width + cx] = Some(points.len());
+            active.push(points.len());
+            points.push(candidate);
+            found = true;
+            break;
+        }
+
+        if !found {
+            active.swap_remove(slot);
+        }
+    }
+
+    // Fisher–Yates
+    for i in (1..points.len()).rev() {
+        points.swap(i, rng.index(i + 1));
+    }
+    points
+}
+
+fn distance_to_segment(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let t = if ab.length_squared() > 0.0 { ((p - a).dot(ab) / ab.length_squared()).clamp(0.0, 1.0) } else { 0.0 };
+    p.distance(a + ab * t)
+}
+
+/// Грубая сетка проходимости арены: препятствие принимается, только если все клетки,
+/// достижимые от героя, остаются достижимыми (не появляется запертых карманов)
+struct Coverage {
+    min: Vec2,
+    width: usize,
+    height: usize,
+    blocked: Vec<bool>,
+    /// Клетка героя — источник flood fill
+    start: Option<usize>,
+    reachable: Vec<bool>,
+    reachable_count: usize,
+}
+
+impl Coverage {
+    fn new(bounds: &ArenaBounds, from: Vec2) -> Self {
+        let size = bounds.max - bounds.min;
+        let width = (size.x / COVERAGE_CELL).ceil().max(1.0) as usize;
+        let height = (size.y / COVERAGE_CELL).ceil().max(1.0) as usize;
+        let local = ((from - bounds.min) / COVERAGE_CELL).floor();
+        let start = (local.x >= 0.0 && local.y >= 0.0 && local.x < width as f32 && local.y < height as f32)
+            .then(|| local.y as usize * width + local.x as usize);
+        Self {
+            min: bounds.min,
+            width,
+            height,
+            blocked: vec![false; width * height],
+            start,
+            reachable: vec![false; width * height],
+            reachable_count: 0,
+        }
+    }
+
+    fn cell_center(&self, cell: usize) -> Vec2 {
+        self.min + (Vec2::new((cell % self.width) as f32, (cell / self.width) as f32) + 0.5) * COVERAGE_CELL
+    }
+
+    /// Клетки, центр которых внутри круга
+    fn cells_in_circle(&self, center: Vec2, radius: f32) -> Vec<usize> {
+        let lo = ((center - Vec2::splat(radius) - self.min) / COVERAGE_CELL).floor().max(Vec2::ZERO);
+        let hi = ((center + Vec2::splat(radius) - self.min) / COVERAGE_CELL).ceil();
+        let mut cells = Vec::new();
+        for y in lo.y as usize..(hi.y as usize).min(self.height) {
+            for x in lo.x as usize..(hi.x as usize).min(self.width) {
+                let cell = y * self.width + x;
+                if self.cell_center(cell).distance(center) <= radius {
+                    cells.push(cell);
+                }
+            }
+        }
+        cells
+    }
+
+    fn stamp_circle(&mut self, center: Vec2, radius: f32) {
+        for cell in self.cells_in_circle(center, radius) {
+            self.blocked[cell] = true;
+        }
+    }
+
+    /// Повёрнутый прямоугольник (секция стены) + радиус агента
+    fn stamp_box(&mut self, center: Vec2, half_extents: Vec2, rotation: f32) {
+        let inverse = Quat::from_rotation_y(-rotation);
+        for cell in 0..self.blocked.len() {
+            let offset = self.cell_center(cell) - center;
+            let local = (inverse * Vec3::new(offset.x, 0.0, offset.y)).xz().abs();
+            if local.x <= half_extents.x + AGENT_RADIUS && local.y <= half_extents.y + AGENT_RADIUS {
+                self.blocked[cell] = true;
+            }
+        }
+    }
+
+    /// Фиксирует статичную часть арены: дальше сравниваем достижимость с ней
+    fn seal(&mut self) {
+        (self.reachable, self.reachable_count) = self.flood_fill();
+    }
+
+    /// Занимает круг, если ни одна достижимая клетка не отрезана; иначе откатывает
+    fn try_block(&mut self, center: Vec2, radius: f32) -> bool {
+        let cells: Vec<usize> = self.cells_in_circle(center, radius)
+            .into_iter()
+            .filter(|&cell| !self.blocked[cell])
+            .collect();
+        let lost = cells.iter().filter(|&&cell| self.reachable[cell]).count();
+        for &cell in &cells {
+            self.blocked[cell] = true;
+        }
+
+        let (reachable, count) = self.flood_fill();
+        if count + lost == self.reachable_count {
+            self.reachable = reachable;
+            self.reachable_count = count;
+            return true;
+        }
+        for &cell in &cells {
+            self.blocked[cell] = false;
+        }
+        false
+    }
+
+    /// Flood fill (4 соседа) от клетки героя
+    fn flood_fill(&self) -> (Vec<bool>, usize) {
+        let mut visited = vec![false; self.blocked.len()];
+        let Some(start) = self.start.filter(|&start| !self.blocked[start]) else { return (visited, 0) };
+
+        let mut queue = VecDeque::from([start]);
+        visited[start] = true;
+        let mut reached = 1;
+        while let Some(cell) = queue.pop_front() {
+            let (x, y) = (cell % self.width, cell / self.width);
+            let neighbors = [
+                (x > 0).then(|| cell - 1),
+                (x + 1 < self.width).then(|| cell + 1),
+                (y > 0).then(|| cell - self.width),
+                (y + 1 < self.height).then(|| cell + self.width),
+            ];
+            for next in neighbors.into_iter().flatten() {
+                if !visited[next] && !self.blocked[next] {
+                    visited[next] = true;
+                    reached += 1;
+                    queue.push_back(next);
+                }
+            }
+        }
+        (visited, reached)
+    }
+}
@@ -13,6 +13,14 @@ pub struct RimLightSettings {
     pub power: f32,
 }
 
+/// Rim light персонажей — общий для скиннутых моделей и VAT-импостеров врагов
+pub fn character_rim_light() -> RimLightSettings {
+    RimLightSettings {
+        color: LinearRgba::new(0.4, 0.4, 0.8, 0.6),
+        power: 3.0,
+    }
+}
+
 /// MaterialExtension для стилизованного PBR с rim light
 #[derive(Asset, AsBindGroup, Reflect, Debug, Clone)]
 pub struct RimLightExtension {
@@ -57,10 +65,7 @@ pub fn replace_character_materials(
                     let stylized = StylizedMaterial {
                         base: base_material.clone(),
                         extension: RimLightExtension {
-                            settings: RimLightSettings {
-                                color: LinearRgba::new(0.4, 0.4, 0.8, 0.6),
-                                power: 3.0,
-                            },
+                            settings: character_rim_light(),
                         },
                     };
 
@@ -2,8 +2,14 @@ use bevy::prelude::*;
 use bevy::pbr::MaterialPlugin;
 use crate::shared::GameState;
 use super::parts::setup_scene;
+use super::parts::arena_layout;
+use super::parts::biomes;
+use super::parts::game_modes;
+use super::parts::safe_zone;
+use super::parts::scatter;
+use super::parts::ground_fog;
+use super::parts::ambient_particles;
 use super::parts::ground_circle;
-use super::parts::portal_setup;
 use super::parts::stylized_material;
 use super::parts::portal_fill;
 use super::parts::range_indicator;
@@ -14,9 +20,20 @@ impl Plugin for WorldPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(MaterialPlugin::<stylized_material::StylizedMaterial>::default())
             .add_plugins(MaterialPlugin::<portal_fill::PortalVortexMaterial>::default())
-            .add_systems(Startup, setup_scene::setup_scene)
-            .add_systems(Startup, portal_setup::setup_portals)
+            .add_plugins(MaterialPlugin::<ground_fog::GroundFogMaterial>::default())
+            .init_resource::<biomes::SelectedBiome>()
+            .init_resource::<game_modes::SelectedMode>()
+            .init_resource::<safe_zone::SafeZone>()
+            .init_resource::<scatter::ArenaScatter>()
+            .init_asset::<arena_layout::ArenaLayout>()
+            .init_asset_loader::<arena_layout::ArenaLayoutLoader>()
+            .add_systems(Startup, arena_layout::load_arena_layout)
+            .add_systems(OnEnter(GameState::Loading), (biomes::sync_active_arena, scatter::new_run_seed))
             .add_systems(Update, (
+                setup_scene::setup_scene,
+                scatter::scatter_props_system.after(setup_scene::setup_scene),
+                ground_fog::update_ground_fog_time,
+                ambient_particles::ambient_particles_system,
                 stylized_material::replace_character_materials,
                 ground_circle::health_ring_system
                     .run_if(in_state(GameState::Playing)),
@@ -26,6 +43,8 @@ impl Plugin for WorldPlugin {
                     .run_if(in_state(GameState::Playing)),
                 ground_circle::selection_highlight_system
                     .run_if(in_state(GameState::Playing)),
+                ground_circle::aura_buff_ring_system
+                    .run_if(in_state(GameState::Playing)),
                 range_indicator::spawn_range_indicator
                     .run_if(in_state(GameState::Playing)),
                 range_indicator::despawn_range_indicator
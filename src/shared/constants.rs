@@ -3,6 +3,13 @@
 pub const WALK_SPEED: f32 = 5.0;
 pub const RUN_SPEED: f32 = 10.0;
 
+// Модель разгона игрока
+pub const PLAYER_ACCELERATION: f32 = 35.0;  // м/с² — до бега ~0.3с
+pub const PLAYER_DECELERATION: f32 = 45.0;  // м/с² — торможение резче разгона
+pub const PLAYER_TURN_RATE: f32 = 12.0;     // рад/с — разворот на 180° ≈ 0.26с
+pub const SPRINT_STAMINA_DRAIN: f32 = 25.0; // ед/с — базовый запас 100 = 4с спринта
+pub const STAMINA_RECOVER_FRACTION: f32 = 0.3; // после опустошения спринт снова доступен с 30%
+
 // Параметры камеры (оптимизировано для arena shooter)
 pub const CAMERA_FOLLOW_SPEED: f32 = 15.0;  // Exponential decay rate (higher = snappier)
 pub const CAMERA_OFFSET_Y: f32 = 16.0;  // Высота камеры (было 8.0, удвоено для лучшего обзора)
@@ -15,17 +22,8 @@ pub const CAMERA_ZOOM_DEFAULT: f32 = 14.0;  // По умолчанию (теку
 pub const CAMERA_ZOOM_SPEED: f32 = 1.5;     // Чувствительность зума
 pub const CAMERA_ZOOM_SMOOTHNESS: f32 = 8.0; // Exponential decay rate for zoom
 
-// Позиции порталов ("Разломы Нави") на дальней стене арены (верх экрана)
-use bevy::prelude::Vec3;
+// Позиции порталов ("Разломы Нави") — в раскладке арены (assets/arenas/*.arena.ron)
 
-/// Портал 1 — "Разлом Огня" (верх-лево)
-pub const PORTAL_1_POS: Vec3 = Vec3::new(-20.0, 1.5, -24.5);
-/// Портал 2 — "Разлом Тьмы" (верх-право)
-pub const PORTAL_2_POS: Vec3 = Vec3::new(20.0, 1.5, -24.5);
-/// Точка спавна врагов из портала 1 (5м от стены, внутрь арены)
-pub const PORTAL_1_SPAWN: Vec3 = Vec3::new(-20.0, 0.9, -20.0);
-/// Точка спавна врагов из портала 2
-pub const PORTAL_2_SPAWN: Vec3 = Vec3::new(20.0, 0.9, -20.0);
 /// Базовый радиус воронки портала (диаметр 3м)
 pub const PORTAL_BASE_RADIUS: f32 = 1.5;
 
@@ -38,21 +36,23 @@ pub enum GameLayer {
     Static,    // Стены, пол, статические объекты
     Player,    // Игрок
     Enemy,     // Враги
-    Projectile // Пули/снаряды (для будущего расширения)
+    Projectile, // Пули/снаряды (для будущего расширения)
+    Debris     // Обломки разбитых урн и бочек (только косметика)
 }
 
 impl GameLayer {
     /// Возвращает CollisionLayers для статических объектов (стены, пол)
-    /// Коллайдируют с: Player, Enemy, Projectile
+    /// Коллайдируют с: Player, Enemy, Projectile, Debris
     pub fn static_layers() -> CollisionLayers {
         CollisionLayers::new(
             [GameLayer::Static],
-            [GameLayer::Player, GameLayer::Enemy, GameLayer::Projectile]
+            [GameLayer::Player, GameLayer::Enemy, GameLayer::Projectile, GameLayer::Debris]
         )
     }
 
     /// Возвращает CollisionLayers для игрока
     /// Коллайдирует с: Static (проходит сквозь врагов — стандарт VS/action RPG)
+    /// Окружение толпой — опция BodyBlocking (мягкое выталкивание, не физический контакт)
     pub fn player_layers() -> CollisionLayers {
         CollisionLayers::new(
             [GameLayer::Player],
@@ -61,11 +61,20 @@ impl GameLayer {
     }
 
     /// Возвращает CollisionLayers для врагов
-    /// Коллайдируют с: Static (enemy_separation_system отталкивает через SpatialGrid)
+    /// Коллайдируют с: Static (enemy_separation_system отталкивает через SpatialIndex)
     pub fn enemy_layers() -> CollisionLayers {
         CollisionLayers::new(
             [GameLayer::Enemy],
             [GameLayer::Static]
         )
     }
+
+    /// Возвращает CollisionLayers для обломков
+    /// Коллайдируют с: Static (падают на пол и отскакивают от скал, героя и врагов не толкают)
+    pub fn debris_layers() -> CollisionLayers {
+        CollisionLayers::new(
+            [GameLayer::Debris],
+            [GameLayer::Static]
+        )
+    }
 }
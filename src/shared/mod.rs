@@ -4,4 +4,4 @@ pub mod rng;
 
 pub use constants::GameLayer;
 pub use game_state::GameState;
-pub use rng::rand_01;
+pub use rng::{rand_01, rand_u64, RunSeed, SeededRng};
@@ -1,5 +1,12 @@
+use bevy::prelude::Resource;
+
 /// Pseudo-random [0.0, 1.0) — xorshift64, seed из адреса стека (WASM-safe)
 pub fn rand_01() -> f32 {
+    ((rand_u64() % 10000) as f32) / 10000.0
+}
+
+/// Сырое состояние глобального xorshift64 (общее с rand_01)
+pub fn rand_u64() -> u64 {
     use std::sync::atomic::{AtomicU64, Ordering};
     static RNG_STATE: AtomicU64 = AtomicU64::new(0);
 
@@ -14,5 +21,44 @@ pub fn rand_01() -> f32 {
     state ^= state >> 7;
     state ^= state << 17;
     RNG_STATE.store(state, Ordering::Relaxed);
-    ((state % 10000) as f32) / 10000.0
+    state
+}
+
+/// Seed забега — всё процедурное в арене выводится из него (один seed — одна арена)
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RunSeed(pub u64);
+
+/// Детерминированный xorshift64* — своя последовательность на каждый seed,
+/// не зависит от глобального rand_01
+pub struct SeededRng(u64);
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        // splitmix: соседние seed дают непохожие последовательности; состояние не ноль
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        Self((z ^ (z >> 31)) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// [0.0, 1.0)
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    pub fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+
+    /// [0, len)
+    pub fn index(&mut self, len: usize) -> usize {
+        (self.next_u64() % len.max(1) as u64) as usize
+    }
 }
@@ -1,63 +1,39 @@
 // Пути к ассетам игры
 
 // Модель богатыря (merged GLB — все анимации в одном файле)
+pub const BOGATYR_GLB: &str = "models/characters/bogatyr/bogatyr_merged.glb";
 pub const BOGATYR_MODEL: &str = "models/characters/bogatyr/bogatyr_merged.glb#Scene0";
 
-// Анимации богатыря (из единого merged GLB)
-pub const ANIM_IDLE: &str = "models/characters/bogatyr/bogatyr_merged.glb#Animation1";    // Idle (4s)
-pub const ANIM_WALK: &str = "models/characters/bogatyr/bogatyr_merged.glb#Animation2";    // walking_man
-pub const ANIM_RUN: &str = "models/characters/bogatyr/bogatyr_merged.glb#Animation3";     // running
-pub const ANIM_ATTACK: &str = "models/characters/bogatyr/bogatyr_merged.glb#Animation4";  // Attack
-pub const ANIM_HIT: &str = "models/characters/bogatyr/bogatyr_merged.glb#Animation5";     // Hit Reaction
-
 // Модель Упыря (merged GLB — все анимации в одном файле)
+pub const UPYR_GLB: &str = "models/enemies/upyr_merged.glb";
 pub const UPYR_MODEL: &str = "models/enemies/upyr_merged.glb#Scene0";
-pub const UPYR_ANIM_IDLE: &str = "models/enemies/upyr_merged.glb#Animation1";         // Idle
-pub const UPYR_ANIM_WALK: &str = "models/enemies/upyr_merged.glb#Animation2";         // Monster_Walk
-pub const UPYR_ANIM_ATTACK: &str = "models/enemies/upyr_merged.glb#Animation3";       // Attack
-pub const UPYR_ANIM_DEATH: &str = "models/enemies/upyr_merged.glb#Animation4";        // Dead
-pub const UPYR_ANIM_HIT: &str = "models/enemies/upyr_merged.glb#Animation5";          // Hit_Reaction
-pub const UPYR_ANIM_RUN: &str = "models/enemies/upyr_merged.glb#Animation6";          // Running
-pub const UPYR_ANIM_SCREAM: &str = "models/enemies/upyr_merged.glb#Animation7";       // Zombie_Scream
 
 // Модель Лешего (merged GLB — все анимации в одном файле)
+pub const LESHIY_GLB: &str = "models/enemies/leshiy_merged.glb";
 pub const LESHIY_MODEL: &str = "models/enemies/leshiy_merged.glb#Scene0";
-pub const LESHIY_ANIM_IDLE: &str = "models/enemies/leshiy_merged.glb#Animation0";      // Idle
-pub const LESHIY_ANIM_WALK: &str = "models/enemies/leshiy_merged.glb#Animation1";      // Walking
-pub const LESHIY_ANIM_RUN: &str = "models/enemies/leshiy_merged.glb#Animation2";       // Running
-pub const LESHIY_ANIM_ATTACK: &str = "models/enemies/leshiy_merged.glb#Animation3";    // Slash Attack
-pub const LESHIY_ANIM_DEATH: &str = "models/enemies/leshiy_merged.glb#Animation4";     // Death
-pub const LESHIY_ANIM_HIT: &str = "models/enemies/leshiy_merged.glb#Animation5";       // Hit Reaction
 
 // Модель Волколака (Tripo GLB — 6 анимаций квадрупеда)
+pub const VOLKOLAK_GLB: &str = "models/enemies/volkolak_merged.glb";
 pub const VOLKOLAK_MODEL: &str = "models/enemies/volkolak_merged.glb#Scene0";
-pub const VOLKOLAK_ANIM_IDLE: &str = "models/enemies/volkolak_merged.glb#Animation0";    // idle
-pub const VOLKOLAK_ANIM_WALK: &str = "models/enemies/volkolak_merged.glb#Animation1";    // walk
-pub const VOLKOLAK_ANIM_RUN: &str = "models/enemies/volkolak_merged.glb#Animation2";     // run
-pub const VOLKOLAK_ANIM_ATTACK: &str = "models/enemies/volkolak_merged.glb#Animation3";  // attack (slash)
-pub const VOLKOLAK_ANIM_HIT: &str = "models/enemies/volkolak_merged.glb#Animation4";     // hit (hurt)
-pub const VOLKOLAK_ANIM_DEATH: &str = "models/enemies/volkolak_merged.glb#Animation5";   // death (fall)
+
+// Анимации берутся по ИМЕНИ клипа из Gltf::named_animations (таблицы в anim_clips),
+// а не по #AnimationN — порядок клипов после merge-скриптов не важен
 
 // Окружение
 
-// Скальные стены арены (Meshy GLB — 3 варианта для разнообразия)
-pub const CLIFF_WALL_A: &str = "models/environment/cliff_wall_a.glb#Scene0";
-pub const CLIFF_WALL_B: &str = "models/environment/cliff_wall_b.glb#Scene0";
-pub const CLIFF_WALL_C: &str = "models/environment/cliff_wall_c.glb#Scene0";
+// Раскладки арен (стены, декор, пол, порталы — пути к их моделям/текстурам внутри)
+pub const ARENA_FOREST: &str = "arenas/forest.arena.ron";
+pub const ARENA_SWAMP: &str = "arenas/swamp.arena.ron";
+pub const ARENA_WINTER: &str = "arenas/winter.arena.ron";
+pub const ARENA_BURNT_VILLAGE: &str = "arenas/burnt_village.arena.ron";
+pub const ARENA_NAVI: &str = "arenas/navi.arena.ron";
 
 // Порталы (Meshy GLB — Разломы Нави)
 pub const PORTAL_FIRE: &str = "models/environment/portal_fire.glb#Scene0";
 pub const PORTAL_DARK: &str = "models/environment/portal_dark.glb#Scene0";
 
-// Пол арены — seamless PBR текстуры с Polyhaven (forrest_ground_01, 1K)
-pub const FLOOR_DIFF: &str = "textures/grass_diff.jpg";
-pub const FLOOR_NORMAL: &str = "textures/grass_nor.jpg";
-
-// Декор арены (Meshy GLB)
-pub const RUNE_STONE: &str = "models/environment/rune_stone.glb#Scene0";
-pub const ROCK_LARGE: &str = "models/environment/rock_large.glb#Scene0";
-pub const DEAD_TREE: &str = "models/environment/dead_tree.glb#Scene0";
-pub const BONE_PILE: &str = "models/environment/bone_pile.glb#Scene0";
+// Разбиваемые контейнеры (урны и бочки — из примитивов)
+pub const BONE_PILE_MODEL: &str = "models/environment/bone_pile.glb#Scene0";
 
 // Оружие
 pub const SWORD_MODEL: &str = "models/weapons/runic_sword.glb#Scene0";